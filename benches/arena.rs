@@ -0,0 +1,121 @@
+// Compares parsing into the owned Program tree, which the parser converts
+// from the Ast it builds, with parsing into an arena Ast directly: the time
+// taken, the allocations made, the allocations the finished tree keeps alive
+// and the time taken to drop it.
+//
+// Run with `cargo bench --bench arena`.
+use letter_rdp_rust::arena::Ast;
+use letter_rdp_rust::init;
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::{Duration, Instant};
+
+struct CountingAllocator;
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+static LIVE: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        LIVE.fetch_add(1, Ordering::Relaxed);
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        LIVE.fetch_sub(1, Ordering::Relaxed);
+        System.dealloc(ptr, layout);
+    }
+}
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+const SNIPPET: &str = "
+function update(state, action) {
+    let total = state.count * 2 + action.delta - 1;
+    if (total >= state.limit && !action.force) {
+        return state.limit;
+    }
+    const next = state.items.concat(action.item, total, 'added');
+    return helpers.merge(state, next, total % 3 === 0 || action.odd);
+}
+";
+
+const ROUNDS: u32 = 10;
+
+struct Measurement {
+    time: Duration,
+    allocations: usize,
+    retained: usize,
+    drop_time: Duration,
+}
+
+fn measure<T>(mut parse: impl FnMut() -> T) -> Measurement {
+    let mut measurement = Measurement {
+        time: Duration::ZERO,
+        allocations: 0,
+        retained: 0,
+        drop_time: Duration::ZERO,
+    };
+
+    for _ in 0..ROUNDS {
+        let allocations = ALLOCATIONS.load(Ordering::Relaxed);
+        let live = LIVE.load(Ordering::Relaxed);
+        let start = Instant::now();
+        let tree = parse();
+        measurement.time += start.elapsed();
+        measurement.allocations = ALLOCATIONS.load(Ordering::Relaxed) - allocations;
+        measurement.retained = LIVE.load(Ordering::Relaxed).saturating_sub(live);
+
+        let start = Instant::now();
+        drop(tree);
+        measurement.drop_time += start.elapsed();
+    }
+
+    measurement.time /= ROUNDS;
+    measurement.drop_time /= ROUNDS;
+    measurement
+}
+
+fn report(name: &str, measurement: &Measurement) {
+    println!(
+        "{:<28} {:>10.2?} {:>12} {:>12} {:>10.2?}",
+        name,
+        measurement.time,
+        measurement.allocations,
+        measurement.retained,
+        measurement.drop_time
+    );
+}
+
+fn main() {
+    let source = SNIPPET.repeat(2000);
+    println!("Parsing {} bytes, {} rounds", source.len(), ROUNDS);
+    println!(
+        "{:<28} {:>10} {:>12} {:>12} {:>10}",
+        "", "parse", "allocations", "retained", "drop"
+    );
+
+    let mut parser = init();
+    let owned = measure(|| parser.parse(&source));
+    report("Program", &owned);
+
+    // A new Ast for every round, so its node storage is allocated each time.
+    let mut parser = init();
+    let arena = measure(|| {
+        let mut ast = Ast::default();
+        parser.parse_into(&source, &mut ast);
+        ast
+    });
+    report("Ast", &arena);
+
+    // The Ast is kept between rounds, so its node storage is allocated once
+    // and the names of each parse are already in its interner.
+    let mut parser = init();
+    let mut ast = Ast::default();
+    let reused = measure(|| {
+        parser.parse_into(&source, &mut ast);
+    });
+    report("Ast, reused", &reused);
+}
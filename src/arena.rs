@@ -0,0 +1,1447 @@
+use super::interner::{Interner, Symbol};
+use super::nodes::*;
+use std::borrow::Cow;
+use std::sync::{Arc, Mutex};
+
+// A handle to a node stored in an Ast.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct NodeId(u32);
+
+impl NodeId {
+    pub fn index(self) -> usize {
+        return self.0 as usize;
+    }
+}
+
+// A run of nodes stored next to each other, such as the statements of a
+// block.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct NodeList {
+    start: u32,
+    len: u32,
+}
+
+impl NodeList {
+    pub fn len(self) -> usize {
+        return self.len as usize;
+    }
+
+    pub fn is_empty(self) -> bool {
+        return self.len == 0;
+    }
+}
+
+// The nodes of an Ast, named and laid out like the owned nodes, with their
+// children referred to by NodeId instead of being boxed. Names and string
+// values are interned, and referred to by Symbol. A name written without
+// escapes also keeps its text, so the owned tree can borrow it.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Node<'a> {
+    Program {
+        hashbang: Option<Cow<'a, str>>,
+        body: NodeList,
+    },
+    ExpressionStatement {
+        expression: NodeId,
+    },
+    BlockStatement {
+        body: NodeList,
+    },
+    VariableStatement {
+        kind: &'a str,
+        declarations: NodeList,
+    },
+    VariableDeclaration {
+        id: NodeId,
+        init: Option<NodeId>,
+    },
+    IfStatement {
+        test: NodeId,
+        consequent: NodeId,
+        alternate: Option<NodeId>,
+    },
+    FunctionDeclaration {
+        id: NodeId,
+        params: NodeList,
+        body: NodeId,
+        is_async: bool,
+        is_generator: bool,
+        return_type: Option<NodeId>,
+    },
+    ClassDeclaration {
+        id: NodeId,
+        super_class: Option<NodeId>,
+        body: NodeId,
+    },
+    ClassBody {
+        body: NodeList,
+    },
+    MethodDefinition {
+        key: NodeId,
+        value: NodeId,
+        kind: &'a str,
+        computed: bool,
+        is_static: bool,
+    },
+    PropertyDefinition {
+        key: NodeId,
+        value: Option<NodeId>,
+        computed: bool,
+        is_static: bool,
+    },
+    StaticBlock {
+        body: NodeList,
+    },
+    ReturnStatement {
+        argument: Option<NodeId>,
+    },
+    ThrowStatement {
+        argument: NodeId,
+    },
+    EmptyStatement,
+    Directive {
+        expression: NodeId,
+        directive: Cow<'a, str>,
+    },
+    ImportDeclaration {
+        specifiers: NodeList,
+        source: NodeId,
+    },
+    ImportSpecifier {
+        imported: NodeId,
+        local: NodeId,
+    },
+    ImportDefaultSpecifier {
+        local: NodeId,
+    },
+    ImportNamespaceSpecifier {
+        local: NodeId,
+    },
+    ExportNamedDeclaration {
+        declaration: Option<NodeId>,
+        specifiers: NodeList,
+        source: Option<NodeId>,
+    },
+    ExportSpecifier {
+        local: NodeId,
+        exported: NodeId,
+    },
+    ExportDefaultDeclaration {
+        declaration: NodeId,
+    },
+    ExportAllDeclaration {
+        exported: Option<NodeId>,
+        source: NodeId,
+    },
+    BinaryExpression {
+        operator: Cow<'a, str>,
+        left: NodeId,
+        right: NodeId,
+    },
+    UnaryExpression {
+        operator: Cow<'a, str>,
+        argument: NodeId,
+    },
+    LogicalExpression {
+        operator: Cow<'a, str>,
+        left: NodeId,
+        right: NodeId,
+    },
+    AssignmentExpression {
+        operator: Cow<'a, str>,
+        left: NodeId,
+        right: NodeId,
+    },
+    SequenceExpression {
+        expressions: NodeList,
+    },
+    FunctionExpression {
+        id: Option<NodeId>,
+        params: NodeList,
+        body: NodeId,
+        is_async: bool,
+        is_generator: bool,
+        return_type: Option<NodeId>,
+    },
+    ClassExpression {
+        id: Option<NodeId>,
+        super_class: Option<NodeId>,
+        body: NodeId,
+    },
+    AwaitExpression {
+        argument: NodeId,
+    },
+    YieldExpression {
+        argument: Option<NodeId>,
+        delegate: bool,
+    },
+    Identifier {
+        name: Symbol,
+        text: Option<&'a str>,
+        type_annotation: Option<NodeId>,
+        optional: bool,
+    },
+    PrivateIdentifier {
+        name: Symbol,
+        text: Option<&'a str>,
+    },
+    ThisExpression,
+    Super,
+    MemberExpression {
+        object: NodeId,
+        property: NodeId,
+        computed: bool,
+        optional: bool,
+    },
+    CallExpression {
+        callee: NodeId,
+        arguments: NodeList,
+        optional: bool,
+    },
+    ChainExpression {
+        expression: NodeId,
+    },
+    ImportExpression {
+        source: NodeId,
+    },
+    NumericLiteral {
+        value: f64,
+        raw: Cow<'a, str>,
+    },
+    StringLiteral {
+        value: Symbol,
+        raw: Cow<'a, str>,
+    },
+    BooleanLiteral {
+        value: bool,
+    },
+    NullLiteral,
+    RegExpLiteral {
+        pattern: Cow<'a, str>,
+        flags: Cow<'a, str>,
+        raw: Cow<'a, str>,
+    },
+    JSXElement {
+        name: NodeId,
+        attributes: NodeList,
+        children: NodeList,
+        self_closing: bool,
+    },
+    JSXFragment {
+        children: NodeList,
+    },
+    JSXIdentifier {
+        name: Symbol,
+        text: Option<&'a str>,
+    },
+    JSXMemberExpression {
+        object: NodeId,
+        property: NodeId,
+    },
+    JSXAttribute {
+        name: NodeId,
+        value: Option<NodeId>,
+    },
+    JSXSpreadAttribute {
+        argument: NodeId,
+    },
+    JSXExpressionContainer {
+        expression: Option<NodeId>,
+    },
+    JSXText {
+        value: Cow<'a, str>,
+    },
+    TypeAnnotation {
+        type_annotation: NodeId,
+    },
+    KeywordType {
+        name: Cow<'a, str>,
+    },
+    TypeReference {
+        name: Cow<'a, str>,
+    },
+    LiteralType {
+        literal: NodeId,
+    },
+    ArrayType {
+        element_type: NodeId,
+    },
+    UnionType {
+        types: NodeList,
+    },
+    TypeAliasDeclaration {
+        id: NodeId,
+        type_annotation: NodeId,
+    },
+    InterfaceDeclaration {
+        id: NodeId,
+        extends: NodeList,
+        body: NodeId,
+    },
+    InterfaceBody {
+        body: NodeList,
+    },
+    PropertySignature {
+        key: NodeId,
+        optional: bool,
+        type_annotation: NodeId,
+    },
+}
+
+// A program stored as a flat, index-based tree: every node lives in one
+// vector and the lists of child nodes in another, so a tree of any size
+// takes a handful of allocations. Children are stored before their parents
+// and the Program node last. The parser builds its nodes straight into an
+// Ast, and converts it when an owned tree is asked for.
+#[derive(Debug, Default, Clone)]
+pub struct Ast<'a> {
+    nodes: Vec<Node<'a>>,
+    lists: Vec<NodeId>,
+    // Holds the names and string values of the nodes.
+    interner: Arc<Mutex<Interner>>,
+    // Ids of list items whose own items are still being stored.
+    pending: Vec<NodeId>,
+}
+
+impl<'a> From<Program<'a>> for Ast<'a> {
+    fn from(program: Program<'a>) -> Self {
+        let mut ast = Ast::default();
+        ast.store(program);
+        return ast;
+    }
+}
+
+impl<'a> Ast<'a> {
+    // An Ast interning its names into an interner shared with others, such
+    // as the one of a Parser.
+    pub fn with_interner(interner: Arc<Mutex<Interner>>) -> Self {
+        Self {
+            interner,
+            ..Self::default()
+        }
+    }
+
+    pub fn interner(&self) -> &Arc<Mutex<Interner>> {
+        return &self.interner;
+    }
+
+    // The name or string value a node refers to by symbol.
+    pub fn resolve(&self, symbol: Symbol) -> Arc<str> {
+        return self.interner.lock().unwrap().resolve_shared(symbol);
+    }
+
+    pub fn root(&self) -> NodeId {
+        return NodeId(self.nodes.len() as u32 - 1);
+    }
+
+    pub fn node(&self, id: NodeId) -> &Node<'a> {
+        return &self.nodes[id.index()];
+    }
+
+    pub fn list(&self, list: NodeList) -> &[NodeId] {
+        let start = list.start as usize;
+        return &self.lists[start..start + list.len()];
+    }
+
+    pub fn len(&self) -> usize {
+        return self.nodes.len();
+    }
+
+    pub fn is_empty(&self) -> bool {
+        return self.nodes.is_empty();
+    }
+
+    // Adds a node whose children are already stored, as the `build` of a
+    // BinaryOperator does.
+    pub fn add(&mut self, node: Node<'a>) -> NodeId {
+        self.nodes.push(node);
+        return NodeId(self.nodes.len() as u32 - 1);
+    }
+
+    // Replaces the nodes of the Ast with those of the program, reusing the
+    // storage already allocated. Returns the id of the root.
+    pub fn store(&mut self, program: Program<'a>) -> NodeId {
+        self.clear();
+        let body = self.add_list(program.body, Self::add_statement);
+        return self.add(Node::Program {
+            hashbang: program.hashbang,
+            body,
+        });
+    }
+
+    // Rebuilds the owned tree of the program.
+    pub fn to_program(&self) -> Program<'a> {
+        return match self.node(self.root()) {
+            Node::Program { hashbang, body } => Program {
+                typ: "Program",
+                hashbang: hashbang.clone(),
+                body: self.items(*body, Self::statement),
+            },
+            _ => panic!("Ast has no program"),
+        };
+    }
+
+    // Empties the Ast so it can hold the nodes of a source borrowed for
+    // another lifetime, keeping its storage and interner.
+    pub(crate) fn reset<'b>(mut self) -> Ast<'b> {
+        self.clear();
+        return Ast {
+            // The vector is empty, and collecting it into one of nodes of
+            // the same size reuses its storage.
+            nodes: self.nodes.into_iter().map(|_| unreachable!()).collect(),
+            lists: self.lists,
+            interner: self.interner,
+            pending: self.pending,
+        };
+    }
+
+    pub(crate) fn clear(&mut self) {
+        self.nodes.clear();
+        self.lists.clear();
+        self.pending.clear();
+    }
+
+    pub(crate) fn node_mut(&mut self, id: NodeId) -> &mut Node<'a> {
+        return &mut self.nodes[id.index()];
+    }
+
+    pub(crate) fn intern(&mut self, string: &str) -> Symbol {
+        return self.interner.lock().unwrap().intern(string);
+    }
+
+    // The symbol of a name, and its text when it's borrowed from the source.
+    pub(crate) fn intern_name(&mut self, name: &Cow<'a, str>) -> (Symbol, Option<&'a str>) {
+        let text = match name {
+            Cow::Borrowed(text) => Some(*text),
+            Cow::Owned(_) => None,
+        };
+        return (self.intern(name), text);
+    }
+
+    // The name of an identifier node, borrowed from the source when it was
+    // written without escapes.
+    pub(crate) fn name(&self, id: NodeId) -> Cow<'a, str> {
+        let (name, text) = match self.node(id) {
+            Node::Identifier { name, text, .. }
+            | Node::PrivateIdentifier { name, text }
+            | Node::JSXIdentifier { name, text } => (*name, *text),
+            node => unexpected(node),
+        };
+        return match text {
+            Some(text) => Cow::Borrowed(text),
+            None => Cow::Owned(self.resolve(name).to_string()),
+        };
+    }
+
+    // Lists are built while their items are parsed, which may build lists
+    // of their own, so the ids of the items wait on a stack until the list
+    // is finished. Returns where the list starts on that stack.
+    pub(crate) fn start_list(&self) -> usize {
+        return self.pending.len();
+    }
+
+    pub(crate) fn push_item(&mut self, id: NodeId) {
+        self.pending.push(id);
+    }
+
+    pub(crate) fn finish_list(&mut self, start: usize) -> NodeList {
+        let list_start = self.lists.len() as u32;
+        self.lists.extend(self.pending.drain(start..));
+        return NodeList {
+            start: list_start,
+            len: self.lists.len() as u32 - list_start,
+        };
+    }
+
+    fn add_list<T>(
+        &mut self,
+        items: Vec<T>,
+        mut add: impl FnMut(&mut Self, T) -> NodeId,
+    ) -> NodeList {
+        let start = self.start_list();
+        for item in items {
+            let id = add(self, item);
+            self.push_item(id);
+        }
+        return self.finish_list(start);
+    }
+
+    fn items<T>(&self, list: NodeList, item: impl Fn(&Self, NodeId) -> T) -> Vec<T> {
+        return self.list(list).iter().map(|id| item(self, *id)).collect();
+    }
+
+    fn add_statement(&mut self, statement: Statement<'a>) -> NodeId {
+        let node = match statement {
+            Statement::ExpressionStatement(s) => Node::ExpressionStatement {
+                expression: self.add_expression(s.expression),
+            },
+            Statement::BlockStatement(s) => return self.add_block(s),
+            Statement::VariableStatement(s) => Node::VariableStatement {
+                kind: s.kind,
+                declarations: self.add_list(s.declarations, |ast, d| {
+                    let node = Node::VariableDeclaration {
+                        id: ast.add_identifier(d.id),
+                        init: d.init.map(|e| ast.add_expression(e)),
+                    };
+                    ast.add(node)
+                }),
+            },
+            Statement::IfStatement(s) => Node::IfStatement {
+                test: self.add_expression(s.test),
+                consequent: self.add_statement(*s.consequent),
+                alternate: s.alternate.map(|a| self.add_statement(*a)),
+            },
+            Statement::FunctionDeclaration(s) => Node::FunctionDeclaration {
+                id: self.add_identifier(s.id),
+                params: self.add_list(s.params, Self::add_identifier),
+                body: self.add_block(s.body),
+                is_async: s.is_async,
+                is_generator: s.is_generator,
+                return_type: s.return_type.map(|t| self.add_type_annotation(*t)),
+            },
+            Statement::ClassDeclaration(s) => Node::ClassDeclaration {
+                id: self.add_identifier(s.id),
+                super_class: s.super_class.map(|e| self.add_expression(*e)),
+                body: self.add_class_body(s.body),
+            },
+            Statement::ReturnStatement(s) => Node::ReturnStatement {
+                argument: s.argument.map(|e| self.add_expression(e)),
+            },
+            Statement::ThrowStatement(s) => Node::ThrowStatement {
+                argument: self.add_expression(s.argument),
+            },
+            Statement::EmptyStatement { .. } => Node::EmptyStatement,
+            Statement::ModuleDeclaration(s) => return self.add_module_declaration(s),
+            Statement::Directive(s) => Node::Directive {
+                expression: self.add_string_literal(s.expression),
+                directive: s.directive,
+            },
+            Statement::TypeAliasDeclaration(s) => Node::TypeAliasDeclaration {
+                id: self.add_identifier(s.id),
+                type_annotation: self.add_type(s.type_annotation),
+            },
+            Statement::InterfaceDeclaration(s) => {
+                let id = self.add_identifier(s.id);
+                let extends = self.add_list(s.extends, |ast, t| {
+                    ast.add(Node::TypeReference { name: t.name })
+                });
+                let body = self.add_list(s.body.body, |ast, p| {
+                    let node = Node::PropertySignature {
+                        key: ast.add_identifier(p.key),
+                        optional: p.optional,
+                        type_annotation: ast.add_type_annotation(p.type_annotation),
+                    };
+                    ast.add(node)
+                });
+                let body = self.add(Node::InterfaceBody { body });
+                Node::InterfaceDeclaration { id, extends, body }
+            }
+        };
+        return self.add(node);
+    }
+
+    fn add_block(&mut self, block: BlockStatement<'a>) -> NodeId {
+        let body = self.add_list(block.body, Self::add_statement);
+        return self.add(Node::BlockStatement { body });
+    }
+
+    fn add_module_declaration(&mut self, declaration: ModuleDeclaration<'a>) -> NodeId {
+        let node = match declaration {
+            ModuleDeclaration::ImportDeclaration(d) => Node::ImportDeclaration {
+                specifiers: self.add_list(d.specifiers, |ast, specifier| {
+                    let node = match specifier {
+                        ImportSpecifierKind::ImportSpecifier(s) => Node::ImportSpecifier {
+                            imported: ast.add_identifier(s.imported),
+                            local: ast.add_identifier(s.local),
+                        },
+                        ImportSpecifierKind::ImportDefaultSpecifier(s) => {
+                            Node::ImportDefaultSpecifier {
+                                local: ast.add_identifier(s.local),
+                            }
+                        }
+                        ImportSpecifierKind::ImportNamespaceSpecifier(s) => {
+                            Node::ImportNamespaceSpecifier {
+                                local: ast.add_identifier(s.local),
+                            }
+                        }
+                    };
+                    ast.add(node)
+                }),
+                source: self.add_string_literal(d.source),
+            },
+            ModuleDeclaration::ExportNamedDeclaration(d) => Node::ExportNamedDeclaration {
+                declaration: d.declaration.map(|s| self.add_statement(*s)),
+                specifiers: self.add_list(d.specifiers, |ast, s| {
+                    let node = Node::ExportSpecifier {
+                        local: ast.add_identifier(s.local),
+                        exported: ast.add_identifier(s.exported),
+                    };
+                    ast.add(node)
+                }),
+                source: d.source.map(|s| self.add_string_literal(s)),
+            },
+            ModuleDeclaration::ExportDefaultDeclaration(d) => Node::ExportDefaultDeclaration {
+                declaration: match d.declaration {
+                    ExportDefaultKind::FunctionDeclaration(f) => {
+                        self.add_statement(Statement::FunctionDeclaration(f))
+                    }
+                    ExportDefaultKind::ClassDeclaration(c) => {
+                        self.add_statement(Statement::ClassDeclaration(c))
+                    }
+                    ExportDefaultKind::Expression(e) => self.add_expression(e),
+                },
+            },
+            ModuleDeclaration::ExportAllDeclaration(d) => Node::ExportAllDeclaration {
+                exported: d.exported.map(|i| self.add_identifier(i)),
+                source: self.add_string_literal(d.source),
+            },
+        };
+        return self.add(node);
+    }
+
+    fn add_class_body(&mut self, body: ClassBody<'a>) -> NodeId {
+        let body = self.add_list(body.body, |ast, element| {
+            let node = match element {
+                ClassElement::MethodDefinition(m) => Node::MethodDefinition {
+                    key: ast.add_expression(*m.key),
+                    value: ast.add_expression(Expression::FunctionExpression(m.value)),
+                    kind: m.kind,
+                    computed: m.computed,
+                    is_static: m.is_static,
+                },
+                ClassElement::PropertyDefinition(p) => Node::PropertyDefinition {
+                    key: ast.add_expression(*p.key),
+                    value: p.value.map(|e| ast.add_expression(*e)),
+                    computed: p.computed,
+                    is_static: p.is_static,
+                },
+                ClassElement::StaticBlock(b) => Node::StaticBlock {
+                    body: ast.add_list(b.body, Self::add_statement),
+                },
+            };
+            ast.add(node)
+        });
+        return self.add(Node::ClassBody { body });
+    }
+
+    fn add_expression(&mut self, expression: Expression<'a>) -> NodeId {
+        let node = match expression {
+            Expression::Literal(l) => return self.add_literal(l),
+            Expression::BinaryExpression(e) => Node::BinaryExpression {
+                operator: e.operator,
+                left: self.add_expression(*e.left),
+                right: self.add_expression(*e.right),
+            },
+            Expression::UnaryExpression(e) => Node::UnaryExpression {
+                operator: e.operator,
+                argument: self.add_expression(*e.argument),
+            },
+            Expression::LogicalExpression(e) => Node::LogicalExpression {
+                operator: e.operator,
+                left: self.add_expression(*e.left),
+                right: self.add_expression(*e.right),
+            },
+            Expression::AssignmentExpression(e) => Node::AssignmentExpression {
+                operator: e.operator,
+                left: self.add_expression(*e.left),
+                right: self.add_expression(*e.right),
+            },
+            Expression::SequenceExpression(e) => Node::SequenceExpression {
+                expressions: self.add_list(e.expressions, Self::add_expression),
+            },
+            Expression::FunctionExpression(e) => Node::FunctionExpression {
+                id: e.id.map(|i| self.add_identifier(i)),
+                params: self.add_list(e.params, Self::add_identifier),
+                body: self.add_block(e.body),
+                is_async: e.is_async,
+                is_generator: e.is_generator,
+                return_type: e.return_type.map(|t| self.add_type_annotation(*t)),
+            },
+            Expression::ClassExpression(e) => Node::ClassExpression {
+                id: e.id.map(|i| self.add_identifier(i)),
+                super_class: e.super_class.map(|s| self.add_expression(*s)),
+                body: self.add_class_body(e.body),
+            },
+            Expression::JSXElement(e) => return self.add_jsx_element(e),
+            Expression::JSXFragment(f) => return self.add_jsx_fragment(f),
+            Expression::AwaitExpression(e) => Node::AwaitExpression {
+                argument: self.add_expression(*e.argument),
+            },
+            Expression::YieldExpression(e) => Node::YieldExpression {
+                argument: e.argument.map(|a| self.add_expression(*a)),
+                delegate: e.delegate,
+            },
+            Expression::LeftHandSideExpression(e) => match e {
+                LeftHandSideExpression::Identifier(i) => return self.add_identifier(i),
+                LeftHandSideExpression::PrivateIdentifier(i) => {
+                    let (name, text) = self.intern_name(&i.name);
+                    Node::PrivateIdentifier { name, text }
+                }
+                LeftHandSideExpression::ThisExpression(_) => Node::ThisExpression,
+                LeftHandSideExpression::Super(_) => Node::Super,
+                LeftHandSideExpression::MemberExpression(m) => Node::MemberExpression {
+                    object: self.add_expression(*m.object),
+                    property: self.add_expression(*m.property),
+                    computed: m.computed,
+                    optional: m.optional,
+                },
+                LeftHandSideExpression::CallExpression(c) => Node::CallExpression {
+                    callee: self.add_expression(*c.callee),
+                    arguments: self.add_list(c.arguments, Self::add_expression),
+                    optional: c.optional,
+                },
+                LeftHandSideExpression::ChainExpression(c) => Node::ChainExpression {
+                    expression: self.add_expression(*c.expression),
+                },
+                LeftHandSideExpression::ImportExpression(i) => Node::ImportExpression {
+                    source: self.add_expression(*i.source),
+                },
+            },
+        };
+        return self.add(node);
+    }
+
+    fn add_identifier(&mut self, identifier: Identifier<'a>) -> NodeId {
+        let (name, text) = self.intern_name(&identifier.name);
+        let node = Node::Identifier {
+            name,
+            text,
+            type_annotation: identifier
+                .type_annotation
+                .map(|t| self.add_type_annotation(*t)),
+            optional: identifier.optional,
+        };
+        return self.add(node);
+    }
+
+    fn add_literal(&mut self, literal: Literal<'a>) -> NodeId {
+        let node = match literal {
+            Literal::NumericLiteral(l) => Node::NumericLiteral {
+                value: l.value,
+                raw: l.raw,
+            },
+            Literal::StringLiteral(l) => return self.add_string_literal(l),
+            Literal::BooleanLiteral(l) => Node::BooleanLiteral { value: l.value },
+            Literal::NullLiteral(_) => Node::NullLiteral,
+            Literal::RegExpLiteral(l) => Node::RegExpLiteral {
+                pattern: l.pattern,
+                flags: l.flags,
+                raw: l.raw,
+            },
+        };
+        return self.add(node);
+    }
+
+    fn add_string_literal(&mut self, literal: StringLiteral<'a>) -> NodeId {
+        let node = Node::StringLiteral {
+            value: self.intern(&literal.value),
+            raw: literal.raw,
+        };
+        return self.add(node);
+    }
+
+    fn add_jsx_element(&mut self, element: JSXElement<'a>) -> NodeId {
+        let node = Node::JSXElement {
+            name: self.add_jsx_element_name(element.name),
+            attributes: self.add_list(element.attributes, |ast, attribute| {
+                let node = match attribute {
+                    JSXAttributeKind::JSXAttribute(a) => Node::JSXAttribute {
+                        name: ast.add_jsx_identifier(a.name),
+                        value: a.value.map(|v| match v {
+                            JSXAttributeValue::StringLiteral(l) => ast.add_string_literal(l),
+                            JSXAttributeValue::JSXExpressionContainer(c) => {
+                                ast.add_jsx_expression_container(c)
+                            }
+                            JSXAttributeValue::JSXElement(e) => ast.add_jsx_element(e),
+                            JSXAttributeValue::JSXFragment(f) => ast.add_jsx_fragment(f),
+                        }),
+                    },
+                    JSXAttributeKind::JSXSpreadAttribute(s) => Node::JSXSpreadAttribute {
+                        argument: ast.add_expression(s.argument),
+                    },
+                };
+                ast.add(node)
+            }),
+            children: self.add_list(element.children, Self::add_jsx_child),
+            self_closing: element.self_closing,
+        };
+        return self.add(node);
+    }
+
+    fn add_jsx_fragment(&mut self, fragment: JSXFragment<'a>) -> NodeId {
+        let children = self.add_list(fragment.children, Self::add_jsx_child);
+        return self.add(Node::JSXFragment { children });
+    }
+
+    fn add_jsx_element_name(&mut self, name: JSXElementName<'a>) -> NodeId {
+        let node = match name {
+            JSXElementName::JSXIdentifier(i) => return self.add_jsx_identifier(i),
+            JSXElementName::JSXMemberExpression(m) => Node::JSXMemberExpression {
+                object: self.add_jsx_element_name(*m.object),
+                property: self.add_jsx_identifier(m.property),
+            },
+        };
+        return self.add(node);
+    }
+
+    fn add_jsx_identifier(&mut self, identifier: JSXIdentifier<'a>) -> NodeId {
+        let (name, text) = self.intern_name(&identifier.name);
+        return self.add(Node::JSXIdentifier { name, text });
+    }
+
+    fn add_jsx_child(&mut self, child: JSXChild<'a>) -> NodeId {
+        return match child {
+            JSXChild::JSXText(t) => self.add(Node::JSXText { value: t.value }),
+            JSXChild::JSXExpressionContainer(c) => self.add_jsx_expression_container(c),
+            JSXChild::JSXElement(e) => self.add_jsx_element(e),
+            JSXChild::JSXFragment(f) => self.add_jsx_fragment(f),
+        };
+    }
+
+    fn add_jsx_expression_container(&mut self, container: JSXExpressionContainer<'a>) -> NodeId {
+        let expression = container.expression.map(|e| self.add_expression(*e));
+        return self.add(Node::JSXExpressionContainer { expression });
+    }
+
+    fn add_type_annotation(&mut self, annotation: TypeAnnotation<'a>) -> NodeId {
+        let type_annotation = self.add_type(annotation.type_annotation);
+        return self.add(Node::TypeAnnotation { type_annotation });
+    }
+
+    fn add_type(&mut self, type_: Type<'a>) -> NodeId {
+        let node = match type_ {
+            Type::KeywordType(t) => Node::KeywordType { name: t.name },
+            Type::TypeReference(t) => Node::TypeReference { name: t.name },
+            Type::LiteralType(t) => Node::LiteralType {
+                literal: self.add_literal(t.literal),
+            },
+            Type::ArrayType(t) => Node::ArrayType {
+                element_type: self.add_type(*t.element_type),
+            },
+            Type::UnionType(t) => Node::UnionType {
+                types: self.add_list(t.types, Self::add_type),
+            },
+        };
+        return self.add(node);
+    }
+
+    pub(crate) fn statement(&self, id: NodeId) -> Statement<'a> {
+        return match self.node(id) {
+            Node::ExpressionStatement { expression } => {
+                Statement::ExpressionStatement(ExpressionStatement {
+                    typ: "ExpressionStatement",
+                    expression: self.expression(*expression),
+                })
+            }
+            Node::BlockStatement { .. } => Statement::BlockStatement(self.block(id)),
+            Node::VariableStatement { kind, declarations } => {
+                Statement::VariableStatement(VariableStatement {
+                    typ: "VariableStatement",
+                    kind,
+                    declarations: self.items(*declarations, |ast, id| match ast.node(id) {
+                        Node::VariableDeclaration { id, init } => VariableDeclaration {
+                            typ: "VariableDeclaration",
+                            id: ast.identifier(*id),
+                            init: init.map(|e| ast.expression(e)),
+                        },
+                        node => unexpected(node),
+                    }),
+                })
+            }
+            Node::IfStatement {
+                test,
+                consequent,
+                alternate,
+            } => Statement::IfStatement(IfStatement {
+                typ: "IfStatement",
+                test: self.expression(*test),
+                consequent: Box::new(self.statement(*consequent)),
+                alternate: alternate.map(|s| Box::new(self.statement(s))),
+            }),
+            Node::FunctionDeclaration {
+                id,
+                params,
+                body,
+                is_async,
+                is_generator,
+                return_type,
+            } => Statement::FunctionDeclaration(FunctionDeclaration {
+                typ: "FunctionDeclaration",
+                id: self.identifier(*id),
+                params: self.items(*params, Self::identifier),
+                body: self.block(*body),
+                is_async: *is_async,
+                is_generator: *is_generator,
+                return_type: return_type.map(|t| Box::new(self.type_annotation(t))),
+            }),
+            Node::ClassDeclaration {
+                id,
+                super_class,
+                body,
+            } => Statement::ClassDeclaration(ClassDeclaration {
+                typ: "ClassDeclaration",
+                id: self.identifier(*id),
+                super_class: super_class.map(|e| Box::new(self.expression(e))),
+                body: self.class_body(*body),
+            }),
+            Node::ReturnStatement { argument } => Statement::ReturnStatement(ReturnStatement {
+                typ: "ReturnStatement",
+                argument: argument.map(|e| self.expression(e)),
+            }),
+            Node::ThrowStatement { argument } => Statement::ThrowStatement(ThrowStatement {
+                typ: "ThrowStatement",
+                argument: self.expression(*argument),
+            }),
+            Node::EmptyStatement => Statement::EmptyStatement {
+                typ: "EmptyStatement",
+            },
+            Node::Directive {
+                expression,
+                directive,
+            } => Statement::Directive(Directive {
+                typ: "Directive",
+                expression: self.string_literal(*expression),
+                directive: directive.clone(),
+            }),
+            Node::ImportDeclaration { .. }
+            | Node::ExportNamedDeclaration { .. }
+            | Node::ExportDefaultDeclaration { .. }
+            | Node::ExportAllDeclaration { .. } => {
+                Statement::ModuleDeclaration(self.module_declaration(id))
+            }
+            Node::TypeAliasDeclaration {
+                id,
+                type_annotation,
+            } => Statement::TypeAliasDeclaration(TypeAliasDeclaration {
+                typ: "TypeAliasDeclaration",
+                id: self.identifier(*id),
+                type_annotation: self.type_(*type_annotation),
+            }),
+            Node::InterfaceDeclaration { id, extends, body } => {
+                Statement::InterfaceDeclaration(InterfaceDeclaration {
+                    typ: "InterfaceDeclaration",
+                    id: self.identifier(*id),
+                    extends: self.items(*extends, |ast, id| match ast.type_(id) {
+                        Type::TypeReference(t) => t,
+                        _ => unexpected(ast.node(id)),
+                    }),
+                    body: match self.node(*body) {
+                        Node::InterfaceBody { body } => InterfaceBody {
+                            typ: "InterfaceBody",
+                            body: self.items(*body, Self::property_signature),
+                        },
+                        node => unexpected(node),
+                    },
+                })
+            }
+            node => unexpected(node),
+        };
+    }
+
+    fn block(&self, id: NodeId) -> BlockStatement<'a> {
+        return match self.node(id) {
+            Node::BlockStatement { body } => BlockStatement {
+                typ: "BlockStatement",
+                body: self.items(*body, Self::statement),
+            },
+            node => unexpected(node),
+        };
+    }
+
+    fn module_declaration(&self, id: NodeId) -> ModuleDeclaration<'a> {
+        return match self.node(id) {
+            Node::ImportDeclaration { specifiers, source } => {
+                ModuleDeclaration::ImportDeclaration(ImportDeclaration {
+                    typ: "ImportDeclaration",
+                    specifiers: self.items(*specifiers, Self::import_specifier),
+                    source: self.string_literal(*source),
+                })
+            }
+            Node::ExportNamedDeclaration {
+                declaration,
+                specifiers,
+                source,
+            } => ModuleDeclaration::ExportNamedDeclaration(ExportNamedDeclaration {
+                typ: "ExportNamedDeclaration",
+                declaration: declaration.map(|s| Box::new(self.statement(s))),
+                specifiers: self.items(*specifiers, |ast, id| match ast.node(id) {
+                    Node::ExportSpecifier { local, exported } => ExportSpecifier {
+                        typ: "ExportSpecifier",
+                        local: ast.identifier(*local),
+                        exported: ast.identifier(*exported),
+                    },
+                    node => unexpected(node),
+                }),
+                source: source.map(|s| self.string_literal(s)),
+            }),
+            Node::ExportDefaultDeclaration { declaration } => {
+                ModuleDeclaration::ExportDefaultDeclaration(ExportDefaultDeclaration {
+                    typ: "ExportDefaultDeclaration",
+                    declaration: match self.node(*declaration) {
+                        Node::FunctionDeclaration { .. } | Node::ClassDeclaration { .. } => {
+                            match self.statement(*declaration) {
+                                Statement::FunctionDeclaration(f) => {
+                                    ExportDefaultKind::FunctionDeclaration(f)
+                                }
+                                Statement::ClassDeclaration(c) => {
+                                    ExportDefaultKind::ClassDeclaration(c)
+                                }
+                                _ => unexpected(self.node(*declaration)),
+                            }
+                        }
+                        _ => ExportDefaultKind::Expression(self.expression(*declaration)),
+                    },
+                })
+            }
+            Node::ExportAllDeclaration { exported, source } => {
+                ModuleDeclaration::ExportAllDeclaration(ExportAllDeclaration {
+                    typ: "ExportAllDeclaration",
+                    exported: exported.map(|i| self.identifier(i)),
+                    source: self.string_literal(*source),
+                })
+            }
+            node => unexpected(node),
+        };
+    }
+
+    fn import_specifier(&self, id: NodeId) -> ImportSpecifierKind<'a> {
+        return match self.node(id) {
+            Node::ImportSpecifier { imported, local } => {
+                ImportSpecifierKind::ImportSpecifier(ImportSpecifier {
+                    typ: "ImportSpecifier",
+                    imported: self.identifier(*imported),
+                    local: self.identifier(*local),
+                })
+            }
+            Node::ImportDefaultSpecifier { local } => {
+                ImportSpecifierKind::ImportDefaultSpecifier(ImportDefaultSpecifier {
+                    typ: "ImportDefaultSpecifier",
+                    local: self.identifier(*local),
+                })
+            }
+            Node::ImportNamespaceSpecifier { local } => {
+                ImportSpecifierKind::ImportNamespaceSpecifier(ImportNamespaceSpecifier {
+                    typ: "ImportNamespaceSpecifier",
+                    local: self.identifier(*local),
+                })
+            }
+            node => unexpected(node),
+        };
+    }
+
+    fn class_body(&self, id: NodeId) -> ClassBody<'a> {
+        let body = match self.node(id) {
+            Node::ClassBody { body } => *body,
+            node => unexpected(node),
+        };
+        return ClassBody {
+            typ: "ClassBody",
+            body: self.items(body, |ast, id| match ast.node(id) {
+                Node::MethodDefinition {
+                    key,
+                    value,
+                    kind,
+                    computed,
+                    is_static,
+                } => ClassElement::MethodDefinition(MethodDefinition {
+                    typ: "MethodDefinition",
+                    key: Box::new(ast.expression(*key)),
+                    value: match ast.expression(*value) {
+                        Expression::FunctionExpression(f) => f,
+                        _ => unexpected(ast.node(*value)),
+                    },
+                    kind,
+                    computed: *computed,
+                    is_static: *is_static,
+                }),
+                Node::PropertyDefinition {
+                    key,
+                    value,
+                    computed,
+                    is_static,
+                } => ClassElement::PropertyDefinition(PropertyDefinition {
+                    typ: "PropertyDefinition",
+                    key: Box::new(ast.expression(*key)),
+                    value: value.map(|e| Box::new(ast.expression(e))),
+                    computed: *computed,
+                    is_static: *is_static,
+                }),
+                Node::StaticBlock { body } => ClassElement::StaticBlock(StaticBlock {
+                    typ: "StaticBlock",
+                    body: ast.items(*body, Self::statement),
+                }),
+                node => unexpected(node),
+            }),
+        };
+    }
+
+    pub(crate) fn expression(&self, id: NodeId) -> Expression<'a> {
+        let lhs = Expression::LeftHandSideExpression;
+        return match self.node(id) {
+            Node::NumericLiteral { .. }
+            | Node::StringLiteral { .. }
+            | Node::BooleanLiteral { .. }
+            | Node::NullLiteral
+            | Node::RegExpLiteral { .. } => Expression::Literal(self.literal(id)),
+            Node::BinaryExpression {
+                operator,
+                left,
+                right,
+            } => Expression::BinaryExpression(BinaryExpression {
+                typ: "BinaryExpression",
+                operator: operator.clone(),
+                left: Box::new(self.expression(*left)),
+                right: Box::new(self.expression(*right)),
+            }),
+            Node::UnaryExpression { operator, argument } => {
+                Expression::UnaryExpression(UnaryExpression {
+                    typ: "UnaryExpression",
+                    operator: operator.clone(),
+                    argument: Box::new(self.expression(*argument)),
+                })
+            }
+            Node::LogicalExpression {
+                operator,
+                left,
+                right,
+            } => Expression::LogicalExpression(LogicalExpression {
+                typ: "LogicalExpression",
+                operator: operator.clone(),
+                left: Box::new(self.expression(*left)),
+                right: Box::new(self.expression(*right)),
+            }),
+            Node::AssignmentExpression {
+                operator,
+                left,
+                right,
+            } => Expression::AssignmentExpression(AssignmentExpression {
+                typ: "AssignmentExpression",
+                operator: operator.clone(),
+                left: Box::new(self.expression(*left)),
+                right: Box::new(self.expression(*right)),
+            }),
+            Node::SequenceExpression { expressions } => {
+                Expression::SequenceExpression(SequenceExpression {
+                    typ: "SequenceExpression",
+                    expressions: self.items(*expressions, Self::expression),
+                })
+            }
+            Node::FunctionExpression {
+                id,
+                params,
+                body,
+                is_async,
+                is_generator,
+                return_type,
+            } => Expression::FunctionExpression(FunctionExpression {
+                typ: "FunctionExpression",
+                id: id.map(|i| self.identifier(i)),
+                params: self.items(*params, Self::identifier),
+                body: self.block(*body),
+                is_async: *is_async,
+                is_generator: *is_generator,
+                return_type: return_type.map(|t| Box::new(self.type_annotation(t))),
+            }),
+            Node::ClassExpression {
+                id,
+                super_class,
+                body,
+            } => Expression::ClassExpression(ClassExpression {
+                typ: "ClassExpression",
+                id: id.map(|i| self.identifier(i)),
+                super_class: super_class.map(|e| Box::new(self.expression(e))),
+                body: self.class_body(*body),
+            }),
+            Node::JSXElement { .. } => Expression::JSXElement(self.jsx_element(id)),
+            Node::JSXFragment { .. } => Expression::JSXFragment(self.jsx_fragment(id)),
+            Node::AwaitExpression { argument } => Expression::AwaitExpression(AwaitExpression {
+                typ: "AwaitExpression",
+                argument: Box::new(self.expression(*argument)),
+            }),
+            Node::YieldExpression { argument, delegate } => {
+                Expression::YieldExpression(YieldExpression {
+                    typ: "YieldExpression",
+                    argument: argument.map(|e| Box::new(self.expression(e))),
+                    delegate: *delegate,
+                })
+            }
+            Node::Identifier { .. } => lhs(LeftHandSideExpression::Identifier(self.identifier(id))),
+            Node::PrivateIdentifier { .. } => lhs(LeftHandSideExpression::PrivateIdentifier(
+                PrivateIdentifier {
+                    typ: "PrivateIdentifier",
+                    name: self.name(id),
+                },
+            )),
+            Node::ThisExpression => lhs(LeftHandSideExpression::ThisExpression(ThisExpression {
+                typ: "ThisExpression",
+            })),
+            Node::Super => lhs(LeftHandSideExpression::Super(Super { typ: "Super" })),
+            Node::MemberExpression {
+                object,
+                property,
+                computed,
+                optional,
+            } => lhs(LeftHandSideExpression::MemberExpression(MemberExpression {
+                typ: "MemberExpression",
+                object: Box::new(self.expression(*object)),
+                property: Box::new(self.expression(*property)),
+                computed: *computed,
+                optional: *optional,
+            })),
+            Node::CallExpression {
+                callee,
+                arguments,
+                optional,
+            } => lhs(LeftHandSideExpression::CallExpression(CallExpression {
+                typ: "CallExpression",
+                callee: Box::new(self.expression(*callee)),
+                arguments: self.items(*arguments, Self::expression),
+                optional: *optional,
+            })),
+            Node::ChainExpression { expression } => {
+                lhs(LeftHandSideExpression::ChainExpression(ChainExpression {
+                    typ: "ChainExpression",
+                    expression: Box::new(self.expression(*expression)),
+                }))
+            }
+            Node::ImportExpression { source } => {
+                lhs(LeftHandSideExpression::ImportExpression(ImportExpression {
+                    typ: "ImportExpression",
+                    source: Box::new(self.expression(*source)),
+                }))
+            }
+            node => unexpected(node),
+        };
+    }
+
+    fn identifier(&self, id: NodeId) -> Identifier<'a> {
+        return match self.node(id) {
+            Node::Identifier {
+                type_annotation,
+                optional,
+                ..
+            } => Identifier {
+                typ: "Identifier",
+                name: self.name(id),
+                type_annotation: type_annotation.map(|t| Box::new(self.type_annotation(t))),
+                optional: *optional,
+            },
+            node => unexpected(node),
+        };
+    }
+
+    fn literal(&self, id: NodeId) -> Literal<'a> {
+        return match self.node(id) {
+            Node::NumericLiteral { value, raw } => Literal::NumericLiteral(NumericLiteral {
+                typ: "NumericLiteral",
+                value: *value,
+                raw: raw.clone(),
+            }),
+            Node::StringLiteral { .. } => Literal::StringLiteral(self.string_literal(id)),
+            Node::BooleanLiteral { value } => Literal::BooleanLiteral(BooleanLiteral {
+                typ: "BooleanLiteral",
+                value: *value,
+            }),
+            Node::NullLiteral => Literal::NullLiteral(NullLiteral { typ: "NullLiteral" }),
+            Node::RegExpLiteral {
+                pattern,
+                flags,
+                raw,
+            } => Literal::RegExpLiteral(RegExpLiteral {
+                typ: "RegExpLiteral",
+                pattern: pattern.clone(),
+                flags: flags.clone(),
+                raw: raw.clone(),
+            }),
+            node => unexpected(node),
+        };
+    }
+
+    fn string_literal(&self, id: NodeId) -> StringLiteral<'a> {
+        return match self.node(id) {
+            Node::StringLiteral { value, raw } => StringLiteral {
+                typ: "StringLiteral",
+                value: self.string_value(*value, raw),
+                raw: raw.clone(),
+            },
+            node => unexpected(node),
+        };
+    }
+
+    // A value without escapes is borrowed from between the quotes of the
+    // literal, when the literal is borrowed from the source.
+    fn string_value(&self, value: Symbol, raw: &Cow<'a, str>) -> Cow<'a, str> {
+        let value = self.resolve(value);
+        if let Cow::Borrowed(raw) = *raw {
+            let body = raw.get(1..raw.len().saturating_sub(1));
+            if let Some(body) = body.filter(|body| *body == &*value) {
+                return Cow::Borrowed(body);
+            }
+        }
+        return Cow::Owned(value.to_string());
+    }
+
+    fn jsx_element(&self, id: NodeId) -> JSXElement<'a> {
+        return match self.node(id) {
+            Node::JSXElement {
+                name,
+                attributes,
+                children,
+                self_closing,
+            } => JSXElement {
+                typ: "JSXElement",
+                name: self.jsx_element_name(*name),
+                attributes: self.items(*attributes, Self::jsx_attribute),
+                children: self.items(*children, Self::jsx_child),
+                self_closing: *self_closing,
+            },
+            node => unexpected(node),
+        };
+    }
+
+    fn jsx_fragment(&self, id: NodeId) -> JSXFragment<'a> {
+        return match self.node(id) {
+            Node::JSXFragment { children } => JSXFragment {
+                typ: "JSXFragment",
+                children: self.items(*children, Self::jsx_child),
+            },
+            node => unexpected(node),
+        };
+    }
+
+    fn jsx_element_name(&self, id: NodeId) -> JSXElementName<'a> {
+        return match self.node(id) {
+            Node::JSXIdentifier { .. } => JSXElementName::JSXIdentifier(self.jsx_identifier(id)),
+            Node::JSXMemberExpression { object, property } => {
+                JSXElementName::JSXMemberExpression(JSXMemberExpression {
+                    typ: "JSXMemberExpression",
+                    object: Box::new(self.jsx_element_name(*object)),
+                    property: self.jsx_identifier(*property),
+                })
+            }
+            node => unexpected(node),
+        };
+    }
+
+    fn jsx_identifier(&self, id: NodeId) -> JSXIdentifier<'a> {
+        return match self.node(id) {
+            Node::JSXIdentifier { .. } => JSXIdentifier {
+                typ: "JSXIdentifier",
+                name: self.name(id),
+            },
+            node => unexpected(node),
+        };
+    }
+
+    fn jsx_attribute(&self, id: NodeId) -> JSXAttributeKind<'a> {
+        return match self.node(id) {
+            Node::JSXAttribute { name, value } => JSXAttributeKind::JSXAttribute(JSXAttribute {
+                typ: "JSXAttribute",
+                name: self.jsx_identifier(*name),
+                value: value.map(|v| match self.node(v) {
+                    Node::StringLiteral { .. } => {
+                        JSXAttributeValue::StringLiteral(self.string_literal(v))
+                    }
+                    Node::JSXExpressionContainer { .. } => {
+                        JSXAttributeValue::JSXExpressionContainer(self.jsx_expression_container(v))
+                    }
+                    Node::JSXElement { .. } => JSXAttributeValue::JSXElement(self.jsx_element(v)),
+                    Node::JSXFragment { .. } => {
+                        JSXAttributeValue::JSXFragment(self.jsx_fragment(v))
+                    }
+                    node => unexpected(node),
+                }),
+            }),
+            Node::JSXSpreadAttribute { argument } => {
+                JSXAttributeKind::JSXSpreadAttribute(JSXSpreadAttribute {
+                    typ: "JSXSpreadAttribute",
+                    argument: self.expression(*argument),
+                })
+            }
+            node => unexpected(node),
+        };
+    }
+
+    fn jsx_child(&self, id: NodeId) -> JSXChild<'a> {
+        return match self.node(id) {
+            Node::JSXText { value } => JSXChild::JSXText(JSXText {
+                typ: "JSXText",
+                value: value.clone(),
+            }),
+            Node::JSXExpressionContainer { .. } => {
+                JSXChild::JSXExpressionContainer(self.jsx_expression_container(id))
+            }
+            Node::JSXElement { .. } => JSXChild::JSXElement(self.jsx_element(id)),
+            Node::JSXFragment { .. } => JSXChild::JSXFragment(self.jsx_fragment(id)),
+            node => unexpected(node),
+        };
+    }
+
+    fn jsx_expression_container(&self, id: NodeId) -> JSXExpressionContainer<'a> {
+        return match self.node(id) {
+            Node::JSXExpressionContainer { expression } => JSXExpressionContainer {
+                typ: "JSXExpressionContainer",
+                expression: expression.map(|e| Box::new(self.expression(e))),
+            },
+            node => unexpected(node),
+        };
+    }
+
+    fn type_annotation(&self, id: NodeId) -> TypeAnnotation<'a> {
+        return match self.node(id) {
+            Node::TypeAnnotation { type_annotation } => TypeAnnotation {
+                typ: "TypeAnnotation",
+                type_annotation: self.type_(*type_annotation),
+            },
+            node => unexpected(node),
+        };
+    }
+
+    fn type_(&self, id: NodeId) -> Type<'a> {
+        return match self.node(id) {
+            Node::KeywordType { name } => Type::KeywordType(KeywordType {
+                typ: "KeywordType",
+                name: name.clone(),
+            }),
+            Node::TypeReference { name } => Type::TypeReference(TypeReference {
+                typ: "TypeReference",
+                name: name.clone(),
+            }),
+            Node::LiteralType { literal } => Type::LiteralType(LiteralType {
+                typ: "LiteralType",
+                literal: self.literal(*literal),
+            }),
+            Node::ArrayType { element_type } => Type::ArrayType(ArrayType {
+                typ: "ArrayType",
+                element_type: Box::new(self.type_(*element_type)),
+            }),
+            Node::UnionType { types } => Type::UnionType(UnionType {
+                typ: "UnionType",
+                types: self.items(*types, Self::type_),
+            }),
+            node => unexpected(node),
+        };
+    }
+
+    fn property_signature(&self, id: NodeId) -> PropertySignature<'a> {
+        return match self.node(id) {
+            Node::PropertySignature {
+                key,
+                optional,
+                type_annotation,
+            } => PropertySignature {
+                typ: "PropertySignature",
+                key: self.identifier(*key),
+                optional: *optional,
+                type_annotation: self.type_annotation(*type_annotation),
+            },
+            node => unexpected(node),
+        };
+    }
+}
+
+// The parser and the conversion of an owned tree store the same nodes, so a
+// node is always found where its parent expects it.
+fn unexpected(node: &Node) -> ! {
+    panic!("Unexpected node in Ast: {:?}", node);
+}
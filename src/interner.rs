@@ -0,0 +1,70 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+
+// A handle to a string stored in an Interner. Symbols from the same interner
+// are equal exactly when their strings are.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Symbol(u32);
+
+impl Symbol {
+    pub fn index(self) -> usize {
+        return self.0 as usize;
+    }
+}
+
+// Stores each distinct string once, handing out a Symbol for it. Strings are
+// never removed, so a Symbol stays valid for as long as its interner lives.
+#[derive(Debug, Default, Clone)]
+pub struct Interner {
+    symbols: HashMap<Arc<str>, Symbol>,
+    strings: Vec<Arc<str>>,
+}
+
+impl Interner {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    // Returns the symbol of the string, storing the string the first time
+    // it's seen.
+    pub fn intern(&mut self, string: &str) -> Symbol {
+        if let Some(symbol) = self.symbols.get(string) {
+            return *symbol;
+        }
+
+        let symbol = Symbol(self.strings.len() as u32);
+        let string: Arc<str> = Arc::from(string);
+        self.strings.push(Arc::clone(&string));
+        self.symbols.insert(string, symbol);
+        return symbol;
+    }
+
+    // Returns the symbol of the string if it has been interned.
+    pub fn get(&self, string: &str) -> Option<Symbol> {
+        return self.symbols.get(string).copied();
+    }
+
+    pub fn resolve(&self, symbol: Symbol) -> &str {
+        return &self.strings[symbol.index()];
+    }
+
+    // Like `resolve`, but the string can be kept once the interner is
+    // unlocked.
+    pub fn resolve_shared(&self, symbol: Symbol) -> Arc<str> {
+        return Arc::clone(&self.strings[symbol.index()]);
+    }
+
+    pub fn len(&self) -> usize {
+        return self.strings.len();
+    }
+
+    pub fn is_empty(&self) -> bool {
+        return self.strings.is_empty();
+    }
+
+    // Removes every string, invalidating the symbols handed out so far.
+    pub fn clear(&mut self) {
+        self.symbols.clear();
+        self.strings.clear();
+    }
+}
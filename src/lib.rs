@@ -1,10 +1,26 @@
+#![allow(clippy::needless_return)]
+pub mod arena;
+mod interner;
 mod nodes;
 mod parser;
+mod stream;
 mod tokenizer;
 use self::parser::Parser;
+pub use interner::{Interner, Symbol};
 pub use nodes::*;
+pub use parser::{
+    Associativity, BinaryOperator, EcmaVersion, Limit, ParseError, ParserLimits, ParserOptions,
+    SourceType,
+};
+pub use stream::{StatementStream, StreamError, StreamTokenizer};
+pub use tokenizer::{TokenRule, TokenSpec, TokenSpecError, Tokenizer};
 
 pub fn init<'a>() -> Parser<'a> {
     let parser = Parser::new();
     return parser;
 }
+
+pub fn init_with<'a>(options: ParserOptions) -> Parser<'a> {
+    let parser = Parser::with_options(options);
+    return parser;
+}
@@ -1,12 +1,20 @@
-#[derive(Debug, Clone, PartialEq)]
+use std::borrow::Cow;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub struct Token<'a> {
-    pub typ: &'a str,
-    pub value: String,
+    pub typ: &'static str,
+    // The text of the token, borrowed from the source.
+    pub value: &'a str,
+    pub start: usize,
+    pub end: usize,
+    pub newline_before: bool,
 }
 
 #[derive(Debug, PartialEq)]
 pub struct Program<'a> {
     pub typ: &'a str,
+    // The text of a leading `#!` line, without the `#!`.
+    pub hashbang: Option<Cow<'a, str>>,
     pub body: Vec<Statement<'a>>,
 }
 
@@ -16,41 +24,194 @@ pub enum Statement<'a> {
     BlockStatement(BlockStatement<'a>),
     VariableStatement(VariableStatement<'a>),
     IfStatement(IfStatement<'a>),
+    FunctionDeclaration(FunctionDeclaration<'a>),
+    ClassDeclaration(ClassDeclaration<'a>),
+    ReturnStatement(ReturnStatement<'a>),
+    ThrowStatement(ThrowStatement<'a>),
     EmptyStatement { typ: &'a str },
+    ModuleDeclaration(ModuleDeclaration<'a>),
+    Directive(Directive<'a>),
+    TypeAliasDeclaration(TypeAliasDeclaration<'a>),
+    InterfaceDeclaration(InterfaceDeclaration<'a>),
 }
 
+// A string literal statement in the prologue of a program or function body,
+// such as "use strict". `directive` is the raw text between the quotes.
 #[derive(Debug, Clone, PartialEq)]
-pub struct IfStatement<'a> {
+pub struct Directive<'a> {
     pub typ: &'a str,
-    pub test: Test<'a>,
-    pub consequent: Box<Statement<'a>>,
-    pub alternate: Option<Box<Statement<'a>>>,
+    pub expression: StringLiteral<'a>,
+    pub directive: Cow<'a, str>,
 }
 
 #[derive(Debug, Clone, PartialEq)]
-pub enum Test<'a> {
-    Literal(Literal<'a>),
-    Identifier(Identifier<'a>),
-    BinaryExpression(BinaryExpression<'a>),
+pub enum ModuleDeclaration<'a> {
+    ImportDeclaration(ImportDeclaration<'a>),
+    ExportNamedDeclaration(ExportNamedDeclaration<'a>),
+    ExportDefaultDeclaration(ExportDefaultDeclaration<'a>),
+    ExportAllDeclaration(ExportAllDeclaration<'a>),
 }
 
 #[derive(Debug, Clone, PartialEq)]
-pub struct VariableStatement<'a> {
+pub struct ImportDeclaration<'a> {
     pub typ: &'a str,
-    pub declarations: Vec<VariableDeclaration<'a>>,
+    pub specifiers: Vec<ImportSpecifierKind<'a>>,
+    pub source: StringLiteral<'a>,
 }
 
 #[derive(Debug, Clone, PartialEq)]
-pub enum VariableInitializer<'a> {
-    AssignmentExpression(AssignmentExpression<'a>),
-    Literal(Literal<'a>),
+pub enum ImportSpecifierKind<'a> {
+    ImportSpecifier(ImportSpecifier<'a>),
+    ImportDefaultSpecifier(ImportDefaultSpecifier<'a>),
+    ImportNamespaceSpecifier(ImportNamespaceSpecifier<'a>),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ImportSpecifier<'a> {
+    pub typ: &'a str,
+    pub imported: Identifier<'a>,
+    pub local: Identifier<'a>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ImportDefaultSpecifier<'a> {
+    pub typ: &'a str,
+    pub local: Identifier<'a>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ImportNamespaceSpecifier<'a> {
+    pub typ: &'a str,
+    pub local: Identifier<'a>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ExportNamedDeclaration<'a> {
+    pub typ: &'a str,
+    pub declaration: Option<Box<Statement<'a>>>,
+    pub specifiers: Vec<ExportSpecifier<'a>>,
+    pub source: Option<StringLiteral<'a>>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ExportSpecifier<'a> {
+    pub typ: &'a str,
+    pub local: Identifier<'a>,
+    pub exported: Identifier<'a>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ExportDefaultDeclaration<'a> {
+    pub typ: &'a str,
+    pub declaration: ExportDefaultKind<'a>,
+}
+
+// Named functions and classes are declared by the export, while anonymous
+// ones are exported as expressions.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ExportDefaultKind<'a> {
+    FunctionDeclaration(FunctionDeclaration<'a>),
+    ClassDeclaration(ClassDeclaration<'a>),
+    Expression(Expression<'a>),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ExportAllDeclaration<'a> {
+    pub typ: &'a str,
+    pub exported: Option<Identifier<'a>>,
+    pub source: StringLiteral<'a>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct FunctionDeclaration<'a> {
+    pub typ: &'a str,
+    pub id: Identifier<'a>,
+    pub params: Vec<Identifier<'a>>,
+    pub body: BlockStatement<'a>,
+    pub is_async: bool,
+    pub is_generator: bool,
+    pub return_type: Option<Box<TypeAnnotation<'a>>>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ClassDeclaration<'a> {
+    pub typ: &'a str,
+    pub id: Identifier<'a>,
+    pub super_class: Option<Box<Expression<'a>>>,
+    pub body: ClassBody<'a>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ClassBody<'a> {
+    pub typ: &'a str,
+    pub body: Vec<ClassElement<'a>>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ClassElement<'a> {
+    MethodDefinition(MethodDefinition<'a>),
+    PropertyDefinition(PropertyDefinition<'a>),
+    StaticBlock(StaticBlock<'a>),
+}
+
+// `kind` is one of "constructor", "method", "get" or "set".
+#[derive(Debug, Clone, PartialEq)]
+pub struct MethodDefinition<'a> {
+    pub typ: &'a str,
+    pub key: Box<Expression<'a>>,
+    pub value: FunctionExpression<'a>,
+    pub kind: &'a str,
+    pub computed: bool,
+    pub is_static: bool,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct PropertyDefinition<'a> {
+    pub typ: &'a str,
+    pub key: Box<Expression<'a>>,
+    pub value: Option<Box<Expression<'a>>>,
+    pub computed: bool,
+    pub is_static: bool,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct StaticBlock<'a> {
+    pub typ: &'a str,
+    pub body: Vec<Statement<'a>>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ReturnStatement<'a> {
+    pub typ: &'a str,
+    pub argument: Option<Expression<'a>>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ThrowStatement<'a> {
+    pub typ: &'a str,
+    pub argument: Expression<'a>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct IfStatement<'a> {
+    pub typ: &'a str,
+    pub test: Expression<'a>,
+    pub consequent: Box<Statement<'a>>,
+    pub alternate: Option<Box<Statement<'a>>>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct VariableStatement<'a> {
+    pub typ: &'a str,
+    pub kind: &'a str,
+    pub declarations: Vec<VariableDeclaration<'a>>,
 }
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct VariableDeclaration<'a> {
     pub typ: &'a str,
     pub id: Identifier<'a>,
-    pub init: Option<VariableInitializer<'a>>,
+    pub init: Option<Expression<'a>>,
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -69,27 +230,79 @@ pub struct BlockStatement<'a> {
 pub enum Expression<'a> {
     Literal(Literal<'a>),
     BinaryExpression(BinaryExpression<'a>),
+    UnaryExpression(UnaryExpression<'a>),
     LogicalExpression(LogicalExpression<'a>),
     AssignmentExpression(AssignmentExpression<'a>),
+    SequenceExpression(SequenceExpression<'a>),
+    FunctionExpression(FunctionExpression<'a>),
+    ClassExpression(ClassExpression<'a>),
+    JSXElement(JSXElement<'a>),
+    JSXFragment(JSXFragment<'a>),
+    AwaitExpression(AwaitExpression<'a>),
+    YieldExpression(YieldExpression<'a>),
     LeftHandSideExpression(LeftHandSideExpression<'a>),
 }
 
 #[derive(Debug, Clone, PartialEq)]
 pub enum LeftHandSideExpression<'a> {
     Identifier(Identifier<'a>),
+    PrivateIdentifier(PrivateIdentifier<'a>),
+    ThisExpression(ThisExpression<'a>),
+    Super(Super<'a>),
+    MemberExpression(MemberExpression<'a>),
+    CallExpression(CallExpression<'a>),
+    ChainExpression(ChainExpression<'a>),
+    ImportExpression(ImportExpression<'a>),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct MemberExpression<'a> {
+    pub typ: &'a str,
+    pub object: Box<Expression<'a>>,
+    pub property: Box<Expression<'a>>,
+    pub computed: bool,
+    pub optional: bool,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct CallExpression<'a> {
+    pub typ: &'a str,
+    pub callee: Box<Expression<'a>>,
+    pub arguments: Vec<Expression<'a>>,
+    pub optional: bool,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ChainExpression<'a> {
+    pub typ: &'a str,
+    pub expression: Box<Expression<'a>>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ImportExpression<'a> {
+    pub typ: &'a str,
+    pub source: Box<Expression<'a>>,
 }
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct BinaryExpression<'a> {
     pub typ: &'a str,
-    pub operator: String,
+    pub operator: Cow<'a, str>,
     pub left: Box<Expression<'a>>,
     pub right: Box<Expression<'a>>,
 }
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct UnaryExpression<'a> {
+    pub typ: &'a str,
+    pub operator: Cow<'a, str>,
+    pub argument: Box<Expression<'a>>,
+}
+
 #[derive(Debug, Clone, PartialEq)]
 pub struct LogicalExpression<'a> {
     pub typ: &'a str,
-    pub operator: String,
+    pub operator: Cow<'a, str>,
     pub left: Box<Expression<'a>>,
     pub right: Box<Expression<'a>>,
 }
@@ -100,31 +313,94 @@ pub enum Literal<'a> {
     StringLiteral(StringLiteral<'a>),
     BooleanLiteral(BooleanLiteral<'a>),
     NullLiteral(NullLiteral<'a>),
+    RegExpLiteral(RegExpLiteral<'a>),
 }
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct AssignmentExpression<'a> {
     pub typ: &'a str,
-    pub operator: String,
-    pub left: Identifier<'a>,
+    pub operator: Cow<'a, str>,
+    pub left: Box<Expression<'a>>,
     pub right: Box<Expression<'a>>,
 }
 
+#[derive(Debug, Clone, PartialEq)]
+pub struct FunctionExpression<'a> {
+    pub typ: &'a str,
+    pub id: Option<Identifier<'a>>,
+    pub params: Vec<Identifier<'a>>,
+    pub body: BlockStatement<'a>,
+    pub is_async: bool,
+    pub is_generator: bool,
+    pub return_type: Option<Box<TypeAnnotation<'a>>>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ClassExpression<'a> {
+    pub typ: &'a str,
+    pub id: Option<Identifier<'a>>,
+    pub super_class: Option<Box<Expression<'a>>>,
+    pub body: ClassBody<'a>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct AwaitExpression<'a> {
+    pub typ: &'a str,
+    pub argument: Box<Expression<'a>>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct YieldExpression<'a> {
+    pub typ: &'a str,
+    pub argument: Option<Box<Expression<'a>>>,
+    pub delegate: bool,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct SequenceExpression<'a> {
+    pub typ: &'a str,
+    pub expressions: Vec<Expression<'a>>,
+}
+
 #[derive(Debug, Clone, PartialEq)]
 pub struct Identifier<'a> {
     pub typ: &'a str,
-    pub name: String,
+    pub name: Cow<'a, str>,
+    // Only set with the `types` feature, as are `optional` and the
+    // `return_type` of functions.
+    pub type_annotation: Option<Box<TypeAnnotation<'a>>>,
+    // Set on parameters declared as `name?`.
+    pub optional: bool,
+}
+
+// A `#name` class member. The name doesn't include the `#`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PrivateIdentifier<'a> {
+    pub typ: &'a str,
+    pub name: Cow<'a, str>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ThisExpression<'a> {
+    pub typ: &'a str,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Super<'a> {
+    pub typ: &'a str,
 }
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct NumericLiteral<'a> {
     pub typ: &'a str,
-    pub value: i64,
+    pub value: f64,
+    pub raw: Cow<'a, str>,
 }
 #[derive(Debug, Clone, PartialEq)]
 pub struct StringLiteral<'a> {
     pub typ: &'a str,
-    pub value: String,
+    pub value: Cow<'a, str>,
+    pub raw: Cow<'a, str>,
 }
 #[derive(Debug, Clone, PartialEq)]
 pub struct BooleanLiteral<'a> {
@@ -136,3 +412,177 @@ pub struct BooleanLiteral<'a> {
 pub struct NullLiteral<'a> {
     pub typ: &'a str,
 }
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct RegExpLiteral<'a> {
+    pub typ: &'a str,
+    pub pattern: Cow<'a, str>,
+    pub flags: Cow<'a, str>,
+    pub raw: Cow<'a, str>,
+}
+
+// Nodes of the JSX dialect.
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct JSXElement<'a> {
+    pub typ: &'a str,
+    pub name: JSXElementName<'a>,
+    pub attributes: Vec<JSXAttributeKind<'a>>,
+    pub children: Vec<JSXChild<'a>>,
+    pub self_closing: bool,
+}
+
+// A `<>...</>` fragment.
+#[derive(Debug, Clone, PartialEq)]
+pub struct JSXFragment<'a> {
+    pub typ: &'a str,
+    pub children: Vec<JSXChild<'a>>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum JSXElementName<'a> {
+    JSXIdentifier(JSXIdentifier<'a>),
+    JSXMemberExpression(JSXMemberExpression<'a>),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct JSXIdentifier<'a> {
+    pub typ: &'a str,
+    pub name: Cow<'a, str>,
+}
+
+// A dotted element name such as `Foo.Bar`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct JSXMemberExpression<'a> {
+    pub typ: &'a str,
+    pub object: Box<JSXElementName<'a>>,
+    pub property: JSXIdentifier<'a>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum JSXAttributeKind<'a> {
+    JSXAttribute(JSXAttribute<'a>),
+    JSXSpreadAttribute(JSXSpreadAttribute<'a>),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct JSXAttribute<'a> {
+    pub typ: &'a str,
+    pub name: JSXIdentifier<'a>,
+    pub value: Option<JSXAttributeValue<'a>>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum JSXAttributeValue<'a> {
+    StringLiteral(StringLiteral<'a>),
+    JSXExpressionContainer(JSXExpressionContainer<'a>),
+    JSXElement(JSXElement<'a>),
+    JSXFragment(JSXFragment<'a>),
+}
+
+// A `{...rest}` attribute.
+#[derive(Debug, Clone, PartialEq)]
+pub struct JSXSpreadAttribute<'a> {
+    pub typ: &'a str,
+    pub argument: Expression<'a>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum JSXChild<'a> {
+    JSXText(JSXText<'a>),
+    JSXExpressionContainer(JSXExpressionContainer<'a>),
+    JSXElement(JSXElement<'a>),
+    JSXFragment(JSXFragment<'a>),
+}
+
+// The expression is `None` for an empty `{}` or a `{/* comment */}`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct JSXExpressionContainer<'a> {
+    pub typ: &'a str,
+    pub expression: Option<Box<Expression<'a>>>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct JSXText<'a> {
+    pub typ: &'a str,
+    pub value: Cow<'a, str>,
+}
+
+// Nodes of the optional type annotations dialect. They're always defined,
+// so enabling the `types` feature doesn't change the shape of other nodes,
+// but are only produced by parsers built with it.
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct TypeAnnotation<'a> {
+    pub typ: &'a str,
+    pub type_annotation: Type<'a>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Type<'a> {
+    KeywordType(KeywordType<'a>),
+    TypeReference(TypeReference<'a>),
+    LiteralType(LiteralType<'a>),
+    ArrayType(ArrayType<'a>),
+    UnionType(UnionType<'a>),
+}
+
+// A predefined type such as `number` or `string`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct KeywordType<'a> {
+    pub typ: &'a str,
+    pub name: Cow<'a, str>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct TypeReference<'a> {
+    pub typ: &'a str,
+    pub name: Cow<'a, str>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct LiteralType<'a> {
+    pub typ: &'a str,
+    pub literal: Literal<'a>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ArrayType<'a> {
+    pub typ: &'a str,
+    pub element_type: Box<Type<'a>>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct UnionType<'a> {
+    pub typ: &'a str,
+    pub types: Vec<Type<'a>>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct TypeAliasDeclaration<'a> {
+    pub typ: &'a str,
+    pub id: Identifier<'a>,
+    pub type_annotation: Type<'a>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct InterfaceDeclaration<'a> {
+    pub typ: &'a str,
+    pub id: Identifier<'a>,
+    pub extends: Vec<TypeReference<'a>>,
+    pub body: InterfaceBody<'a>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct InterfaceBody<'a> {
+    pub typ: &'a str,
+    pub body: Vec<PropertySignature<'a>>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct PropertySignature<'a> {
+    pub typ: &'a str,
+    pub key: Identifier<'a>,
+    pub optional: bool,
+    pub type_annotation: TypeAnnotation<'a>,
+}
@@ -1,11 +1,268 @@
+use super::arena::{Ast, Node, NodeId, NodeList};
+use super::interner::Interner;
 use super::nodes::*;
 use super::tokenizer::Tokenizer;
+use regex::Regex;
+use std::borrow::Cow;
+use std::collections::{HashMap, HashSet};
+use std::iter::Peekable;
+use std::str::CharIndices;
+use std::sync::{Arc, Mutex};
+
+mod jsx;
+mod operators;
+#[cfg(feature = "types")]
+mod types;
+
+pub use operators::{Associativity, BinaryOperator};
+
+// Reserved words that aren't keywords of the grammar yet, but still can't
+// be used as identifiers.
+const RESERVED_WORDS: [&str; 19] = [
+    "break",
+    "case",
+    "catch",
+    "continue",
+    "debugger",
+    "delete",
+    "do",
+    "enum",
+    "finally",
+    "for",
+    "in",
+    "instanceof",
+    "new",
+    "switch",
+    "try",
+    "typeof",
+    "void",
+    "while",
+    "with",
+];
+
+// Words that are only reserved in strict mode code.
+const STRICT_RESERVED_WORDS: [&str; 9] = [
+    "implements",
+    "interface",
+    "let",
+    "package",
+    "private",
+    "protected",
+    "public",
+    "static",
+    "yield",
+];
+
+// Whether the source is a classic script or an ES module. `import` and
+// `export` declarations are only allowed in modules.
+#[derive(Default, Debug, Clone, Copy, PartialEq)]
+pub enum SourceType {
+    #[default]
+    Script,
+    Module,
+}
+
+// The language level to accept. `Es5` rejects the syntax added since, such
+// as `let`, classes, modules, async functions and generators.
+#[derive(Default, Debug, Clone, Copy, PartialEq)]
+pub enum EcmaVersion {
+    Es5,
+    #[default]
+    Latest,
+}
+
+// Configures the grammar accepted by a Parser. The defaults parse a modern
+// script with automatic semicolon insertion.
+#[derive(Debug, Clone)]
+pub struct ParserOptions {
+    pub ecma_version: EcmaVersion,
+    pub source_type: SourceType,
+    // Parses the source as strict mode code from the start. Modules always
+    // are.
+    pub strict: bool,
+    pub automatic_semicolon_insertion: bool,
+    pub allow_return_outside_function: bool,
+    // Experimental syntax. JSX is the only extension to the language parsed
+    // so far, so it's the only toggle.
+    pub jsx: bool,
+    // The infix operators of expressions, with their precedence.
+    pub binary_operators: Vec<BinaryOperator>,
+    pub limits: ParserLimits,
+}
+
+impl Default for ParserOptions {
+    fn default() -> Self {
+        Self {
+            ecma_version: EcmaVersion::Latest,
+            source_type: SourceType::Script,
+            strict: false,
+            automatic_semicolon_insertion: true,
+            allow_return_outside_function: false,
+            jsx: false,
+            binary_operators: BinaryOperator::defaults(),
+            limits: ParserLimits::default(),
+        }
+    }
+}
+
+// Bounds on the resources a parse may use, for parsing untrusted input.
+// Nesting depth is limited by default, as deeply nested source would
+// otherwise overflow the stack.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ParserLimits {
+    pub max_depth: usize,
+    pub max_tokens: usize,
+    pub max_input_bytes: usize,
+    // In bytes, between the quotes of the literal as written.
+    pub max_string_length: usize,
+}
+
+impl Default for ParserLimits {
+    fn default() -> Self {
+        Self {
+            max_depth: 128,
+            max_tokens: usize::MAX,
+            max_input_bytes: usize::MAX,
+            max_string_length: usize::MAX,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Limit {
+    Depth,
+    Tokens,
+    InputBytes,
+    StringLength,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ParseError {
+    // A limit of ParserLimits was exceeded at byte `offset` of the source.
+    LimitExceeded {
+        limit: Limit,
+        max: usize,
+        offset: usize,
+    },
+    SyntaxError {
+        message: String,
+    },
+}
+
+impl std::fmt::Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            ParseError::LimitExceeded { limit, max, offset } => {
+                let name = match limit {
+                    Limit::Depth => "nesting depth",
+                    Limit::Tokens => "number of tokens",
+                    Limit::InputBytes => "input size",
+                    Limit::StringLength => "string literal length",
+                };
+                write!(
+                    f,
+                    "Maximum {} of {} exceeded at offset {}",
+                    name, max, offset
+                )
+            }
+            ParseError::SyntaxError { message } => write!(f, "{}", message),
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+type ParseResult<T> = Result<T, ParseError>;
+
+fn syntax_error(message: impl Into<String>) -> ParseError {
+    return ParseError::SyntaxError {
+        message: message.into(),
+    };
+}
+
+// The panicking entry points fail with the message of the error.
+fn or_panic<T>(result: ParseResult<T>) -> T {
+    return match result {
+        Ok(node) => node,
+        Err(error) => panic!("{}", error),
+    };
+}
+
+// Tracks the kind of the function being parsed, which decides whether
+// `await` and `yield` are keywords or plain identifiers.
+#[derive(Default, Debug, Clone, Copy)]
+struct FunctionContext {
+    is_async: bool,
+    is_generator: bool,
+}
+
+// Private names declared by the class being parsed, with their kind and
+// whether they're static, and the `#name` references found in its body.
+// References are resolved when the class ends, since a method may use a
+// name declared further down.
+#[derive(Default, Debug, Clone)]
+struct ClassContext<'a> {
+    private_names: HashMap<Cow<'a, str>, (&'static str, bool)>,
+    references: Vec<(Cow<'a, str>, usize)>,
+}
+
+// The names bound in a block, with the kind of their declaration. `var`
+// declarations are recorded in every scope up to the nearest function
+// scope, where they're hoisted to.
+#[derive(Default, Debug, Clone)]
+struct Scope<'a> {
+    names: HashMap<Cow<'a, str>, &'a str>,
+    is_function: bool,
+}
+
+impl<'a> Scope<'a> {
+    fn function() -> Self {
+        return Scope {
+            names: HashMap::new(),
+            is_function: true,
+        };
+    }
+}
+
+// Where a statement appears, which decides what it may declare.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum StatementPosition {
+    // The body of an `if` or `else`.
+    Single,
+    // The statements of a block or function body.
+    List,
+    // The body of the program, where modules import and export.
+    Program,
+}
+
+// The parts shared by function declarations, expressions and methods.
+struct FunctionParts {
+    params: NodeList,
+    body: NodeId,
+    return_type: Option<NodeId>,
+}
 
 #[derive(Default, Debug)]
 pub struct Parser<'a> {
     pub string: &'a str,
     pub lookahead: Option<Token<'a>>,
     pub tokenizer: Tokenizer<'a>,
+    pub options: ParserOptions,
+    scopes: Vec<Scope<'a>>,
+    functions: Vec<FunctionContext>,
+    classes: Vec<ClassContext<'a>>,
+    // Whether the code being parsed is strict mode code.
+    strict: bool,
+    // Productions currently being parsed inside one another.
+    depth: usize,
+    tokens: usize,
+    // The nodes are built into it, and converted by the entry points that
+    // return owned nodes. Its storage is kept from one parse to the next,
+    // while its interner only holds the names of the last parse.
+    ast: Ast<'a>,
+    // Shared with the Asts of `parse_into`, the only ones whose symbols
+    // outlive a parse.
+    interner: Arc<Mutex<Interner>>,
 }
 
 impl<'a> Parser<'a> {
@@ -13,36 +270,268 @@ impl<'a> Parser<'a> {
         Self::default()
     }
 
-    pub fn parse(&mut self, string: &'a str) -> Program {
-        self.string = string.clone();
+    pub fn with_options(options: ParserOptions) -> Self {
+        Self {
+            options,
+            ..Self::default()
+        }
+    }
+
+    pub fn parse(&mut self, string: &'a str) -> Program<'a> {
+        return or_panic(self.try_parse(string));
+    }
+
+    // Like `parse`, but returns syntax errors and exceeded limits instead of
+    // panicking.
+    pub fn try_parse(&mut self, string: &'a str) -> Result<Program<'a>, ParseError> {
+        self.clear_names();
+        self.build(string)?;
+        return Ok(self.ast.to_program());
+    }
+
+    // The interner of the Asts built by `parse_into`. It's kept from one
+    // parse to the next, so a name parsed again gets the same symbol.
+    pub fn interner(&self) -> &Arc<Mutex<Interner>> {
+        return &self.interner;
+    }
+
+    // Makes the parser intern names into an interner shared with others.
+    pub fn set_interner(&mut self, interner: Arc<Mutex<Interner>>) {
+        self.interner = interner;
+    }
+
+    // The owned trees hold no symbols, so the names interned to build one
+    // are dropped before the next, keeping a long-lived parser from growing.
+    fn clear_names(&mut self) {
+        self.ast.interner().lock().unwrap().clear();
+    }
+
+    // Parses the source into an Ast, replacing its nodes so its storage can
+    // be reused from one parse to the next. The Ast is made to share the
+    // interner of the parser, so its symbols match those of earlier parses.
+    pub fn parse_into(&mut self, string: &'a str, ast: &mut Ast<'a>) -> NodeId {
+        if !Arc::ptr_eq(ast.interner(), &self.interner) {
+            *ast = Ast::with_interner(Arc::clone(&self.interner));
+        }
+        std::mem::swap(&mut self.ast, ast);
+        let root = self.build(string);
+        std::mem::swap(&mut self.ast, ast);
+        return or_panic(root);
+    }
+
+    // Parses the program into the Ast of the parser, returning its root.
+    fn build(&mut self, string: &'a str) -> ParseResult<NodeId> {
+        self.start(string)?;
+        let hashbang = self.tokenizer.hashbang();
+        self.lookahead = self.next_token()?;
+        return self.program(hashbang);
+    }
+
+    // Parses the source as a module, whatever the `source_type` option is.
+    pub fn parse_module(&mut self, string: &'a str) -> Program<'a> {
+        return or_panic(self.try_parse_module(string));
+    }
+
+    pub fn try_parse_module(&mut self, string: &'a str) -> Result<Program<'a>, ParseError> {
+        let source_type = std::mem::replace(&mut self.options.source_type, SourceType::Module);
+        let program = self.try_parse(string);
+        self.options.source_type = source_type;
+        return program;
+    }
+
+    // Parses a single statement, which must span the whole source.
+    pub fn parse_statement(&mut self, string: &'a str) -> Statement<'a> {
+        return or_panic(self.try_parse_statement(string));
+    }
+
+    pub fn try_parse_statement(&mut self, string: &'a str) -> Result<Statement<'a>, ParseError> {
+        let statement = self.whole(string, |parser| {
+            parser.statement(StatementPosition::Program)
+        })?;
+        return Ok(self.ast.statement(statement));
+    }
+
+    // Parses a single expression, such as `age >= 18 && active`, which must
+    // span the whole source.
+    pub fn parse_expression(&mut self, string: &'a str) -> Expression<'a> {
+        return or_panic(self.try_parse_expression(string));
+    }
+
+    pub fn try_parse_expression(&mut self, string: &'a str) -> Result<Expression<'a>, ParseError> {
+        let expression = self.whole(string, Self::expression)?;
+        return Ok(self.ast.expression(expression));
+    }
+
+    // Parses a production which must span the whole source.
+    fn whole<T>(
+        &mut self,
+        string: &'a str,
+        production: impl FnOnce(&mut Self) -> ParseResult<T>,
+    ) -> ParseResult<T> {
+        self.clear_names();
+        self.start(string)?;
+        self.lookahead = self.next_token()?;
+        let node = production(self)?;
+        self.end_of_input()?;
+        return Ok(node);
+    }
+
+    // Parses the statement starting at offset `start` of the source, as a
+    // directive when `in_prologue` is set, leaving the token that follows it
+    // as the lookahead. Statement streams parse their input this way, one
+    // top-level statement at a time.
+    pub(crate) fn statement_at(
+        &mut self,
+        string: &'a str,
+        start: usize,
+        in_prologue: bool,
+    ) -> ParseResult<Statement<'a>> {
+        self.clear_names();
+        self.start(string)?;
+        self.tokenizer.cursor = start as u64;
+        self.lookahead = self.next_token()?;
+        let statement = if in_prologue && self.lookahead_type() == "STRING" {
+            self.directive()?
+        } else {
+            self.statement(StatementPosition::Program)?
+        };
+        return Ok(self.ast.statement(statement));
+    }
+
+    // Drops what the parser borrowed from the last source, keeping its
+    // options, its interner and the storage of its Ast, so it can go on to
+    // parse a source borrowed for another lifetime. The tokenizer goes back
+    // to the default one.
+    pub(crate) fn reset<'b>(self) -> Parser<'b> {
+        return Parser {
+            string: "",
+            lookahead: None,
+            tokenizer: Tokenizer::default(),
+            options: self.options,
+            scopes: Vec::new(),
+            functions: self.functions,
+            classes: Vec::new(),
+            strict: false,
+            depth: 0,
+            tokens: 0,
+            ast: self.ast.reset(),
+            interner: self.interner,
+        };
+    }
+
+    fn start(&mut self, string: &'a str) -> ParseResult<()> {
+        let max_input_bytes = self.options.limits.max_input_bytes;
+        if string.len() > max_input_bytes {
+            return Err(self.limit_exceeded(Limit::InputBytes, max_input_bytes, max_input_bytes));
+        }
+        self.string = string;
+        self.depth = 0;
+        self.tokens = 0;
+        self.scopes = vec![Scope::function()];
+        self.functions = Vec::new();
+        self.classes = Vec::new();
+        self.strict = self.options.strict || self.options.source_type == SourceType::Module;
+        self.ast.clear();
         self.tokenizer.init(string);
-        self.lookahead = self.tokenizer.next();
-        return self.program();
+        return Ok(());
+    }
+
+    fn end_of_input(&self) -> ParseResult<()> {
+        if self.lookahead.is_some() {
+            return Err(syntax_error(format!(
+                "Unexpected token: {}, expected: end of input",
+                self.lookahead_type()
+            )));
+        }
+        return Ok(());
     }
 
     // Program
-    //  : StatementList
+    //  : OptHashbang OptDirectivePrologue OptStatementList
     //  ;
-    fn program(&mut self) -> Program {
-        return Program {
-            typ: "Program",
-            body: self.statement_list(""),
+    fn program(&mut self, hashbang: Option<&'a str>) -> ParseResult<NodeId> {
+        let items = self.ast.start_list();
+        self.directive_prologue()?;
+        if self.lookahead.is_some() {
+            self.statement_list("", StatementPosition::Program)?;
+        }
+        let body = self.ast.finish_list(items);
+
+        return Ok(self.ast.add(Node::Program {
+            hashbang: hashbang.map(Cow::Borrowed),
+            body,
+        }));
+    }
+
+    // DirectivePrologue
+    //  : Directive
+    //  | DirectivePrologue Directive
+    //  ;
+    //
+    // The prologue ends at the first statement that isn't a lone string
+    // literal, which is added as a regular statement. The statements are
+    // added to the list being built.
+    fn directive_prologue(&mut self) -> ParseResult<()> {
+        while self.lookahead_type() == "STRING" {
+            let statement = self.directive()?;
+            self.ast.push_item(statement);
+            if !matches!(self.ast.node(statement), Node::Directive { .. }) {
+                break;
+            }
+        }
+
+        return Ok(());
+    }
+
+    // Directive
+    //  : StringLiteral ";"
+    //  ;
+    //
+    // Returns a regular statement when the string literal is only the start
+    // of one, which can't be a declaration.
+    fn directive(&mut self) -> ParseResult<NodeId> {
+        let raw = self.lookahead_value();
+        let statement = self.statement(StatementPosition::List)?;
+        let expression = match self.ast.node(statement) {
+            Node::ExpressionStatement { expression }
+                if matches!(self.ast.node(*expression), Node::StringLiteral { .. }) =>
+            {
+                *expression
+            }
+            _ => return Ok(statement),
+        };
+
+        let directive = Cow::Borrowed(&raw[1..raw.len() - 1]);
+        if directive == "use strict" {
+            self.strict = true;
+        }
+        *self.ast.node_mut(statement) = Node::Directive {
+            expression,
+            directive,
         };
+        return Ok(statement);
     }
 
     // StatementList
     // : Statement
     // | StatementList Statement
     // ;
-    fn statement_list(&mut self, stop_lookahead: &str) -> Vec<Statement<'a>> {
-        let mut statement_list = Vec::new();
-        statement_list.push(self.statement());
-
-        while !self.lookahead.is_none() && self.lookahead.as_ref().unwrap().typ != stop_lookahead {
-            statement_list.push(self.statement())
+    //
+    // The statements are added to the list being built.
+    fn statement_list(
+        &mut self,
+        stop_lookahead: &str,
+        position: StatementPosition,
+    ) -> ParseResult<()> {
+        let statement = self.statement(position)?;
+        self.ast.push_item(statement);
+
+        while self.lookahead.is_some() && self.lookahead_type() != stop_lookahead {
+            let statement = self.statement(position)?;
+            self.ast.push_item(statement);
         }
 
-        return statement_list;
+        return Ok(());
     }
 
     // Statement
@@ -51,270 +540,1472 @@ impl<'a> Parser<'a> {
     // | EmptyStatement
     // | VariableStatement
     // | IfStatement
+    // | FunctionDeclaration
+    // | ClassDeclaration
+    // | ReturnStatement
+    // | ThrowStatement
+    // | ModuleDeclaration
     // ;
-    fn statement(&mut self) -> Statement<'a> {
-        match self.lookahead.as_ref().unwrap().typ {
-            ";" => self.empty_statement(),
-            "{" => self.block_statement(),
-            "let" => self.variable_statement(),
-            "if" => self.if_statement(),
-            _ => self.expression_statement(),
+    fn statement(&mut self, position: StatementPosition) -> ParseResult<NodeId> {
+        return self.nested(|parser| match parser.lookahead_type() {
+            ";" => parser.empty_statement(),
+            "{" => parser.block_statement(),
+            "let" | "const" if position == StatementPosition::Single => Err(syntax_error(
+                "Lexical declaration cannot appear in a single-statement context",
+            )),
+            "let" | "const" | "var" => parser.variable_statement(),
+            "if" => parser.if_statement(),
+            _ if parser.is_function_start() => parser.function_declaration(),
+            "class" => parser.class_declaration(),
+            "return" => parser.return_statement(),
+            "throw" => parser.throw_statement(),
+            "export" => parser.module_declaration(position),
+            "import" if parser.peek_type() != Some("(") => parser.module_declaration(position),
+            #[cfg(feature = "types")]
+            _ if parser.is_type_declaration_start("type") => parser.type_alias_declaration(),
+            #[cfg(feature = "types")]
+            _ if parser.is_type_declaration_start("interface") => parser.interface_declaration(),
+            _ => parser.expression_statement(),
+        });
+    }
+
+    // FunctionDeclaration
+    //  : FunctionPrefix Identifier FunctionParamsAndBody
+    //  ;
+    fn function_declaration(&mut self) -> ParseResult<NodeId> {
+        let (is_async, is_generator) = self.function_prefix()?;
+        let id = self.binding_identifier("function")?;
+        let function = self.function_params_and_body(Some(id), is_async, is_generator)?;
+
+        return Ok(self.ast.add(Node::FunctionDeclaration {
+            id,
+            params: function.params,
+            body: function.body,
+            is_async,
+            is_generator,
+            return_type: function.return_type,
+        }));
+    }
+
+    // FunctionExpression
+    //  : FunctionPrefix OptIdentifier FunctionParamsAndBody
+    //  ;
+    fn function_expression(&mut self) -> ParseResult<NodeId> {
+        let (is_async, is_generator) = self.function_prefix()?;
+        let id = if self.lookahead_type() == "IDENTIFIER" {
+            Some(self.identifier()?)
+        } else {
+            None
+        };
+        let function = self.function_params_and_body(id, is_async, is_generator)?;
+
+        return Ok(self.ast.add(Node::FunctionExpression {
+            id,
+            params: function.params,
+            body: function.body,
+            is_async,
+            is_generator,
+            return_type: function.return_type,
+        }));
+    }
+
+    // FunctionPrefix
+    //  : OptAsync "function" OptStar
+    //  ;
+    fn function_prefix(&mut self) -> ParseResult<(bool, bool)> {
+        let is_async = self.is_contextual_keyword("async");
+        if is_async {
+            self.check_modern("async")?;
+            self.contextual_keyword("async")?;
+        }
+        self.eat("function")?;
+
+        let is_generator = self.lookahead_value() == "*";
+        if is_generator {
+            self.check_modern("function*")?;
+            self.eat_operator("*")?;
         }
+
+        return Ok((is_async, is_generator));
     }
 
-    // IfStatement
-    //  : "if" "(" Expression ")" Statement
-    //  : "if" "(" Expression ")" Statement "else" Statement
+    // `async` is only a keyword when followed by `function` on the same line.
+    fn is_function_start(&self) -> bool {
+        if self.is_contextual_keyword("async") {
+            let next = self.tokenizer.clone().try_next().ok().flatten();
+            return next.is_some_and(|t| t.typ == "function" && !t.newline_before);
+        }
+        return self.lookahead_type() == "function";
+    }
+
+    // FunctionParamsAndBody
+    //  : "(" OptFormalParameterList ")" OptTypeAnnotation FunctionBody
     //  ;
+    //
+    // Parameters and the body share one scope, so `let` can't redeclare a
+    // parameter.
+    fn function_params_and_body(
+        &mut self,
+        name: Option<NodeId>,
+        is_async: bool,
+        is_generator: bool,
+    ) -> ParseResult<FunctionParts> {
+        self.functions.push(FunctionContext {
+            is_async,
+            is_generator,
+        });
+        self.scopes.push(Scope::function());
+
+        let outer_strict = self.strict;
 
-    fn if_statement(&mut self) -> Statement<'a> {
-        self.eat("if");
-        self.eat("(");
-        let test = if self.lookahead.clone().unwrap().typ == "IDENTIFIER" {
-            Test::Identifier(self.identifier())
+        self.eat("(")?;
+        let params = if self.lookahead_type() != ")" {
+            self.formal_parameter_list()?
         } else {
-            match self.expression() {
-                Expression::Literal(lt) => match lt {
-                    Literal::StringLiteral(sl) => {
-                        Test::Literal(Literal::StringLiteral(StringLiteral { ..sl }))
-                    }
-                    Literal::NumericLiteral(nl) => {
-                        Test::Literal(Literal::NumericLiteral(NumericLiteral { ..nl }))
-                    }
-                    Literal::BooleanLiteral(bl) => {
-                        Test::Literal(Literal::BooleanLiteral(BooleanLiteral { ..bl }))
+            NodeList::default()
+        };
+        self.eat(")")?;
+        #[cfg(feature = "types")]
+        let return_type = self.opt_type_annotation()?;
+        #[cfg(not(feature = "types"))]
+        let return_type = None;
+        let body = self.function_body()?;
+        // A "use strict" directive in the body makes the name and parameters
+        // strict too, though they came before it.
+        if self.strict {
+            if let Some(name) = name {
+                self.check_strict_binding(&self.ast.name(name))?;
+            }
+            self.check_strict_params(params)?;
+        }
+
+        self.strict = outer_strict;
+        self.scopes.pop();
+        self.functions.pop();
+
+        return Ok(FunctionParts {
+            params,
+            body,
+            return_type,
+        });
+    }
+
+    // FunctionBody
+    //  : "{" OptDirectivePrologue OptStatementList "}"
+    //  ;
+    fn function_body(&mut self) -> ParseResult<NodeId> {
+        self.eat("{")?;
+        let items = self.ast.start_list();
+        self.directive_prologue()?;
+        if self.lookahead_type() != "}" {
+            self.statement_list("}", StatementPosition::List)?;
+        }
+        self.eat("}")?;
+        let body = self.ast.finish_list(items);
+
+        return Ok(self.ast.add(Node::BlockStatement { body }));
+    }
+
+    // A "use strict" directive also applies to the parameters parsed before
+    // it, and strict functions can't repeat a parameter name.
+    fn check_strict_params(&self, params: NodeList) -> ParseResult<()> {
+        let mut names = HashSet::new();
+        for param in self.ast.list(params) {
+            let name = self.ast.name(*param);
+            self.check_strict_binding(&name)?;
+            if !names.insert(name) {
+                return Err(syntax_error(
+                    "Duplicate parameter name not allowed in this context",
+                ));
+            }
+        }
+        return Ok(());
+    }
+
+    fn check_strict_binding(&self, name: &str) -> ParseResult<()> {
+        if STRICT_RESERVED_WORDS.contains(&name) {
+            return Err(syntax_error(format!(
+                "Unexpected strict mode reserved word '{}'",
+                name
+            )));
+        }
+        if name == "eval" || name == "arguments" {
+            return Err(syntax_error("Unexpected eval or arguments in strict mode"));
+        }
+        return Ok(());
+    }
+
+    // FormalParameterList
+    //  : FormalParameter
+    //  | FormalParameterList "," FormalParameter
+    //  ;
+    //
+    // FormalParameter
+    //  : Identifier OptOptional OptTypeAnnotation
+    //  ;
+    fn formal_parameter_list(&mut self) -> ParseResult<NodeList> {
+        let params = self.ast.start_list();
+
+        loop {
+            let name = self.binding_name("param")?;
+            #[cfg(feature = "types")]
+            let param = self.annotated_identifier(name, true)?;
+            #[cfg(not(feature = "types"))]
+            let param = self.identifier_node(name, None, false);
+            self.ast.push_item(param);
+            if self.lookahead_type() != "," {
+                break;
+            }
+            self.eat(",")?;
+        }
+
+        return Ok(self.ast.finish_list(params));
+    }
+
+    // ClassDeclaration
+    //  : "class" Identifier OptClassHeritage ClassBody
+    //  ;
+    //
+    // All parts of a class are strict mode code.
+    fn class_declaration(&mut self) -> ParseResult<NodeId> {
+        self.check_modern("class")?;
+        let outer_strict = std::mem::replace(&mut self.strict, true);
+        self.eat("class")?;
+        let id = self.binding_identifier("class")?;
+        let super_class = self.class_heritage()?;
+        let body = self.class_body()?;
+        self.strict = outer_strict;
+
+        return Ok(self.ast.add(Node::ClassDeclaration {
+            id,
+            super_class,
+            body,
+        }));
+    }
+
+    // ClassExpression
+    //  : "class" OptIdentifier OptClassHeritage ClassBody
+    //  ;
+    fn class_expression(&mut self) -> ParseResult<NodeId> {
+        self.check_modern("class")?;
+        let outer_strict = std::mem::replace(&mut self.strict, true);
+        self.eat("class")?;
+        let id = if self.lookahead_type() == "IDENTIFIER" {
+            Some(self.identifier()?)
+        } else {
+            None
+        };
+        let super_class = self.class_heritage()?;
+        let body = self.class_body()?;
+        self.strict = outer_strict;
+
+        return Ok(self.ast.add(Node::ClassExpression {
+            id,
+            super_class,
+            body,
+        }));
+    }
+
+    // ClassHeritage
+    //  : "extends" LeftHandSideExpression
+    //  ;
+    fn class_heritage(&mut self) -> ParseResult<Option<NodeId>> {
+        if self.lookahead_type() != "extends" {
+            return Ok(None);
+        }
+        self.eat("extends")?;
+        return Ok(Some(self.left_hand_side_expression()?));
+    }
+
+    // ClassBody
+    //  : "{" OptClassElementList "}"
+    //  ;
+    fn class_body(&mut self) -> ParseResult<NodeId> {
+        self.classes.push(ClassContext::default());
+        self.eat("{")?;
+
+        let elements = self.ast.start_list();
+        let mut has_constructor = false;
+        while self.lookahead_type() != "}" {
+            if self.lookahead_type() == ";" {
+                self.eat(";")?;
+                continue;
+            }
+            let element = self.class_element()?;
+            if let Node::MethodDefinition {
+                kind: "constructor",
+                ..
+            } = self.ast.node(element)
+            {
+                if has_constructor {
+                    return Err(syntax_error("A class may only have one constructor"));
+                }
+                has_constructor = true;
+            }
+            self.ast.push_item(element);
+        }
+        self.eat("}")?;
+        let body = self.ast.finish_list(elements);
+
+        self.resolve_private_references()?;
+
+        return Ok(self.ast.add(Node::ClassBody { body }));
+    }
+
+    // References to names the class doesn't declare are passed on to the
+    // enclosing class, and are an error at the outermost one.
+    fn resolve_private_references(&mut self) -> ParseResult<()> {
+        let class = self.classes.pop().unwrap();
+        for (name, offset) in class.references {
+            if class.private_names.contains_key(&name) {
+                continue;
+            }
+            match self.classes.last_mut() {
+                Some(outer) => outer.references.push((name, offset)),
+                None => {
+                    let (line, column) = self.tokenizer.location(offset);
+                    return Err(syntax_error(format!(
+                        "Private field '#{}' must be declared in an enclosing class ({}:{})",
+                        name, line, column
+                    )));
+                }
+            }
+        }
+        return Ok(());
+    }
+
+    // ClassElement
+    //  : OptStatic MethodDefinition
+    //  | OptStatic FieldDefinition ";"
+    //  | ClassStaticBlock
+    //  ;
+    //
+    // MethodDefinition
+    //  : OptAsync OptStar ClassElementName FunctionParamsAndBody
+    //  | "get" ClassElementName "(" ")" FunctionBody
+    //  | "set" ClassElementName "(" Identifier ")" FunctionBody
+    //  ;
+    //
+    // FieldDefinition
+    //  : ClassElementName OptInitializer
+    //  ;
+    fn class_element(&mut self) -> ParseResult<NodeId> {
+        let is_static = self.is_class_modifier("static");
+        if is_static {
+            self.contextual_keyword("static")?;
+            if self.lookahead_type() == "{" {
+                return self.class_static_block();
+            }
+        }
+
+        let is_async = self.is_class_modifier("async");
+        if is_async {
+            self.contextual_keyword("async")?;
+        }
+        let is_generator = self.lookahead_value() == "*";
+        if is_generator {
+            self.eat_operator("*")?;
+        }
+        let mut kind = "method";
+        if !is_async && !is_generator {
+            for accessor in ["get", "set"] {
+                if self.is_class_modifier(accessor) {
+                    self.contextual_keyword(accessor)?;
+                    kind = accessor;
+                    break;
+                }
+            }
+        }
+
+        let (key, computed) = self.class_element_name()?;
+        let name = match self.ast.node(key) {
+            _ if computed => None,
+            Node::Identifier { name, .. } | Node::StringLiteral { value: name, .. } => {
+                Some(self.ast.resolve(*name))
+            }
+            _ => None,
+        };
+        let name = name.as_deref();
+
+        if self.lookahead_type() != "(" {
+            if kind != "method" || is_async || is_generator {
+                return Err(syntax_error(format!(
+                    "Unexpected token: {}, expected: (",
+                    self.lookahead_type()
+                )));
+            }
+            if name == Some("constructor") {
+                return Err(syntax_error(
+                    "Classes may not have a field named 'constructor'",
+                ));
+            }
+            self.declare_private_name(key, "field", is_static)?;
+            return self.field_definition(key, computed, is_static);
+        }
+
+        if name == Some("constructor") && !is_static {
+            match kind {
+                "get" | "set" => {
+                    return Err(syntax_error("Class constructor may not be an accessor"))
+                }
+                _ if is_async => {
+                    return Err(syntax_error("Class constructor may not be an async method"))
+                }
+                _ if is_generator => {
+                    return Err(syntax_error("Class constructor may not be a generator"))
+                }
+                _ => kind = "constructor",
+            }
+        }
+        if name == Some("prototype") && is_static {
+            return Err(syntax_error(
+                "Classes may not have a static property named 'prototype'",
+            ));
+        }
+        self.declare_private_name(key, kind, is_static)?;
+
+        let function = self.function_params_and_body(None, is_async, is_generator)?;
+        match (kind, function.params.len()) {
+            ("get", 0) | ("set", 1) | ("method", _) | ("constructor", _) => {}
+            ("get", _) => return Err(syntax_error("Getter must not have any formal parameters")),
+            _ => {
+                return Err(syntax_error(
+                    "Setter must have exactly one formal parameter",
+                ))
+            }
+        }
+
+        let value = self.ast.add(Node::FunctionExpression {
+            id: None,
+            params: function.params,
+            body: function.body,
+            is_async,
+            is_generator,
+            return_type: function.return_type,
+        });
+        return Ok(self.ast.add(Node::MethodDefinition {
+            key,
+            value,
+            kind,
+            computed,
+            is_static,
+        }));
+    }
+
+    // `static`, `async`, `get` and `set` are also valid element names, as in
+    // `get() {}` or `static = 1`, so they only act as modifiers when another
+    // name follows. `async` must be followed by it on the same line.
+    fn is_class_modifier(&self, value: &str) -> bool {
+        if !self.is_contextual_keyword(value) {
+            return false;
+        }
+        return match self.tokenizer.clone().try_next().ok().flatten() {
+            Some(t) if value == "async" && t.newline_before => false,
+            Some(t) => !matches!(t.typ, "(" | "SIMPLE_ASSIGN" | ";" | "}"),
+            None => false,
+        };
+    }
+
+    // ClassElementName
+    //  : IdentifierName
+    //  | StringLiteral
+    //  | NumericLiteral
+    //  | PrivateIdentifier
+    //  | "[" AssignmentExpression "]"
+    //  ;
+    fn class_element_name(&mut self) -> ParseResult<(NodeId, bool)> {
+        return Ok(match self.lookahead_type() {
+            "STRING" => (self.string_literal()?, false),
+            "NUMBER" => (self.numeric_literal()?, false),
+            "PRIVATE_NAME" => (self.private_identifier()?, false),
+            "[" => {
+                self.eat("[")?;
+                let key = self.assignment_expression()?;
+                self.eat("]")?;
+                (key, true)
+            }
+            _ => (self.identifier_name()?, false),
+        });
+    }
+
+    // A private name may be declared once, except for a getter and setter
+    // pair that are both static or both not.
+    fn declare_private_name(
+        &mut self,
+        key: NodeId,
+        kind: &'static str,
+        is_static: bool,
+    ) -> ParseResult<()> {
+        let name = match self.ast.node(key) {
+            Node::PrivateIdentifier { .. } => self.ast.name(key),
+            _ => return Ok(()),
+        };
+        if name == "constructor" {
+            return Err(syntax_error(
+                "Classes may not have a private field named '#constructor'",
+            ));
+        }
+
+        let private_names = &mut self.classes.last_mut().unwrap().private_names;
+        let kind = match private_names.get(&name) {
+            None => kind,
+            Some(&(previous, was_static)) => match (previous, kind) {
+                ("get", "set") | ("set", "get") if was_static == is_static => "accessor",
+                _ => {
+                    return Err(syntax_error(format!(
+                        "Identifier '#{}' has already been declared",
+                        name
+                    )))
+                }
+            },
+        };
+        private_names.insert(name, (kind, is_static));
+        return Ok(());
+    }
+
+    // FieldDefinition
+    //  : ClassElementName OptInitializer ";"
+    //  ;
+    //
+    // Initializers are evaluated like the body of a method, so `await` and
+    // `yield` are plain identifiers there.
+    fn field_definition(
+        &mut self,
+        key: NodeId,
+        computed: bool,
+        is_static: bool,
+    ) -> ParseResult<NodeId> {
+        let value = if self.lookahead_type() == "SIMPLE_ASSIGN" {
+            self.eat("SIMPLE_ASSIGN")?;
+            self.functions.push(FunctionContext::default());
+            let value = self.assignment_expression()?;
+            self.functions.pop();
+            Some(value)
+        } else {
+            None
+        };
+        self.consume_semicolon()?;
+
+        return Ok(self.ast.add(Node::PropertyDefinition {
+            key,
+            value,
+            computed,
+            is_static,
+        }));
+    }
+
+    // ClassStaticBlock
+    //  : "static" "{" OptStatementList "}"
+    //  ;
+    fn class_static_block(&mut self) -> ParseResult<NodeId> {
+        self.scopes.push(Scope::function());
+        let body = self.block()?;
+        self.scopes.pop();
+
+        return Ok(self.ast.add(Node::StaticBlock { body }));
+    }
+
+    // PrivateIdentifier
+    //  : PRIVATE_NAME
+    //  ;
+    fn private_identifier(&mut self) -> ParseResult<NodeId> {
+        let token = self.eat("PRIVATE_NAME")?;
+        let name = match identifier_value(&token.value[1..]) {
+            Some(name) => name,
+            None => {
+                let (line, column) = self.tokenizer.location(token.start);
+                return Err(syntax_error(format!(
+                    "Invalid Unicode escape sequence in identifier ({}:{})",
+                    line, column
+                )));
+            }
+        };
+
+        let (name, text) = self.ast.intern_name(&name);
+        return Ok(self.ast.add(Node::PrivateIdentifier { name, text }));
+    }
+
+    // A `#name` used in a member expression must be declared by an enclosing
+    // class.
+    fn private_reference(&mut self) -> ParseResult<NodeId> {
+        let start = self.lookahead.as_ref().unwrap().start;
+        let id = self.private_identifier()?;
+        let name = self.ast.name(id);
+        match self.classes.last_mut() {
+            Some(class) => class.references.push((name, start)),
+            None => {
+                let (line, column) = self.tokenizer.location(start);
+                return Err(syntax_error(format!(
+                    "Private field '#{}' must be declared in an enclosing class ({}:{})",
+                    name, line, column
+                )));
+            }
+        }
+        return Ok(id);
+    }
+
+    // ReturnStatement
+    //  : "return" [no LineTerminator here] OptExpression ";"
+    //  ;
+    fn return_statement(&mut self) -> ParseResult<NodeId> {
+        if self.functions.is_empty() && !self.options.allow_return_outside_function {
+            return Err(syntax_error("Illegal return statement"));
+        }
+        self.eat("return")?;
+        let argument = if self.can_insert_semicolon() {
+            None
+        } else {
+            Some(self.expression()?)
+        };
+        self.consume_semicolon()?;
+
+        return Ok(self.ast.add(Node::ReturnStatement { argument }));
+    }
+
+    // ThrowStatement
+    //  : "throw" [no LineTerminator here] Expression ";"
+    //  ;
+    fn throw_statement(&mut self) -> ParseResult<NodeId> {
+        self.eat("throw")?;
+        if self.has_newline_before() {
+            return Err(syntax_error("Illegal newline after throw"));
+        }
+        let argument = self.expression()?;
+        self.consume_semicolon()?;
+
+        return Ok(self.ast.add(Node::ThrowStatement { argument }));
+    }
+
+    // `await` is a keyword inside async functions and at the top level of a
+    // module, `yield` inside generators.
+    fn in_async(&self) -> bool {
+        return match self.functions.last() {
+            Some(function) => function.is_async,
+            None => self.options.source_type == SourceType::Module,
+        };
+    }
+
+    fn in_generator(&self) -> bool {
+        return self.functions.last().is_some_and(|f| f.is_generator);
+    }
+
+    // ModuleDeclaration
+    // : ImportDeclaration
+    // | ExportDeclaration
+    // ;
+    fn module_declaration(&mut self, position: StatementPosition) -> ParseResult<NodeId> {
+        let keyword = self.lookahead_type();
+        self.check_modern(keyword)?;
+        if self.options.source_type != SourceType::Module {
+            return Err(syntax_error(format!(
+                "'{}' may only be used in a module",
+                keyword
+            )));
+        }
+        if position != StatementPosition::Program {
+            return Err(syntax_error(format!(
+                "'{}' may only appear at the top level",
+                keyword
+            )));
+        }
+
+        if keyword == "import" {
+            return self.import_declaration();
+        }
+        return self.export_declaration();
+    }
+
+    // ImportDeclaration
+    // : "import" StringLiteral ";"
+    // | "import" ImportClause "from" StringLiteral ";"
+    // ;
+    //
+    // ImportClause
+    // : Identifier
+    // | NameSpaceImport
+    // | NamedImports
+    // | Identifier "," NameSpaceImport
+    // | Identifier "," NamedImports
+    // ;
+    fn import_declaration(&mut self) -> ParseResult<NodeId> {
+        self.eat("import")?;
+        let items = self.ast.start_list();
+
+        if self.lookahead_type() != "STRING" {
+            let has_default = self.lookahead_type() == "IDENTIFIER";
+            if has_default {
+                let local = self.binding_identifier("import")?;
+                let specifier = self.ast.add(Node::ImportDefaultSpecifier { local });
+                self.ast.push_item(specifier);
+            }
+
+            if !has_default || self.lookahead_type() == "," {
+                if has_default {
+                    self.eat(",")?;
+                }
+                match self.lookahead_type() {
+                    "{" => self.named_imports()?,
+                    "MULTIPLICATIVE_OPERATOR" => {
+                        let specifier = self.namespace_import()?;
+                        self.ast.push_item(specifier);
                     }
-                    Literal::NullLiteral(nl) => {
-                        Test::Literal(Literal::NullLiteral(NullLiteral { ..nl }))
+                    typ => {
+                        return Err(syntax_error(format!(
+                            "Unexpected token: {}, expected: import clause",
+                            typ
+                        )))
                     }
-                },
-                Expression::BinaryExpression(bxp) => {
-                    Test::BinaryExpression(BinaryExpression { ..bxp })
                 }
-                _ => panic!("Unexpected test expression"),
             }
+            self.contextual_keyword("from")?;
+        }
+        let specifiers = self.ast.finish_list(items);
+
+        let source = self.string_literal()?;
+        self.consume_semicolon()?;
+
+        return Ok(self.ast.add(Node::ImportDeclaration { specifiers, source }));
+    }
+
+    // NameSpaceImport
+    // : "*" "as" Identifier
+    // ;
+    fn namespace_import(&mut self) -> ParseResult<NodeId> {
+        self.eat_operator("*")?;
+        self.contextual_keyword("as")?;
+        let local = self.binding_identifier("import")?;
+
+        return Ok(self.ast.add(Node::ImportNamespaceSpecifier { local }));
+    }
+
+    // NamedImports
+    // : "{" "}"
+    // | "{" ImportSpecifier ("," ImportSpecifier)* OptComma "}"
+    // ;
+    //
+    // ImportSpecifier
+    // : ModuleExportName
+    // | ModuleExportName "as" Identifier
+    // ;
+    //
+    // The specifiers are added to the list being built.
+    fn named_imports(&mut self) -> ParseResult<()> {
+        self.eat("{")?;
+
+        while self.lookahead_type() != "}" {
+            let imported = self.module_export_name()?;
+            let local = if self.is_contextual_keyword("as") {
+                self.contextual_keyword("as")?;
+                self.binding_identifier("import")?
+            } else {
+                let name = self.ast.name(imported);
+                self.declare(name, "import")?;
+                self.copy_node(imported)
+            };
+            let specifier = self.ast.add(Node::ImportSpecifier { imported, local });
+            self.ast.push_item(specifier);
+
+            if self.lookahead_type() != "}" {
+                self.eat(",")?;
+            }
+        }
+        self.eat("}")?;
+
+        return Ok(());
+    }
+
+    // ExportDeclaration
+    // : "export" "default" ExportDefaultKind
+    // | "export" "*" OptExportAs "from" StringLiteral ";"
+    // | "export" ExportSpecifiers OptFromClause ";"
+    // | "export" VariableStatement
+    // | "export" FunctionDeclaration
+    // | "export" ClassDeclaration
+    // ;
+    fn export_declaration(&mut self) -> ParseResult<NodeId> {
+        self.eat("export")?;
+
+        match self.lookahead_type() {
+            "default" => {
+                self.eat("default")?;
+                let declaration = self.export_default_kind()?;
+                return Ok(self.ast.add(Node::ExportDefaultDeclaration { declaration }));
+            }
+            "MULTIPLICATIVE_OPERATOR" => {
+                self.eat_operator("*")?;
+                let exported = if self.is_contextual_keyword("as") {
+                    self.contextual_keyword("as")?;
+                    Some(self.module_export_name()?)
+                } else {
+                    None
+                };
+                self.contextual_keyword("from")?;
+                let source = self.string_literal()?;
+                self.consume_semicolon()?;
+                return Ok(self
+                    .ast
+                    .add(Node::ExportAllDeclaration { exported, source }));
+            }
+            "{" => {
+                let specifiers = self.export_specifiers()?;
+                let source = if self.is_contextual_keyword("from") {
+                    self.contextual_keyword("from")?;
+                    Some(self.string_literal()?)
+                } else {
+                    None
+                };
+                self.consume_semicolon()?;
+                return Ok(self.ast.add(Node::ExportNamedDeclaration {
+                    declaration: None,
+                    specifiers,
+                    source,
+                }));
+            }
+            "let" | "const" | "var" => {
+                let declaration = self.variable_statement()?;
+                return Ok(self.ast.add(Node::ExportNamedDeclaration {
+                    declaration: Some(declaration),
+                    specifiers: NodeList::default(),
+                    source: None,
+                }));
+            }
+            _ if self.is_function_start() => {
+                let declaration = self.function_declaration()?;
+                return Ok(self.ast.add(Node::ExportNamedDeclaration {
+                    declaration: Some(declaration),
+                    specifiers: NodeList::default(),
+                    source: None,
+                }));
+            }
+            "class" => {
+                let declaration = self.class_declaration()?;
+                return Ok(self.ast.add(Node::ExportNamedDeclaration {
+                    declaration: Some(declaration),
+                    specifiers: NodeList::default(),
+                    source: None,
+                }));
+            }
+            typ => {
+                return Err(syntax_error(format!(
+                    "Unexpected token: {}, expected: export declaration",
+                    typ
+                )))
+            }
+        }
+    }
+
+    // ExportDefaultKind
+    // : FunctionDeclaration
+    // | ClassDeclaration
+    // | FunctionExpression
+    // | ClassExpression
+    // | AssignmentExpression ";"
+    // ;
+    //
+    // A function or class is a declaration when it's named, and binds its
+    // name in the module. Either way it isn't followed by a semicolon.
+    fn export_default_kind(&mut self) -> ParseResult<NodeId> {
+        let expression = if self.is_function_start() {
+            self.function_expression()?
+        } else if self.lookahead_type() == "class" {
+            self.class_expression()?
+        } else {
+            let expression = self.assignment_expression()?;
+            self.consume_semicolon()?;
+            return Ok(expression);
         };
 
-        self.eat(")");
-        let consequent = Box::new(self.statement());
+        let declaration = match *self.ast.node(expression) {
+            Node::FunctionExpression {
+                id: Some(id),
+                params,
+                body,
+                is_async,
+                is_generator,
+                return_type,
+            } => {
+                let name = self.ast.name(id);
+                self.declare(name, "function")?;
+                Node::FunctionDeclaration {
+                    id,
+                    params,
+                    body,
+                    is_async,
+                    is_generator,
+                    return_type,
+                }
+            }
+            Node::ClassExpression {
+                id: Some(id),
+                super_class,
+                body,
+            } => {
+                let name = self.ast.name(id);
+                self.declare(name, "class")?;
+                Node::ClassDeclaration {
+                    id,
+                    super_class,
+                    body,
+                }
+            }
+            _ => return Ok(expression),
+        };
+        *self.ast.node_mut(expression) = declaration;
+        return Ok(expression);
+    }
+
+    // ExportSpecifiers
+    // : "{" "}"
+    // | "{" ExportSpecifier ("," ExportSpecifier)* OptComma "}"
+    // ;
+    //
+    // ExportSpecifier
+    // : ModuleExportName
+    // | ModuleExportName "as" ModuleExportName
+    // ;
+    fn export_specifiers(&mut self) -> ParseResult<NodeList> {
+        self.eat("{")?;
+        let specifiers = self.ast.start_list();
+
+        while self.lookahead_type() != "}" {
+            let local = self.module_export_name()?;
+            let exported = if self.is_contextual_keyword("as") {
+                self.contextual_keyword("as")?;
+                self.module_export_name()?
+            } else {
+                self.copy_node(local)
+            };
+            let specifier = self.ast.add(Node::ExportSpecifier { local, exported });
+            self.ast.push_item(specifier);
+
+            if self.lookahead_type() != "}" {
+                self.eat(",")?;
+            }
+        }
+        self.eat("}")?;
+
+        return Ok(self.ast.finish_list(specifiers));
+    }
+
+    // ModuleExportName
+    // : IdentifierName
+    // ;
+    fn module_export_name(&mut self) -> ParseResult<NodeId> {
+        return self.identifier_name();
+    }
+
+    // A name written once but standing for both sides of a specifier, as in
+    // `export { a }`, gets a node for each.
+    fn copy_node(&mut self, id: NodeId) -> NodeId {
+        let node = self.ast.node(id).clone();
+        return self.ast.add(node);
+    }
+
+    // An identifier that introduces a new binding of the given kind.
+    fn binding_identifier(&mut self, kind: &'a str) -> ParseResult<NodeId> {
+        let name = self.binding_name(kind)?;
+        return Ok(self.identifier_node(name, None, false));
+    }
+
+    // The name of a binding, declared before an annotation that may follow
+    // it is parsed.
+    fn binding_name(&mut self, kind: &'a str) -> ParseResult<Cow<'a, str>> {
+        let name = self.identifier_text()?;
+        self.declare(name.clone(), kind)?;
+        return Ok(name);
+    }
+
+    // IfStatement
+    //  : "if" "(" Expression ")" Statement
+    //  : "if" "(" Expression ")" Statement "else" Statement
+    //  ;
+
+    fn if_statement(&mut self) -> ParseResult<NodeId> {
+        self.eat("if")?;
+        self.eat("(")?;
+        let test = self.expression()?;
+        self.eat(")")?;
+        let consequent = self.statement(StatementPosition::Single)?;
         let mut alternate = None;
-        if !self.lookahead.is_none() && self.lookahead.clone().unwrap().typ == "else" {
-            self.eat("else");
-            alternate = Some(Box::new(self.statement()));
+        if self.lookahead_type() == "else" {
+            self.eat("else")?;
+            alternate = Some(self.statement(StatementPosition::Single)?);
         }
 
-        return Statement::IfStatement(IfStatement {
-            typ: "IfStatement",
+        return Ok(self.ast.add(Node::IfStatement {
             test,
             consequent,
             alternate,
-        });
+        }));
     }
 
     // VariableStatement
-    //     : "let" VariableDeclarationList ";"
+    //     : VariableKind VariableDeclarationList ";"
     //     ;
-    fn variable_statement(&mut self) -> Statement<'a> {
-        self.eat("let");
-        let declarations = self.variable_declarations_list();
-        self.eat(";");
-
-        return Statement::VariableStatement(VariableStatement {
-            typ: "VariableStatement",
-            declarations,
-        });
+    //
+    // VariableKind
+    //     : "let"
+    //     | "const"
+    //     | "var"
+    //     ;
+    fn variable_statement(&mut self) -> ParseResult<NodeId> {
+        let kind = self.lookahead_type();
+        if kind != "var" {
+            self.check_modern(kind)?;
+        }
+        self.eat(kind)?;
+        let declarations = self.variable_declarations_list(kind)?;
+        self.consume_semicolon()?;
+
+        return Ok(self.ast.add(Node::VariableStatement { kind, declarations }));
     }
 
     // VariableDeclarationList
     //  : VariableDeclaration
     //  | VariableDeclarationList VariableDeclaration
     //  ;
-    fn variable_declarations_list(&mut self) -> Vec<VariableDeclaration<'a>> {
-        let mut declarations = Vec::new();
+    fn variable_declarations_list(&mut self, kind: &'a str) -> ParseResult<NodeList> {
+        let declarations = self.ast.start_list();
 
         loop {
-            declarations.push(self.variable_declaration());
-            if self.lookahead.clone().unwrap().typ != "," {
+            let declaration = self.variable_declaration(kind)?;
+            self.ast.push_item(declaration);
+            if self.lookahead_type() != "," {
                 break;
             }
-            self.eat(",");
+            self.eat(",")?;
+        }
+
+        return Ok(self.ast.finish_list(declarations));
+    }
+
+    // VariableDeclaration
+    //  : Identifier OptTypeAnnotation OptVariableInitializer
+    //  ;
+
+    fn variable_declaration(&mut self, kind: &'a str) -> ParseResult<NodeId> {
+        let name = self.identifier_text()?;
+        #[cfg(feature = "types")]
+        let id = self.annotated_identifier(name.clone(), false)?;
+        #[cfg(not(feature = "types"))]
+        let id = self.identifier_node(name.clone(), None, false);
+
+        let init = if self.lookahead_type() == "SIMPLE_ASSIGN" {
+            Some(self.variable_initializer()?)
+        } else {
+            None
+        };
+
+        if kind == "const" && init.is_none() {
+            return Err(syntax_error(format!(
+                "Missing initializer in const declaration: {}",
+                name
+            )));
         }
+        self.declare(name, kind)?;
 
-        return declarations;
+        return Ok(self.ast.add(Node::VariableDeclaration { id, init }));
     }
 
-    // VariableDeclaration
-    //  : Identifier OptVariableInitializer
-    //  ;
+    // `let` and `const` bindings are unique within their block, while `var`
+    // may be redeclared as long as it doesn't clash with a lexical binding
+    // of any block it's hoisted out of. Functions and parameters redeclare
+    // like `var`.
+    fn declare(&mut self, name: Cow<'a, str>, kind: &'a str) -> ParseResult<()> {
+        if (name == "await" && self.in_async()) || (name == "yield" && self.in_generator()) {
+            return Err(syntax_error(format!(
+                "Cannot use '{}' as an identifier here",
+                name
+            )));
+        }
 
-    fn variable_declaration(&mut self) -> VariableDeclaration<'a> {
-        let id = self.identifier();
-        let lookahead_type = self.lookahead.clone().unwrap().typ;
+        if self.strict {
+            self.check_strict_binding(&name)?;
+        }
 
-        let init = match lookahead_type {
-            ";" => None,
-            "," => None,
-            _ => Some(self.variable_initializer()),
-        };
-        return VariableDeclaration {
-            typ: "VariableDeclaration",
-            id,
-            init,
+        let is_var_like = |kind: &str| kind == "var" || kind == "function" || kind == "param";
+        let hoisted = if kind == "var" {
+            let function = self.scopes.iter().rposition(|scope| scope.is_function);
+            function.unwrap_or(0)
+        } else {
+            self.scopes.len() - 1
         };
+
+        for scope in &mut self.scopes[hoisted..] {
+            if let Some(previous) = scope.names.get(&name) {
+                if !is_var_like(kind) || !is_var_like(previous) {
+                    return Err(syntax_error(format!(
+                        "Identifier '{}' has already been declared",
+                        name
+                    )));
+                }
+            }
+            scope.names.entry(name.clone()).or_insert(kind);
+        }
+        return Ok(());
     }
 
     // VariableInitializer
     //  : SIMPLE_ASSIGN AssignmentExpression
     //  ;
-    fn variable_initializer(&mut self) -> VariableInitializer<'a> {
-        self.eat("SIMPLE_ASSIGN");
-        match self.assignment_expression() {
-            Expression::Literal(id) => VariableInitializer::Literal(id),
-            Expression::AssignmentExpression(ae) => VariableInitializer::AssignmentExpression(ae),
-            _ => panic!("Unexpected expression, expected assignment expression."),
-        }
+    fn variable_initializer(&mut self) -> ParseResult<NodeId> {
+        self.eat("SIMPLE_ASSIGN")?;
+        return self.assignment_expression();
     }
 
     // EmptyStatement
     // : ";"
     // ;
-    fn empty_statement(&mut self) -> Statement<'a> {
-        self.eat(";");
-        return Statement::EmptyStatement {
-            typ: "EmptyStatement",
-        };
+    fn empty_statement(&mut self) -> ParseResult<NodeId> {
+        self.eat(";")?;
+        return Ok(self.ast.add(Node::EmptyStatement));
     }
 
     // BlockStatement
     // : "{" OptStatementList "}"
     // ;
-    fn block_statement(&mut self) -> Statement<'a> {
-        self.eat("{");
-        let body = if self.lookahead.as_ref().unwrap().typ != "}" {
-            self.statement_list("}")
-        } else {
-            vec![]
-        };
-        self.eat("}");
+    fn block_statement(&mut self) -> ParseResult<NodeId> {
+        self.scopes.push(Scope::default());
+        let body = self.block()?;
+        self.scopes.pop();
 
-        return Statement::BlockStatement(BlockStatement {
-            typ: "BlockStatement",
-            body,
-        });
+        return Ok(self.ast.add(Node::BlockStatement { body }));
+    }
+
+    fn block(&mut self) -> ParseResult<NodeList> {
+        self.eat("{")?;
+        let items = self.ast.start_list();
+        if self.lookahead_type() != "}" {
+            self.statement_list("}", StatementPosition::List)?;
+        }
+        self.eat("}")?;
+
+        return Ok(self.ast.finish_list(items));
     }
 
     // ExpressionStatement
     // : Expression ";"
     // ;
-    fn expression_statement(&mut self) -> Statement<'a> {
-        let expression = self.expression();
-        self.eat(";");
-        return Statement::ExpressionStatement(ExpressionStatement {
-            typ: "ExpressionStatement",
-            expression,
-        });
+    fn expression_statement(&mut self) -> ParseResult<NodeId> {
+        let expression = self.expression()?;
+        self.consume_semicolon()?;
+        return Ok(self.ast.add(Node::ExpressionStatement { expression }));
     }
 
     // Expression
     // : AssignmentExpression
+    // | SequenceExpression
     // ;
-    fn expression(&mut self) -> Expression<'a> {
-        return self.assignment_expression();
+    //
+    // SequenceExpression
+    // : Expression "," AssignmentExpression
+    // ;
+    fn expression(&mut self) -> ParseResult<NodeId> {
+        let first = self.assignment_expression()?;
+
+        if self.lookahead_type() != "," {
+            return Ok(first);
+        }
+
+        let items = self.ast.start_list();
+        self.ast.push_item(first);
+        while self.lookahead_type() == "," {
+            self.eat(",")?;
+            let expression = self.assignment_expression()?;
+            self.ast.push_item(expression);
+        }
+        let expressions = self.ast.finish_list(items);
+
+        return Ok(self.ast.add(Node::SequenceExpression { expressions }));
     }
 
     // AssignmentExpression
-    // : LogicalORExpression
+    // : BinaryExpression
     // | LeftHandSideExpression AssignmentOperator AssignmentExpression
     // ;
-    fn assignment_expression(&mut self) -> Expression<'a> {
-        let left = self.logical_or_expression();
+    fn assignment_expression(&mut self) -> ParseResult<NodeId> {
+        if self.is_contextual_keyword("yield") && self.in_generator() {
+            return self.yield_expression();
+        }
 
-        if !self.is_assignment_operator(self.lookahead.as_ref().unwrap().typ) {
-            return left;
+        let left = self.binary_expression(0)?;
+
+        if !self.is_assignment_operator(self.lookahead_type()) {
+            return Ok(left);
         }
 
-        return Expression::AssignmentExpression(AssignmentExpression {
-            typ: "AssignmentExpression",
-            operator: self.assignment_operator().value,
-            left: self.check_valid_assignment_target(left),
-            right: Box::new(self.assignment_expression()),
-        });
+        let operator = Cow::Borrowed(self.assignment_operator()?.value);
+        let left = self.check_valid_assignment_target(left)?;
+        let right = self.nested(Self::assignment_expression)?;
+        return Ok(self.ast.add(Node::AssignmentExpression {
+            operator,
+            left,
+            right,
+        }));
     }
 
-    // EqualityExpression
-    //  : RelationalExpression EQUALITY_OPERATOR EqualityExpression
-    //  | RelationalExpression
-    //  ;
-    fn equality_expression(&mut self) -> Expression<'a> {
-        let mut left = self.relational_expression();
+    // YieldExpression
+    // : "yield"
+    // | "yield" [no LineTerminator here] AssignmentExpression
+    // | "yield" "*" AssignmentExpression
+    // ;
+    fn yield_expression(&mut self) -> ParseResult<NodeId> {
+        self.contextual_keyword("yield")?;
+
+        let delegate = self.lookahead_value() == "*";
+        if delegate {
+            self.eat_operator("*")?;
+        }
+
+        let argument = match self.lookahead_type() {
+            _ if delegate => Some(self.nested(Self::assignment_expression)?),
+            ")" | "]" | "}" | "," | ";" | "EOF" => None,
+            _ if self.has_newline_before() => None,
+            _ => Some(self.nested(Self::assignment_expression)?),
+        };
 
-        while self.lookahead.as_ref().unwrap().typ == "EQUALITY_OPERATOR" {
-            let operator = self.eat("EQUALITY_OPERATOR").value;
-            let right = self.relational_expression();
+        return Ok(self.ast.add(Node::YieldExpression { argument, delegate }));
+    }
 
-            left = Expression::BinaryExpression(BinaryExpression {
-                typ: "BinaryExpression",
-                operator: operator,
-                left: Box::new(left),
-                right: Box::new(right),
-            });
+    // Only identifiers and member expressions can be assigned to. An optional
+    // chain like `a?.b` can't, as it may evaluate to `undefined` instead.
+    fn check_valid_assignment_target(&self, node: NodeId) -> ParseResult<NodeId> {
+        match self.ast.node(node) {
+            Node::Identifier { name, .. } => {
+                if self.strict && matches!(&*self.ast.resolve(*name), "eval" | "arguments") {
+                    return Err(syntax_error("Unexpected eval or arguments in strict mode"));
+                }
+            }
+            Node::MemberExpression {
+                optional: false, ..
+            } => {}
+            _ => {
+                return Err(syntax_error(
+                    "Invalid left-hand side in assignment expression",
+                ))
+            }
         }
+        return Ok(node);
+    }
 
-        return left;
+    // LeftHandSideExpression
+    // : CallMemberExpression
+    // ;
+    fn left_hand_side_expression(&mut self) -> ParseResult<NodeId> {
+        return self.call_member_expression();
     }
 
-    // RelationalExpression
-    // : AdditiveExpression
-    // : AdditiveExpression RELATIONAL_OPERATOR RelationalExpression
+    // CallMemberExpression
+    // : PrimaryExpression
+    // | CallMemberExpression "." MemberProperty
+    // | CallMemberExpression "[" Expression "]"
+    // | CallMemberExpression Arguments
+    // | CallMemberExpression OPTIONAL_CHAINING MemberProperty
+    // | CallMemberExpression OPTIONAL_CHAINING "[" Expression "]"
+    // | CallMemberExpression OPTIONAL_CHAINING Arguments
     // ;
-    fn relational_expression(&mut self) -> Expression<'a> {
-        let mut left = self.additive_expression();
+    //
+    // A chain containing at least one `?.` is wrapped in a ChainExpression,
+    // so short-circuiting covers the whole chain and not only its link.
+    fn call_member_expression(&mut self) -> ParseResult<NodeId> {
+        let mut object = self.primary_expression()?;
+        let mut is_optional_chain = false;
+        let mut links = 0;
 
-        while self.lookahead.as_ref().unwrap().typ == "RELATIONAL_OPERATOR" {
-            let operator = self.eat("RELATIONAL_OPERATOR").value;
-            let right = self.additive_expression();
+        loop {
+            let optional = self.lookahead_type() == "OPTIONAL_CHAINING";
+            if optional {
+                self.check_modern("?.")?;
+                self.eat("OPTIONAL_CHAINING")?;
+                is_optional_chain = true;
+            }
+            if !optional && !matches!(self.lookahead_type(), "(" | "[" | ".") {
+                break;
+            }
+            self.deepen()?;
+            links += 1;
+
+            object = match self.lookahead_type() {
+                "(" => self.call_expression(object, optional)?,
+                "[" => {
+                    self.eat("[")?;
+                    let property = self.expression()?;
+                    self.eat("]")?;
+                    self.member_expression(object, property, true, optional)
+                }
+                "." if !optional => {
+                    self.eat(".")?;
+                    let property = self.member_property()?;
+                    self.member_expression(object, property, false, false)
+                }
+                _ if optional => {
+                    let property = self.member_property()?;
+                    self.member_expression(object, property, false, true)
+                }
+                _ => break,
+            };
+        }
+        self.depth -= links;
 
-            left = Expression::BinaryExpression(BinaryExpression {
-                typ: "BinaryExpression",
-                operator: operator,
-                left: Box::new(left),
-                right: Box::new(right),
-            });
+        if is_optional_chain {
+            return Ok(self.ast.add(Node::ChainExpression { expression: object }));
         }
 
-        return left;
+        return Ok(object);
     }
 
-    fn check_valid_assignment_target(&self, node: Expression<'a>) -> Identifier<'a> {
-        match node {
-            Expression::LeftHandSideExpression(lhse) => match lhse {
-                LeftHandSideExpression::Identifier(i) => Identifier {
-                    typ: "Identifier",
-                    name: i.name,
-                },
-            },
-            _ => panic!("Invalid left-hand side in assignment expression"),
+    // MemberProperty
+    // : IdentifierName
+    // | PrivateIdentifier
+    // ;
+    fn member_property(&mut self) -> ParseResult<NodeId> {
+        if self.lookahead_type() == "PRIVATE_NAME" {
+            return self.private_reference();
         }
+        return self.identifier_name();
     }
 
-    // LeftHandSideExpression
-    // : Identifier
+    fn member_expression(
+        &mut self,
+        object: NodeId,
+        property: NodeId,
+        computed: bool,
+        optional: bool,
+    ) -> NodeId {
+        return self.ast.add(Node::MemberExpression {
+            object,
+            property,
+            computed,
+            optional,
+        });
+    }
+
+    // CallExpression
+    // : Callee Arguments
     // ;
-    fn left_hand_side_expression(&mut self) -> LeftHandSideExpression<'a> {
-        return LeftHandSideExpression::Identifier(self.identifier());
+    fn call_expression(&mut self, callee: NodeId, optional: bool) -> ParseResult<NodeId> {
+        let arguments = self.arguments()?;
+        return Ok(self.ast.add(Node::CallExpression {
+            callee,
+            arguments,
+            optional,
+        }));
+    }
+
+    // Arguments
+    // : "(" OptArgumentList ")"
+    // ;
+    fn arguments(&mut self) -> ParseResult<NodeList> {
+        self.eat("(")?;
+        let argument_list = if self.lookahead_type() != ")" {
+            self.argument_list()?
+        } else {
+            NodeList::default()
+        };
+        self.eat(")")?;
+
+        return Ok(argument_list);
+    }
+
+    // ArgumentList
+    // : AssignmentExpression
+    // | ArgumentList "," AssignmentExpression
+    // ;
+    fn argument_list(&mut self) -> ParseResult<NodeList> {
+        let argument_list = self.ast.start_list();
+
+        loop {
+            let argument = self.assignment_expression()?;
+            self.ast.push_item(argument);
+            if self.lookahead_type() != "," {
+                break;
+            }
+            self.eat(",")?;
+        }
+
+        return Ok(self.ast.finish_list(argument_list));
     }
 
     // Identifier
     // : IDENTIFIER
     // ;
-    fn identifier(&mut self) -> Identifier<'a> {
-        let name = self.eat("IDENTIFIER").clone().value;
-        return Identifier {
-            typ: "Identifier",
-            name: name,
+    fn identifier(&mut self) -> ParseResult<NodeId> {
+        let name = self.identifier_text()?;
+        return Ok(self.identifier_node(name, None, false));
+    }
+
+    // The name of an Identifier, which can't be a reserved word.
+    fn identifier_text(&mut self) -> ParseResult<Cow<'a, str>> {
+        if self.is_keyword(self.lookahead_type()) {
+            return Err(syntax_error(format!(
+                "Unexpected reserved word '{}'",
+                self.lookahead_value()
+            )));
+        }
+
+        let name = self.identifier_name_text()?;
+        if RESERVED_WORDS.contains(&&*name) || self.is_keyword(&name) {
+            return Err(syntax_error(format!("Unexpected reserved word '{}'", name)));
+        }
+        if self.strict && STRICT_RESERVED_WORDS.contains(&&*name) {
+            return Err(syntax_error(format!(
+                "Unexpected strict mode reserved word '{}'",
+                name
+            )));
+        }
+
+        return Ok(name);
+    }
+
+    // IdentifierName
+    // : IDENTIFIER
+    // | Keyword
+    // ;
+    //
+    // Property names may be any word, including reserved ones, as in `a.default`.
+    fn identifier_name(&mut self) -> ParseResult<NodeId> {
+        let name = self.identifier_name_text()?;
+        return Ok(self.identifier_node(name, None, false));
+    }
+
+    fn identifier_name_text(&mut self) -> ParseResult<Cow<'a, str>> {
+        let token_type = self.lookahead_type();
+        let token = if self.is_keyword(token_type) {
+            self.eat(token_type)?
+        } else {
+            self.eat("IDENTIFIER")?
+        };
+        if has_code_point_escape(token.value) {
+            self.check_modern("\\u{")?;
+        }
+
+        return match identifier_value(token.value) {
+            Some(name) => Ok(name),
+            None => {
+                let (line, column) = self.tokenizer.location(token.start);
+                Err(syntax_error(format!(
+                    "Invalid Unicode escape sequence in identifier ({}:{})",
+                    line, column
+                )))
+            }
         };
     }
 
+    fn identifier_node(
+        &mut self,
+        name: Cow<'a, str>,
+        type_annotation: Option<NodeId>,
+        optional: bool,
+    ) -> NodeId {
+        let (name, text) = self.ast.intern_name(&name);
+        return self.ast.add(Node::Identifier {
+            name,
+            text,
+            type_annotation,
+            optional,
+        });
+    }
+
     fn is_assignment_operator(&self, token_type: &str) -> bool {
         return token_type == "SIMPLE_ASSIGN" || token_type == "COMPLEX_ASSIGN";
     }
@@ -323,113 +2014,147 @@ impl<'a> Parser<'a> {
     // : SIMPLE_ASSIGN
     // | COMPLEX_ASSIGN
     // ;
-    fn assignment_operator(&mut self) -> Token {
-        if self.lookahead.as_ref().unwrap().typ == "SIMPLE_ASSIGN" {
+    fn assignment_operator(&mut self) -> ParseResult<Token<'a>> {
+        if self.lookahead_type() == "SIMPLE_ASSIGN" {
             return self.eat("SIMPLE_ASSIGN");
         }
+        if self.lookahead_value() == "**=" {
+            self.check_modern("**=")?;
+        }
         return self.eat("COMPLEX_ASSIGN");
     }
 
-    fn logical_and_expression(&mut self) -> Expression<'a> {
-        let mut left = self.equality_expression();
-
-        while self.lookahead.as_ref().unwrap().typ == "LOGICAL_AND" {
-            let operator = self.eat("LOGICAL_AND").value;
-            let right = self.equality_expression();
-
-            left = Expression::LogicalExpression(LogicalExpression {
-                typ: "LogicalExpression",
-                operator: operator,
-                left: Box::new(left),
-                right: Box::new(right),
-            });
+    // ExponentiationExpression
+    // : UnaryExpression
+    // | LeftHandSideExpression EXPONENTIATION_OPERATOR ExponentiationExpression
+    // ;
+    fn exponentiation_expression(&mut self) -> ParseResult<NodeId> {
+        if self.is_unary_operator(self.lookahead_type()) || self.is_await_start() {
+            let unary = self.unary_expression()?;
+            if self.lookahead_type() == "EXPONENTIATION_OPERATOR" {
+                return Err(syntax_error("Unary operator used immediately before exponentiation expression. Parenthesis must be used to disambiguate operator precedence"));
+            }
+            return Ok(unary);
         }
 
-        return left;
-    }
-
-    fn logical_or_expression(&mut self) -> Expression<'a> {
-        let mut left = self.logical_and_expression();
+        let left = self.left_hand_side_expression()?;
 
-        while self.lookahead.as_ref().unwrap().typ == "LOGICAL_OR" {
-            let operator = self.eat("LOGICAL_OR").value;
-            let right = self.logical_and_expression();
-
-            left = Expression::LogicalExpression(LogicalExpression {
-                typ: "LogicalExpression",
-                operator: operator,
-                left: Box::new(left),
-                right: Box::new(right),
-            });
+        if self.lookahead_type() != "EXPONENTIATION_OPERATOR" {
+            return Ok(left);
         }
 
-        return left;
+        self.check_modern("**")?;
+        let operator = Cow::Borrowed(self.eat("EXPONENTIATION_OPERATOR")?.value);
+        let right = self.nested(Self::exponentiation_expression)?;
+        return Ok(self.ast.add(Node::BinaryExpression {
+            operator,
+            left,
+            right,
+        }));
     }
 
-    // AdditiveExpression
-    // : MultiplicativeExpression
-    // | AdditiveExpression ADDITIVE_OPERATOR Literal
+    // UnaryExpression
+    // : LeftHandSideExpression
+    // | AwaitExpression
+    // | ADDITIVE_OPERATOR UnaryExpression
+    // | LOGICAL_NOT UnaryExpression
+    // | BITWISE_NOT UnaryExpression
     // ;
-    fn additive_expression(&mut self) -> Expression<'a> {
-        let mut left = self.multiplicative_expression();
-
-        while self.lookahead.as_ref().unwrap().typ == "ADDITIVE_OPERATOR" {
-            let operator = self.eat("ADDITIVE_OPERATOR").value;
-            let right = self.multiplicative_expression();
+    fn unary_expression(&mut self) -> ParseResult<NodeId> {
+        if self.is_await_start() {
+            return self.await_expression();
+        }
 
-            left = Expression::BinaryExpression(BinaryExpression {
-                typ: "BinaryExpression",
-                operator: operator,
-                left: Box::new(left),
-                right: Box::new(right),
-            });
+        let token_type = self.lookahead_type();
+        if !self.is_unary_operator(token_type) {
+            return self.left_hand_side_expression();
         }
 
-        return left;
+        let operator = Cow::Borrowed(self.eat(token_type)?.value);
+        let argument = self.nested(Self::unary_expression)?;
+        return Ok(self.ast.add(Node::UnaryExpression { operator, argument }));
     }
 
-    // MultiplicativeExpression
-    // : PrimaryExpression
-    // | MultiplicativeExpression MULTIPLICATIVE_OPERATOR PrimaryExpression
+    // AwaitExpression
+    // : "await" UnaryExpression
     // ;
-    fn multiplicative_expression(&mut self) -> Expression<'a> {
-        let mut left = self.primary_expression();
+    fn await_expression(&mut self) -> ParseResult<NodeId> {
+        self.contextual_keyword("await")?;
 
-        while self.lookahead.as_ref().unwrap().typ == "MULTIPLICATIVE_OPERATOR" {
-            let operator = self.eat("MULTIPLICATIVE_OPERATOR").value;
-            let right = self.primary_expression();
+        let argument = self.nested(Self::unary_expression)?;
+        return Ok(self.ast.add(Node::AwaitExpression { argument }));
+    }
 
-            left = Expression::BinaryExpression(BinaryExpression {
-                typ: "BinaryExpression",
-                operator: operator,
-                left: Box::new(left),
-                right: Box::new(right),
-            });
-        }
+    fn is_await_start(&self) -> bool {
+        return self.is_contextual_keyword("await") && self.in_async();
+    }
 
-        return left;
+    fn is_unary_operator(&self, token_type: &str) -> bool {
+        return token_type == "ADDITIVE_OPERATOR"
+            || token_type == "LOGICAL_NOT"
+            || token_type == "BITWISE_NOT";
     }
 
     //  PrimaryExpression
     // : Literal
     // ; ParenthesizedExpression
-    // ; LeftHandSideExpression
+    // ; FunctionExpression
+    // ; ClassExpression
+    // ; "this"
+    // ; "super"
+    // ; JSXElement
+    // ; Identifier
     // ;
-    fn primary_expression(&mut self) -> Expression<'a> {
+    fn primary_expression(&mut self) -> ParseResult<NodeId> {
         let token = &self.lookahead;
-        if self.is_literal(self.lookahead.as_ref().unwrap().typ) {
-            return Expression::Literal(self.literal());
+        if self.is_literal(self.lookahead_type()) {
+            return self.literal();
+        }
+        if self.is_regexp_start() {
+            return self.regexp_literal();
+        }
+        if self.lookahead_type() == "import" {
+            return self.import_expression();
+        }
+        if self.is_function_start() {
+            return self.function_expression();
+        }
+        match self.lookahead_type() {
+            "class" => return self.class_expression(),
+            "this" => return self.this_expression(),
+            "super" => return self.super_expression(),
+            _ if self.options.jsx && self.lookahead_value() == "<" => return self.jsx_expression(),
+            _ => {}
         }
         match token {
             Some(t) => {
                 if t.typ == "(" {
                     return self.parenthesized_expression();
                 } else {
-                    return Expression::LeftHandSideExpression(self.left_hand_side_expression());
+                    return self.identifier();
                 }
             }
-            None => panic!("Unexpected primary expression"),
+            None => return Err(syntax_error("Unexpected primary expression")),
+        }
+    }
+
+    // ThisExpression
+    // : "this"
+    // ;
+    fn this_expression(&mut self) -> ParseResult<NodeId> {
+        self.eat("this")?;
+        return Ok(self.ast.add(Node::ThisExpression));
+    }
+
+    // Super
+    // : "super"
+    // ;
+    fn super_expression(&mut self) -> ParseResult<NodeId> {
+        if self.classes.is_empty() {
+            return Err(syntax_error("'super' keyword unexpected here"));
         }
+        self.eat("super")?;
+        return Ok(self.ast.add(Node::Super));
     }
 
     fn is_literal(&self, token_type: &str) -> bool {
@@ -440,15 +2165,70 @@ impl<'a> Parser<'a> {
             || token_type == "null";
     }
 
+    // ImportExpression
+    // : "import" "(" AssignmentExpression ")"
+    // ;
+    fn import_expression(&mut self) -> ParseResult<NodeId> {
+        self.check_modern("import")?;
+        self.eat("import")?;
+        self.eat("(")?;
+        let source = self.assignment_expression()?;
+        self.eat(")")?;
+
+        return Ok(self.ast.add(Node::ImportExpression { source }));
+    }
+
+    // A `/` or `/=` can't be a division where an expression begins, so it
+    // starts a regular expression instead.
+    fn is_regexp_start(&self) -> bool {
+        let value = self.lookahead_value();
+        return value == "/" || value == "/=";
+    }
+
+    // RegExpLiteral
+    //  : REGEXP
+    //  ;
+    fn regexp_literal(&mut self) -> ParseResult<NodeId> {
+        let slash = self.lookahead.as_ref().unwrap();
+        let (start, newline_before) = (slash.start, slash.newline_before);
+        self.lookahead = Some(Token {
+            newline_before,
+            ..self
+                .tokenizer
+                .try_read_regexp(start)
+                .map_err(syntax_error)?
+        });
+        let token: Token = self.eat("REGEXP")?;
+
+        let body_end = token.value.rfind('/').unwrap();
+        let flags = &token.value[body_end + 1..];
+        if !valid_regexp_flags(flags) {
+            let (line, column) = self.tokenizer.location(token.start + body_end + 1);
+            return Err(syntax_error(format!(
+                "Invalid regular expression flags ({}:{})",
+                line, column
+            )));
+        }
+        if let Some(flag) = flags.chars().find(|c| "dsuvy".contains(*c)) {
+            self.check_modern(&format!("/{}", flag))?;
+        }
+
+        return Ok(self.ast.add(Node::RegExpLiteral {
+            pattern: Cow::Borrowed(&token.value[1..body_end]),
+            flags: Cow::Borrowed(flags),
+            raw: Cow::Borrowed(token.value),
+        }));
+    }
+
     // ParenthesizedExpression
     // "(" Expression ")"
     // ;
-    fn parenthesized_expression(&mut self) -> Expression<'a> {
-        self.eat("(");
-        let expression = self.expression();
-        self.eat(")");
+    fn parenthesized_expression(&mut self) -> ParseResult<NodeId> {
+        self.eat("(")?;
+        let expression = self.expression()?;
+        self.eat(")")?;
 
-        return expression;
+        return Ok(expression);
     }
 
     // Literal
@@ -457,81 +2237,503 @@ impl<'a> Parser<'a> {
     // | BooleanLiteral
     // | NullLiteral
     // :
-    fn literal(&mut self) -> Literal<'a> {
+    fn literal(&mut self) -> ParseResult<NodeId> {
         let token = &self.lookahead;
 
         let literal = if let Some(t) = token {
             match t.typ {
-                "NUMBER" => Literal::NumericLiteral(self.numeric_literal()),
-                "STRING" => Literal::StringLiteral(self.string_literal()),
-                "true" => Literal::BooleanLiteral(self.boolean_literal(true)),
-                "false" => Literal::BooleanLiteral(self.boolean_literal(false)),
-                "null" => Literal::NullLiteral(self.null_literal()),
-                _ => panic!("Unsupported Literal type {}", t.typ),
+                "NUMBER" => self.numeric_literal()?,
+                "STRING" => self.string_literal()?,
+                "true" => self.boolean_literal(true)?,
+                "false" => self.boolean_literal(false)?,
+                "null" => self.null_literal()?,
+                _ => return Err(syntax_error(format!("Unsupported Literal type {}", t.typ))),
             }
         } else {
-            panic!("Unexpected end of file")
+            return Err(syntax_error("Unexpected end of file"));
         };
-        return literal;
+        return Ok(literal);
     }
 
-    fn boolean_literal(&mut self, value: bool) -> BooleanLiteral<'a> {
+    fn boolean_literal(&mut self, value: bool) -> ParseResult<NodeId> {
         if value {
-            self.eat("true");
-            return BooleanLiteral {
-                typ: "BooleanLiteral",
-                value: true,
-            };
+            self.eat("true")?;
         } else {
-            self.eat("false");
-            return BooleanLiteral {
-                typ: "BooleanLiteral",
-                value: false,
-            };
+            self.eat("false")?;
         }
+        return Ok(self.ast.add(Node::BooleanLiteral { value }));
     }
 
-    fn null_literal(&self) -> NullLiteral<'a> {
-        return NullLiteral { typ: "NullLiteral" };
+    fn null_literal(&mut self) -> ParseResult<NodeId> {
+        self.eat("null")?;
+        return Ok(self.ast.add(Node::NullLiteral));
     }
 
-    // NumericLiteral
+    // StringLiteral
     //  : STRING
     //  ;
-    fn string_literal(&mut self) -> StringLiteral<'a> {
-        let token: Token = self.eat("STRING");
-        let value = token.value;
-        return StringLiteral {
-            typ: "StringLiteral",
-            value: value[1..value.len() - 1].to_string(),
+    fn string_literal(&mut self) -> ParseResult<NodeId> {
+        let token: Token = self.eat("STRING")?;
+        let body = &token.value[1..token.value.len() - 1];
+        let max_string_length = self.options.limits.max_string_length;
+        if body.len() > max_string_length {
+            return Err(self.limit_exceeded(Limit::StringLength, max_string_length, token.start));
+        }
+        if has_code_point_escape(body) {
+            self.check_modern("\\u{")?;
+        }
+        let value = match string_value(body, self.strict) {
+            Ok(v) => v,
+            Err((offset, message)) => {
+                let (line, column) = self.tokenizer.location(token.start + 1 + offset);
+                return Err(syntax_error(format!("{} ({}:{})", message, line, column)));
+            }
         };
+
+        let value = self.ast.intern(&value);
+        return Ok(self.ast.add(Node::StringLiteral {
+            value,
+            raw: Cow::Borrowed(token.value),
+        }));
     }
 
     // NumericLiteral
     //  : NUMBER
     //  ;
-    fn numeric_literal(&mut self) -> NumericLiteral<'a> {
-        let token: Token = self.eat("NUMBER");
+    fn numeric_literal(&mut self) -> ParseResult<NodeId> {
+        let token: Token = self.eat("NUMBER")?;
+        let prefix = token.value.get(..2).map(|p| p.to_ascii_lowercase());
+        if matches!(prefix.as_deref(), Some("0b" | "0o")) {
+            self.check_modern(&token.value[..2])?;
+        }
+        if token.value.contains('_') {
+            self.check_modern("_")?;
+        }
+        let value = match numeric_value(token.value) {
+            Some(v) => v,
+            None => {
+                return Err(syntax_error(format!(
+                    "Invalid numeric literal: {}",
+                    token.value
+                )))
+            }
+        };
+
+        return Ok(self.ast.add(Node::NumericLiteral {
+            value,
+            raw: Cow::Borrowed(token.value),
+        }));
+    }
+
+    // Words like `from` and `as` are keywords only in specific positions and
+    // are otherwise tokenized as plain identifiers.
+    fn is_contextual_keyword(&self, value: &str) -> bool {
+        return self.lookahead_type() == "IDENTIFIER" && self.lookahead_value() == value;
+    }
+
+    fn contextual_keyword(&mut self, value: &str) -> ParseResult<()> {
+        let token = self.eat("IDENTIFIER")?;
+        if token.value != value {
+            return Err(syntax_error(format!(
+                "Unexpected token: {}, expected: {}",
+                token.value, value
+            )));
+        }
+        return Ok(());
+    }
+
+    fn eat_operator(&mut self, value: &str) -> ParseResult<Token<'a>> {
+        let token_type = self.lookahead_type();
+        let token = self.eat(token_type)?;
+        if token.value != value {
+            return Err(syntax_error(format!(
+                "Unexpected token: {}, expected: {}",
+                token.value, value
+            )));
+        }
+        return Ok(token);
+    }
+
+    // Parses a production that may contain itself, failing once they nest
+    // deeper than the `max_depth` limit rather than overflowing the stack.
+    fn nested<T>(
+        &mut self,
+        production: impl FnOnce(&mut Self) -> ParseResult<T>,
+    ) -> ParseResult<T> {
+        self.deepen()?;
+        let node = production(self);
+        self.depth -= 1;
+        return node;
+    }
+
+    // Counts one more level of nesting. Productions built in a loop, such as
+    // `a + b + c` or `a.b.c`, nest each node in the one before it, so they
+    // count a level for every node and give them back when the loop ends.
+    // Converting or dropping the tree recurses through all of them.
+    fn deepen(&mut self) -> ParseResult<()> {
+        self.depth += 1;
+        let max_depth = self.options.limits.max_depth;
+        if self.depth > max_depth {
+            let offset = self
+                .lookahead
+                .as_ref()
+                .map_or(self.string.len(), |t| t.start);
+            return Err(self.limit_exceeded(Limit::Depth, max_depth, offset));
+        }
+        return Ok(());
+    }
 
-        return NumericLiteral {
-            typ: "NumericLiteral",
-            value: token.value.parse::<i64>().unwrap(),
+    fn next_token(&mut self) -> ParseResult<Option<Token<'a>>> {
+        let token = match self.tokenizer.try_next().map_err(syntax_error)? {
+            Some(token) => token,
+            None => return Ok(None),
         };
+        self.tokens += 1;
+        let max_tokens = self.options.limits.max_tokens;
+        if self.tokens > max_tokens {
+            return Err(self.limit_exceeded(Limit::Tokens, max_tokens, token.start));
+        }
+        return Ok(Some(token));
+    }
+
+    fn limit_exceeded(&self, limit: Limit, max: usize, offset: usize) -> ParseError {
+        return ParseError::LimitExceeded { limit, max, offset };
+    }
+
+    // Syntax added after ES5 is rejected when the options target it.
+    fn check_modern(&self, syntax: &str) -> ParseResult<()> {
+        if self.options.ecma_version == EcmaVersion::Es5 {
+            return Err(syntax_error(format!(
+                "'{}' is not available in ES5",
+                syntax
+            )));
+        }
+        return Ok(());
+    }
+
+    // Type of the token following the lookahead.
+    fn peek_type(&self) -> Option<&'a str> {
+        return self
+            .tokenizer
+            .clone()
+            .try_next()
+            .ok()
+            .flatten()
+            .map(|t| t.typ);
+    }
+
+    // Type of the lookahead token, or "EOF" at the end of input.
+    fn lookahead_type(&self) -> &'a str {
+        return self.lookahead.as_ref().map_or("EOF", |t| t.typ);
+    }
+
+    fn lookahead_value(&self) -> &'a str {
+        return self.lookahead.as_ref().map_or("", |t| t.value);
     }
 
-    fn eat(&mut self, token_type: &str) -> Token<'a> {
-        let token: Option<Token> = self.lookahead.clone();
+    // Whether the tokenizer's spec tokenizes `word` as a keyword.
+    fn is_keyword(&self, word: &str) -> bool {
+        return self.tokenizer.spec().keywords.contains(&word);
+    }
+
+    // Whether a line terminator separates the lookahead from the previous token.
+    fn has_newline_before(&self) -> bool {
+        return self.lookahead.as_ref().is_some_and(|t| t.newline_before);
+    }
+
+    // Automatic semicolon insertion: a missing ";" is inserted when the next
+    // token is on a new line, is a "}" or when the input ends.
+    fn consume_semicolon(&mut self) -> ParseResult<()> {
+        match self.lookahead_type() {
+            ";" => {
+                self.eat(";")?;
+            }
+            _ if !self.options.automatic_semicolon_insertion => {
+                self.eat(";")?;
+            }
+            "}" | "EOF" => {}
+            _ if self.has_newline_before() => {}
+            typ => {
+                return Err(syntax_error(format!(
+                    "Unexpected token: {}, expected: ;",
+                    typ
+                )))
+            }
+        }
+        return Ok(());
+    }
+
+    // Whether an optional expression is missing, as after `return`.
+    fn can_insert_semicolon(&self) -> bool {
+        if !self.options.automatic_semicolon_insertion {
+            return self.lookahead_type() == ";";
+        }
+        return matches!(self.lookahead_type(), ";" | "}" | "EOF") || self.has_newline_before();
+    }
+
+    fn eat(&mut self, token_type: &str) -> ParseResult<Token<'a>> {
+        let token: Option<Token> = self.lookahead;
         match token {
-            None => panic!("Unexpected end of input, expected: {}", token_type),
+            None => {
+                return Err(syntax_error(format!(
+                    "Unexpected end of input, expected: {}",
+                    token_type
+                )))
+            }
             Some(t) => {
                 if t.typ != token_type {
-                    panic!("Unexpected token: {}, expected: {}", t.typ, token_type)
+                    return Err(syntax_error(format!(
+                        "Unexpected token: {}, expected: {}",
+                        t.typ, token_type
+                    )));
+                }
+
+                self.lookahead = self.next_token()?;
+
+                return Ok(t);
+            }
+        }
+    }
+}
+
+// Evaluates the raw text of a NUMBER token, returning `None` when the
+// literal is malformed (missing digits, misplaced `_` separators, digits
+// outside of the radix, or a leading zero on a decimal integer).
+fn numeric_value(raw: &str) -> Option<f64> {
+    let radix = match raw.get(..2) {
+        Some("0x") | Some("0X") => 16,
+        Some("0o") | Some("0O") => 8,
+        Some("0b") | Some("0B") => 2,
+        _ => 10,
+    };
+
+    if radix != 10 {
+        let digits = &raw[2..];
+        if !valid_digits(digits, radix) {
+            return None;
+        }
+        return Some(digits.chars().filter(|c| *c != '_').fold(0.0, |acc, c| {
+            acc * radix as f64 + c.to_digit(radix).unwrap() as f64
+        }));
+    }
+
+    let (mantissa, exponent) = match raw.find(['e', 'E']) {
+        Some(i) => (&raw[..i], Some(&raw[i + 1..])),
+        None => (raw, None),
+    };
+    let (integer, fraction) = match mantissa.find('.') {
+        Some(i) => (&mantissa[..i], Some(&mantissa[i + 1..])),
+        None => (mantissa, None),
+    };
+
+    if !integer.is_empty() && !valid_digits(integer, 10) {
+        return None;
+    }
+    if integer.len() > 1 && integer.starts_with('0') {
+        return None;
+    }
+    if let Some(f) = fraction {
+        if !f.is_empty() && !valid_digits(f, 10) {
+            return None;
+        }
+    }
+    if let Some(e) = exponent {
+        let digits = e.strip_prefix(['+', '-']).unwrap_or(e);
+        if !valid_digits(digits, 10) {
+            return None;
+        }
+    }
+
+    return raw.replace('_', "").parse::<f64>().ok();
+}
+
+thread_local! {
+    // Unicode classes are slow to compile, so these are compiled once per
+    // thread.
+    static ID_START_REGEXP: Regex = Regex::new(r"^[\p{ID_Start}$_]$").unwrap();
+    static ID_PART_REGEXP: Regex = Regex::new(r"^[\p{ID_Continue}$\x{200C}\x{200D}]$").unwrap();
+}
+
+// Decodes the `\u` escapes of an IDENTIFIER token, returning `None` when an
+// escape doesn't stand for a character allowed at its position. Names without
+// escapes are borrowed from the source.
+fn identifier_value(raw: &str) -> Option<Cow<'_, str>> {
+    if !raw.contains('\\') {
+        return Some(Cow::Borrowed(raw));
+    }
+
+    let mut name = String::with_capacity(raw.len());
+    let mut chars = raw.char_indices().peekable();
+
+    while let Some((_, c)) = chars.next() {
+        if c != '\\' {
+            name.push(c);
+            continue;
+        }
+        // The IDENTIFIER rule guarantees that a `\` is followed by `u`.
+        chars.next();
+        let c = char::from_u32(unicode_escape(&mut chars)?)?;
+        let valid = if name.is_empty() {
+            &ID_START_REGEXP
+        } else {
+            &ID_PART_REGEXP
+        };
+        if !valid.with(|regexp| regexp.is_match(c.encode_utf8(&mut [0; 4]))) {
+            return None;
+        }
+        name.push(c);
+    }
+
+    return Some(Cow::Owned(name));
+}
+
+// Whether the raw text of a token holds a `\u{...}` code point escape.
+fn has_code_point_escape(raw: &str) -> bool {
+    let mut chars = raw.chars();
+    while let Some(c) = chars.next() {
+        if c == '\\' && chars.next() == Some('u') && chars.as_str().starts_with('{') {
+            return true;
+        }
+    }
+    return false;
+}
+
+// Flags are drawn from `dgimsuyv`, each at most once, and `u` excludes `v`.
+fn valid_regexp_flags(flags: &str) -> bool {
+    let mut seen = HashSet::new();
+    return flags
+        .chars()
+        .all(|c| "dgimsuyv".contains(c) && seen.insert(c))
+        && !(seen.contains(&'u') && seen.contains(&'v'));
+}
+
+// A digit sequence is valid when it's non-empty, only holds digits of the
+// given radix and every `_` separator sits between two digits.
+fn valid_digits(digits: &str, radix: u32) -> bool {
+    return digits
+        .split('_')
+        .all(|part| !part.is_empty() && part.chars().all(|c| c.is_digit(radix)));
+}
+
+// Decodes the escape sequences in the body of a STRING token (the text
+// between the quotes). Errors carry the byte offset of the offending `\`.
+// Bodies without escapes are borrowed from the source.
+//
+// Legacy octal escapes such as `\1` are only allowed outside strict mode.
+// A Rust string can't hold a lone surrogate, so an escaped one that isn't
+// part of a pair, as in `"\uD800"`, decodes to U+FFFD instead; `raw` still
+// has it as written.
+fn string_value(body: &str, strict: bool) -> Result<Cow<'_, str>, (usize, &'static str)> {
+    if !body.contains('\\') {
+        return Ok(Cow::Borrowed(body));
+    }
+
+    let mut value = String::with_capacity(body.len());
+    let mut chars = body.char_indices().peekable();
+
+    while let Some((i, c)) = chars.next() {
+        if c != '\\' {
+            value.push(c);
+            continue;
+        }
+
+        // The STRING rule guarantees that a `\` is always followed by a character.
+        let (_, escaped) = chars.next().unwrap();
+        match escaped {
+            'n' => value.push('\n'),
+            't' => value.push('\t'),
+            'r' => value.push('\r'),
+            'b' => value.push('\u{8}'),
+            'f' => value.push('\u{c}'),
+            'v' => value.push('\u{b}'),
+            '0' if !chars.peek().is_some_and(|(_, c)| c.is_ascii_digit()) => value.push('\0'),
+            '0'..='9' if strict => {
+                return Err((i, "Octal escape sequences are not allowed in strict mode"))
+            }
+            '8' | '9' => value.push(escaped),
+            '0'..='7' => value.push(octal_escape(escaped, &mut chars)),
+            'x' => {
+                let code =
+                    hex_digits(&mut chars, 2).ok_or((i, "Invalid hexadecimal escape sequence"))?;
+                value.push(char::from_u32(code).unwrap());
+            }
+            'u' => {
+                let mut code =
+                    unicode_escape(&mut chars).ok_or((i, "Invalid Unicode escape sequence"))?;
+                // A high surrogate followed by an escaped low surrogate encodes a single code point.
+                if (0xD800..0xDC00).contains(&code) {
+                    let mut rest = chars.clone();
+                    if let (Some((_, '\\')), Some((_, 'u'))) = (rest.next(), rest.next()) {
+                        if let Some(low @ 0xDC00..=0xDFFF) = unicode_escape(&mut rest) {
+                            code = 0x10000 + ((code - 0xD800) << 10) + (low - 0xDC00);
+                            chars = rest;
+                        }
+                    }
+                }
+                match char::from_u32(code) {
+                    Some(c) => value.push(c),
+                    None if (0xD800..0xE000).contains(&code) => value.push('\u{FFFD}'),
+                    None => return Err((i, "Invalid Unicode escape sequence")),
                 }
+            }
+            // Line continuation: an escaped line terminator is dropped from the value.
+            '\r' => {
+                if let Some((_, '\n')) = chars.peek() {
+                    chars.next();
+                }
+            }
+            '\n' | '\u{2028}' | '\u{2029}' => {}
+            other => value.push(other),
+        }
+    }
+
+    return Ok(Cow::Owned(value));
+}
+
+// Reads the code point of a `\uHHHH` or `\u{H...}` escape, after the `u`.
+fn unicode_escape(chars: &mut Peekable<CharIndices>) -> Option<u32> {
+    if let Some((_, '{')) = chars.peek() {
+        chars.next();
+        let mut code: u32 = 0;
+        for (digits, (_, c)) in chars.by_ref().enumerate() {
+            if c == '}' {
+                return if digits > 0 && code <= 0x10FFFF {
+                    Some(code)
+                } else {
+                    None
+                };
+            }
+            code = code.checked_mul(16)?.checked_add(c.to_digit(16)?)?;
+        }
+        return None;
+    }
 
-                self.lookahead = self.tokenizer.next();
+    return hex_digits(chars, 4);
+}
 
-                return t;
+// Reads exactly `count` hexadecimal digits.
+// Reads the digits of a legacy octal escape after its first one. It takes up
+// to three digits in all, as long as the value stays within `\377`.
+fn octal_escape(first: char, chars: &mut Peekable<CharIndices>) -> char {
+    let len = if first <= '3' { 3 } else { 2 };
+    let mut code = first.to_digit(8).unwrap();
+    for _ in 1..len {
+        match chars.peek().and_then(|(_, c)| c.to_digit(8)) {
+            Some(digit) => {
+                code = code * 8 + digit;
+                chars.next();
             }
+            None => break,
         }
     }
+    return char::from_u32(code).unwrap();
+}
+
+fn hex_digits(chars: &mut Peekable<CharIndices>, count: usize) -> Option<u32> {
+    let mut code = 0;
+    for _ in 0..count {
+        let (_, c) = chars.next()?;
+        code = code * 16 + c.to_digit(16)?;
+    }
+    return Some(code);
 }
@@ -0,0 +1,359 @@
+use super::*;
+
+// JSX children are text rather than tokens, so while inside an element the
+// parser reads them straight from the tokenizer. Between children the
+// tokenizer's cursor sits right after the last character consumed and the
+// lookahead is empty.
+impl<'a> Parser<'a> {
+    // JSXExpression
+    //  : JSXElement
+    //  | JSXFragment
+    //  ;
+    pub(super) fn jsx_expression(&mut self) -> ParseResult<NodeId> {
+        let expression = self.jsx_element()?;
+        self.lookahead = self.next_token()?;
+
+        return Ok(expression);
+    }
+
+    // JSXElement
+    //  : "<" JSXElementName JSXAttributes "/" ">"
+    //  | "<" JSXElementName JSXAttributes ">" JSXChildren "<" "/" JSXElementName ">"
+    //  ;
+    //
+    // JSXFragment
+    //  : "<" ">" JSXChildren "<" "/" ">"
+    //  ;
+    fn jsx_element(&mut self) -> ParseResult<NodeId> {
+        let start = self.lookahead.as_ref().unwrap().start;
+        self.eat_operator("<")?;
+
+        if self.lookahead_value().starts_with('>') {
+            self.jsx_tag_end()?;
+            let (children, closing_start) = self.jsx_children(start)?;
+            if self.lookahead_value().starts_with('>') {
+                self.jsx_tag_end()?;
+            } else {
+                let name = self.jsx_closing_name()?;
+                return Err(self.jsx_mismatched_closing_tag(start, "", closing_start, &name));
+            }
+
+            return Ok(self.ast.add(Node::JSXFragment { children }));
+        }
+
+        let name = self.jsx_element_name()?;
+        let attributes = self.jsx_attributes()?;
+
+        let self_closing = self.lookahead_value() == "/";
+        let children = if self_closing {
+            self.eat_operator("/")?;
+            self.jsx_tag_end()?;
+            NodeList::default()
+        } else {
+            self.jsx_tag_end()?;
+            let (children, closing_start) = self.jsx_children(start)?;
+            let closing_name = if self.lookahead_value().starts_with('>') {
+                String::new()
+            } else {
+                self.jsx_closing_name()?
+            };
+            let opening_name = jsx_name_string(&self.ast, name);
+            if closing_name != opening_name {
+                return Err(self.jsx_mismatched_closing_tag(
+                    start,
+                    &opening_name,
+                    closing_start,
+                    &closing_name,
+                ));
+            }
+            self.jsx_tag_end()?;
+            children
+        };
+
+        return Ok(self.ast.add(Node::JSXElement {
+            name,
+            attributes,
+            children,
+            self_closing,
+        }));
+    }
+
+    fn jsx_mismatched_closing_tag(
+        &self,
+        opening_start: usize,
+        opening_name: &str,
+        closing_start: usize,
+        closing_name: &str,
+    ) -> ParseError {
+        let (line, column) = self.tokenizer.location(opening_start);
+        let (closing_line, closing_column) = self.tokenizer.location(closing_start);
+        return syntax_error(format!(
+            "Expected corresponding JSX closing tag for <{}> ({}:{}), found </{}> ({}:{})",
+            opening_name, line, column, closing_name, closing_line, closing_column
+        ));
+    }
+
+    // Ends a tag at its `>`, leaving the tokenizer right after it so the
+    // children can be read as text. The lookahead may have been read as
+    // `>=` or `>>`, so only its first character is consumed.
+    fn jsx_tag_end(&mut self) -> ParseResult<()> {
+        match self.lookahead.take() {
+            Some(token) if token.value.starts_with('>') => {
+                self.tokenizer.cursor = (token.start + 1) as u64;
+            }
+            Some(token) => {
+                return Err(syntax_error(format!(
+                    "Unexpected token: {}, expected: >",
+                    token.typ
+                )))
+            }
+            None => return Err(syntax_error("Unexpected end of input, expected: >")),
+        }
+        return Ok(());
+    }
+
+    // JSXChildren
+    //  : (JSXText | JSXExpressionContainer | JSXElement | JSXFragment)*
+    //  ;
+    //
+    // Stops after the `<` `/` of the closing tag, returning the offset of its
+    // `<` along with the children.
+    fn jsx_children(&mut self, start: usize) -> ParseResult<(NodeList, usize)> {
+        let children = self.ast.start_list();
+
+        loop {
+            if let Some(token) = self.tokenizer.read_jsx_text() {
+                let text = self.ast.add(Node::JSXText {
+                    value: Cow::Borrowed(token.value),
+                });
+                self.ast.push_item(text);
+            }
+
+            self.lookahead = self.next_token()?;
+            match self.lookahead_type() {
+                "{" => {
+                    let container = self.jsx_expression_container()?;
+                    self.ast.push_item(container);
+                    self.jsx_close_brace()?;
+                }
+                "RELATIONAL_OPERATOR" if self.peek_value() == Some("/") => {
+                    let closing_start = self.eat_operator("<")?.start;
+                    self.eat_operator("/")?;
+                    return Ok((self.ast.finish_list(children), closing_start));
+                }
+                "RELATIONAL_OPERATOR" => {
+                    let element = self.nested(Self::jsx_element)?;
+                    self.ast.push_item(element);
+                }
+                _ => {
+                    let (line, column) = self.tokenizer.location(start);
+                    return Err(syntax_error(format!(
+                        "Unterminated JSX contents ({}:{})",
+                        line, column
+                    )));
+                }
+            }
+        }
+    }
+
+    // JSXExpressionContainer
+    //  : "{" OptExpression "}"
+    //  ;
+    //
+    // Leaves the closing "}" as the lookahead.
+    fn jsx_expression_container(&mut self) -> ParseResult<NodeId> {
+        self.eat("{")?;
+        let expression = if self.lookahead_type() == "}" {
+            None
+        } else {
+            Some(self.expression()?)
+        };
+
+        return Ok(self.ast.add(Node::JSXExpressionContainer { expression }));
+    }
+
+    // Consumes the "}" of a container among the children without reading
+    // the text that follows it as tokens.
+    fn jsx_close_brace(&mut self) -> ParseResult<()> {
+        match self.lookahead.take() {
+            Some(token) if token.typ == "}" => {
+                self.tokenizer.cursor = token.end as u64;
+            }
+            Some(token) => {
+                return Err(syntax_error(format!(
+                    "Unexpected token: {}, expected: }}",
+                    token.typ
+                )))
+            }
+            None => return Err(syntax_error("Unexpected end of input, expected: }")),
+        }
+        return Ok(());
+    }
+
+    // JSXAttributes
+    //  : (JSXAttribute | JSXSpreadAttribute)*
+    //  ;
+    //
+    // JSXAttribute
+    //  : JSXIdentifier
+    //  | JSXIdentifier "=" JSXAttributeValue
+    //  ;
+    //
+    // JSXSpreadAttribute
+    //  : "{" "..." AssignmentExpression "}"
+    //  ;
+    fn jsx_attributes(&mut self) -> ParseResult<NodeList> {
+        let attributes = self.ast.start_list();
+
+        while self.lookahead_value() != "/" && !self.lookahead_value().starts_with('>') {
+            if self.lookahead_type() == "{" {
+                self.eat("{")?;
+                self.eat("...")?;
+                let argument = self.assignment_expression()?;
+                self.eat("}")?;
+                let attribute = self.ast.add(Node::JSXSpreadAttribute { argument });
+                self.ast.push_item(attribute);
+                continue;
+            }
+
+            let name = self.jsx_identifier()?;
+            let value = if self.lookahead_type() == "SIMPLE_ASSIGN" {
+                self.eat("SIMPLE_ASSIGN")?;
+                Some(self.jsx_attribute_value()?)
+            } else {
+                None
+            };
+            let attribute = self.ast.add(Node::JSXAttribute { name, value });
+            self.ast.push_item(attribute);
+        }
+
+        return Ok(self.ast.finish_list(attributes));
+    }
+
+    // JSXAttributeValue
+    //  : StringLiteral
+    //  | JSXExpressionContainer
+    //  | JSXElement
+    //  | JSXFragment
+    //  ;
+    //
+    // Attribute strings have no escape sequences.
+    fn jsx_attribute_value(&mut self) -> ParseResult<NodeId> {
+        match self.lookahead_type() {
+            "STRING" => {
+                let raw = self.eat("STRING")?.value;
+                let value = self.ast.intern(&raw[1..raw.len() - 1]);
+                return Ok(self.ast.add(Node::StringLiteral {
+                    value,
+                    raw: Cow::Borrowed(raw),
+                }));
+            }
+            "{" => {
+                let container = self.jsx_expression_container()?;
+                self.eat("}")?;
+                return Ok(container);
+            }
+            _ if self.lookahead_value() == "<" => {
+                let value = self.nested(Self::jsx_element)?;
+                self.lookahead = self.next_token()?;
+                return Ok(value);
+            }
+            typ => {
+                return Err(syntax_error(format!(
+                    "Unexpected token: {}, expected: JSX attribute value",
+                    typ
+                )))
+            }
+        }
+    }
+
+    // JSXElementName
+    //  : JSXIdentifier
+    //  | JSXElementName "." JSXIdentifier
+    //  ;
+    fn jsx_element_name(&mut self) -> ParseResult<NodeId> {
+        let mut name = self.jsx_identifier()?;
+        let mut levels = 0;
+
+        while self.lookahead_type() == "." {
+            self.deepen()?;
+            levels += 1;
+            self.eat(".")?;
+            let property = self.jsx_identifier()?;
+            name = self.ast.add(Node::JSXMemberExpression {
+                object: name,
+                property,
+            });
+        }
+        self.depth -= levels;
+
+        return Ok(name);
+    }
+
+    // The name of a closing tag, which is only compared with the name of its
+    // opening tag and so isn't added to the Ast.
+    fn jsx_closing_name(&mut self) -> ParseResult<String> {
+        let mut name = self.jsx_identifier_text()?.to_string();
+
+        while self.lookahead_type() == "." {
+            self.eat(".")?;
+            name.push('.');
+            name.push_str(self.jsx_identifier_text()?);
+        }
+
+        return Ok(name);
+    }
+
+    // JSXIdentifier
+    //  : JSX_IDENTIFIER
+    //  ;
+    //
+    // The lookahead was read as an ordinary token, so it's read again from
+    // its start to take in any dashes.
+    fn jsx_identifier(&mut self) -> ParseResult<NodeId> {
+        let text = self.jsx_identifier_text()?;
+        let name = self.ast.intern(text);
+
+        return Ok(self.ast.add(Node::JSXIdentifier {
+            name,
+            text: Some(text),
+        }));
+    }
+
+    fn jsx_identifier_text(&mut self) -> ParseResult<&'a str> {
+        let start = match &self.lookahead {
+            Some(token) => token.start,
+            None => {
+                return Err(syntax_error(
+                    "Unexpected end of input, expected: JSX identifier",
+                ))
+            }
+        };
+        self.lookahead = Some(
+            self.tokenizer
+                .try_read_jsx_identifier(start)
+                .map_err(syntax_error)?,
+        );
+        return Ok(self.eat("JSX_IDENTIFIER")?.value);
+    }
+
+    // Value of the token following the lookahead.
+    fn peek_value(&self) -> Option<&'a str> {
+        return self
+            .tokenizer
+            .clone()
+            .try_next()
+            .ok()
+            .flatten()
+            .map(|t| t.value);
+    }
+}
+
+fn jsx_name_string(ast: &Ast, name: NodeId) -> String {
+    return match ast.node(name) {
+        Node::JSXMemberExpression { object, property } => {
+            format!("{}.{}", jsx_name_string(ast, *object), ast.name(*property))
+        }
+        _ => ast.name(name).to_string(),
+    };
+}
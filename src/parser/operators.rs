@@ -0,0 +1,162 @@
+use super::*;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Associativity {
+    Left,
+    Right,
+}
+
+// An infix operator, matched by the type of its token. Operators with a
+// higher precedence bind tighter, and `build` adds the node joining the
+// operator's operands to the Ast.
+#[derive(Debug, Clone, Copy)]
+pub struct BinaryOperator {
+    pub token: &'static str,
+    pub precedence: u8,
+    pub associativity: Associativity,
+    pub build: for<'a> fn(&mut Ast<'a>, Cow<'a, str>, NodeId, NodeId) -> NodeId,
+}
+
+impl BinaryOperator {
+    // A left-associative operator making a BinaryExpression.
+    pub fn binary(token: &'static str, precedence: u8) -> Self {
+        Self {
+            token,
+            precedence,
+            associativity: Associativity::Left,
+            build: binary_expression,
+        }
+    }
+
+    // A left-associative operator making a LogicalExpression.
+    pub fn logical(token: &'static str, precedence: u8) -> Self {
+        Self {
+            token,
+            precedence,
+            associativity: Associativity::Left,
+            build: logical_expression,
+        }
+    }
+
+    // The operators of the language. `**` isn't among them: only a unary
+    // expression may be its left operand, so it's parsed along with them.
+    pub fn defaults() -> Vec<Self> {
+        return vec![
+            Self::logical("LOGICAL_OR", 1),
+            Self::logical("NULLISH_COALESCING", 1),
+            Self::logical("LOGICAL_AND", 2),
+            Self::binary("BITWISE_OR", 3),
+            Self::binary("BITWISE_XOR", 4),
+            Self::binary("BITWISE_AND", 5),
+            Self::binary("EQUALITY_OPERATOR", 6),
+            Self::binary("RELATIONAL_OPERATOR", 7),
+            Self::binary("SHIFT_OPERATOR", 8),
+            Self::binary("ADDITIVE_OPERATOR", 9),
+            Self::binary("MULTIPLICATIVE_OPERATOR", 10),
+        ];
+    }
+}
+
+fn binary_expression<'a>(
+    ast: &mut Ast<'a>,
+    operator: Cow<'a, str>,
+    left: NodeId,
+    right: NodeId,
+) -> NodeId {
+    return ast.add(Node::BinaryExpression {
+        operator,
+        left,
+        right,
+    });
+}
+
+fn logical_expression<'a>(
+    ast: &mut Ast<'a>,
+    operator: Cow<'a, str>,
+    left: NodeId,
+    right: NodeId,
+) -> NodeId {
+    return ast.add(Node::LogicalExpression {
+        operator,
+        left,
+        right,
+    });
+}
+
+impl<'a> Parser<'a> {
+    // BinaryExpression
+    //  : ExponentiationExpression
+    //  | BinaryExpression BinaryOperator BinaryExpression
+    //  ;
+    //
+    // Parses operands joined by the operators of `options.binary_operators`
+    // whose precedence is at least `min_precedence`.
+    pub(super) fn binary_expression(&mut self, min_precedence: u8) -> ParseResult<NodeId> {
+        return Ok(self.binary_operation(u16::from(min_precedence))?.0);
+    }
+
+    // Also returns the token of the operator joining the expression, unless
+    // it's an operand on its own, such as a parenthesized expression.
+    fn binary_operation(
+        &mut self,
+        min_precedence: u16,
+    ) -> ParseResult<(NodeId, Option<&'static str>)> {
+        let mut left = self.nested(Self::exponentiation_expression)?;
+        let mut left_token = None;
+        let mut levels = 0;
+
+        while let Some(operator) = self.binary_operator() {
+            if u16::from(operator.precedence) < min_precedence {
+                break;
+            }
+            self.deepen()?;
+            levels += 1;
+            if operator.token == "NULLISH_COALESCING" {
+                self.check_modern("??")?;
+            }
+            let value = Cow::Borrowed(self.eat(operator.token)?.value);
+
+            let right_precedence = match operator.associativity {
+                Associativity::Left => u16::from(operator.precedence) + 1,
+                Associativity::Right => u16::from(operator.precedence),
+            };
+            let (right, right_token) =
+                self.nested(|parser| parser.binary_operation(right_precedence))?;
+            check_not_mixed_with_coalesce(operator.token, left_token)?;
+            check_not_mixed_with_coalesce(operator.token, right_token)?;
+
+            left = (operator.build)(&mut self.ast, value, left, right);
+            left_token = Some(operator.token);
+        }
+        self.depth -= levels;
+
+        return Ok((left, left_token));
+    }
+
+    fn binary_operator(&self) -> Option<BinaryOperator> {
+        let token_type = self.lookahead.as_ref()?.typ;
+        return self
+            .options
+            .binary_operators
+            .iter()
+            .find(|operator| operator.token == token_type)
+            .copied();
+    }
+}
+
+// `??` can't be combined with `&&` or `||` unless one side is parenthesized.
+fn check_not_mixed_with_coalesce(token: &str, operand_token: Option<&str>) -> ParseResult<()> {
+    let is_coalesce = |token: &str| token == "NULLISH_COALESCING";
+    let is_logical = |token: &str| token == "LOGICAL_AND" || token == "LOGICAL_OR";
+
+    if let Some(operand_token) = operand_token {
+        if (is_coalesce(token) && is_logical(operand_token))
+            || (is_logical(token) && is_coalesce(operand_token))
+        {
+            return Err(syntax_error(
+                "Cannot mix ?? with && or || without parentheses",
+            ));
+        }
+    }
+    return Ok(());
+}
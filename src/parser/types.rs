@@ -0,0 +1,233 @@
+use super::*;
+
+// Type names that refer to a predefined type rather than a declared one.
+const KEYWORD_TYPES: [&str; 12] = [
+    "any",
+    "unknown",
+    "never",
+    "void",
+    "undefined",
+    "null",
+    "boolean",
+    "number",
+    "bigint",
+    "string",
+    "symbol",
+    "object",
+];
+
+impl<'a> Parser<'a> {
+    // `type` and `interface` start a declaration only when a name follows
+    // them, so `type = 1` is still an assignment.
+    pub(super) fn is_type_declaration_start(&self, keyword: &str) -> bool {
+        return self.is_contextual_keyword(keyword) && self.peek_type() == Some("IDENTIFIER");
+    }
+
+    // TypeAliasDeclaration
+    //  : "type" Identifier "=" Type ";"
+    //  ;
+    pub(super) fn type_alias_declaration(&mut self) -> ParseResult<NodeId> {
+        self.contextual_keyword("type")?;
+        let id = self.identifier()?;
+        self.eat("SIMPLE_ASSIGN")?;
+        let type_annotation = self.type_()?;
+        self.consume_semicolon()?;
+
+        return Ok(self.ast.add(Node::TypeAliasDeclaration {
+            id,
+            type_annotation,
+        }));
+    }
+
+    // InterfaceDeclaration
+    //  : "interface" Identifier OptInterfaceExtends InterfaceBody
+    //  ;
+    //
+    // InterfaceExtends
+    //  : "extends" TypeReference ("," TypeReference)*
+    //  ;
+    pub(super) fn interface_declaration(&mut self) -> ParseResult<NodeId> {
+        self.contextual_keyword("interface")?;
+        let id = self.identifier()?;
+
+        let items = self.ast.start_list();
+        if self.lookahead_type() == "extends" {
+            self.eat("extends")?;
+            loop {
+                let name = Cow::Borrowed(self.eat("IDENTIFIER")?.value);
+                let reference = self.ast.add(Node::TypeReference { name });
+                self.ast.push_item(reference);
+                if self.lookahead_type() != "," {
+                    break;
+                }
+                self.eat(",")?;
+            }
+        }
+
+        let extends = self.ast.finish_list(items);
+        let body = self.interface_body()?;
+
+        return Ok(self
+            .ast
+            .add(Node::InterfaceDeclaration { id, extends, body }));
+    }
+
+    // InterfaceBody
+    //  : "{" PropertySignature* "}"
+    //  ;
+    //
+    // PropertySignature
+    //  : IdentifierName OptOptional TypeAnnotation OptSeparator
+    //  ;
+    //
+    // Members are separated by `;` or `,`, which may be left out at the end
+    // of a line.
+    fn interface_body(&mut self) -> ParseResult<NodeId> {
+        self.eat("{")?;
+        let items = self.ast.start_list();
+
+        while self.lookahead_type() != "}" {
+            let key = self.identifier_name()?;
+            let optional = self.lookahead_type() == "?";
+            if optional {
+                self.eat("?")?;
+            }
+            let type_annotation = self.type_annotation()?;
+            let signature = self.ast.add(Node::PropertySignature {
+                key,
+                optional,
+                type_annotation,
+            });
+            self.ast.push_item(signature);
+
+            match self.lookahead_type() {
+                ";" | "," => {
+                    self.eat(self.lookahead_type())?;
+                }
+                "}" => {}
+                _ if self.has_newline_before() => {}
+                typ => {
+                    return Err(syntax_error(format!(
+                        "Unexpected token: {}, expected: ;",
+                        typ
+                    )))
+                }
+            }
+        }
+        self.eat("}")?;
+        let body = self.ast.finish_list(items);
+
+        return Ok(self.ast.add(Node::InterfaceBody { body }));
+    }
+
+    // Adds the identifier of a binding name along with the annotation that
+    // may follow it. Parameters may also be marked optional with `?`.
+    pub(super) fn annotated_identifier(
+        &mut self,
+        name: Cow<'a, str>,
+        allow_optional: bool,
+    ) -> ParseResult<NodeId> {
+        let optional = allow_optional && self.lookahead_type() == "?";
+        if optional {
+            self.eat("?")?;
+        }
+        let type_annotation = self.opt_type_annotation()?;
+
+        return Ok(self.identifier_node(name, type_annotation, optional));
+    }
+
+    pub(super) fn opt_type_annotation(&mut self) -> ParseResult<Option<NodeId>> {
+        if self.lookahead_type() != ":" {
+            return Ok(None);
+        }
+        return Ok(Some(self.type_annotation()?));
+    }
+
+    // TypeAnnotation
+    //  : ":" Type
+    //  ;
+    fn type_annotation(&mut self) -> ParseResult<NodeId> {
+        self.eat(":")?;
+        let type_annotation = self.type_()?;
+        return Ok(self.ast.add(Node::TypeAnnotation { type_annotation }));
+    }
+
+    // Type
+    //  : OptBITWISE_OR ArrayType
+    //  | Type BITWISE_OR ArrayType
+    //  ;
+    fn type_(&mut self) -> ParseResult<NodeId> {
+        if self.lookahead_type() == "BITWISE_OR" {
+            self.eat("BITWISE_OR")?;
+        }
+
+        let first = self.array_type()?;
+        if self.lookahead_type() != "BITWISE_OR" {
+            return Ok(first);
+        }
+
+        let items = self.ast.start_list();
+        self.ast.push_item(first);
+        while self.lookahead_type() == "BITWISE_OR" {
+            self.eat("BITWISE_OR")?;
+            let type_ = self.array_type()?;
+            self.ast.push_item(type_);
+        }
+        let types = self.ast.finish_list(items);
+
+        return Ok(self.ast.add(Node::UnionType { types }));
+    }
+
+    // ArrayType
+    //  : PrimaryType
+    //  | ArrayType "[" "]"
+    //  ;
+    fn array_type(&mut self) -> ParseResult<NodeId> {
+        let mut element_type = self.primary_type()?;
+        let mut levels = 0;
+
+        while self.lookahead_type() == "[" {
+            self.deepen()?;
+            levels += 1;
+            self.eat("[")?;
+            self.eat("]")?;
+            element_type = self.ast.add(Node::ArrayType { element_type });
+        }
+        self.depth -= levels;
+
+        return Ok(element_type);
+    }
+
+    // PrimaryType
+    //  : "(" Type ")"
+    //  | Literal
+    //  | IDENTIFIER
+    //  ;
+    fn primary_type(&mut self) -> ParseResult<NodeId> {
+        match self.lookahead_type() {
+            "(" => {
+                self.eat("(")?;
+                let inner = self.nested(Self::type_)?;
+                self.eat(")")?;
+                return Ok(inner);
+            }
+            "null" => {
+                self.eat("null")?;
+                return Ok(self.ast.add(Node::KeywordType {
+                    name: Cow::Borrowed("null"),
+                }));
+            }
+            typ if self.is_literal(typ) => {
+                let literal = self.literal()?;
+                return Ok(self.ast.add(Node::LiteralType { literal }));
+            }
+            _ => {}
+        }
+
+        let name = Cow::Borrowed(self.eat("IDENTIFIER")?.value);
+        if KEYWORD_TYPES.contains(&&*name) {
+            return Ok(self.ast.add(Node::KeywordType { name }));
+        }
+        return Ok(self.ast.add(Node::TypeReference { name }));
+    }
+}
@@ -0,0 +1,513 @@
+use super::nodes::*;
+use super::parser::{ParseError, Parser, ParserOptions};
+use super::tokenizer::{is_line_terminator, Tokenizer};
+use std::io::{self, Read};
+use std::str;
+
+// Bytes asked of the reader at a time.
+const CHUNK_SIZE: usize = 64 * 1024;
+
+// Bytes of input read ahead of the current token by default.
+const WINDOW: usize = 64 * 1024;
+
+// The part of the input read so far that hasn't been consumed yet. `text`
+// starts `offset` bytes into the input, at line and column `origin`.
+#[derive(Debug)]
+struct Source<R> {
+    reader: R,
+    text: String,
+    // The start of a character split between two reads.
+    partial: Vec<u8>,
+    chunk: Vec<u8>,
+    offset: usize,
+    origin: (usize, usize),
+    eof: bool,
+}
+
+impl<R: Read> Source<R> {
+    fn new(reader: R) -> Self {
+        Self {
+            reader,
+            text: String::new(),
+            partial: Vec::new(),
+            chunk: vec![0; CHUNK_SIZE],
+            offset: 0,
+            origin: (1, 1),
+            eof: false,
+        }
+    }
+
+    // Makes sure `ahead` bytes of text follow the cursor, unless the input
+    // ends first. Text before the cursor is dropped when more has to be
+    // read, and the cursor moved back to the start of the text. Twice the
+    // bytes asked for are read then, so that moving the text back happens
+    // once per `ahead` bytes consumed at most.
+    fn ensure(&mut self, cursor: &mut usize, ahead: usize) -> io::Result<()> {
+        if self.eof || self.text.len() >= *cursor + ahead {
+            return Ok(());
+        }
+        self.discard(*cursor);
+        *cursor = 0;
+        return self.fill(2 * ahead);
+    }
+
+    fn discard(&mut self, len: usize) {
+        let discarded = &self.text[..len];
+        let lines = discarded.matches('\n').count();
+        let column = discarded.chars().rev().take_while(|c| *c != '\n').count();
+        self.origin = if lines == 0 {
+            (self.origin.0, self.origin.1 + column)
+        } else {
+            (self.origin.0 + lines, column + 1)
+        };
+        self.offset += len;
+        self.text.drain(..len);
+    }
+
+    // Reads until the text is `len` bytes long or the input ends.
+    fn fill(&mut self, len: usize) -> io::Result<()> {
+        while self.text.len() < len && !self.eof {
+            let read = match self.reader.read(&mut self.chunk) {
+                Ok(read) => read,
+                Err(error) if error.kind() == io::ErrorKind::Interrupted => continue,
+                Err(error) => return Err(error),
+            };
+            if read == 0 {
+                self.eof = true;
+                if !self.partial.is_empty() {
+                    return Err(invalid_utf8(self.offset + self.text.len()));
+                }
+                break;
+            }
+
+            self.partial.extend_from_slice(&self.chunk[..read]);
+            let valid = match str::from_utf8(&self.partial) {
+                Ok(text) => text.len(),
+                // The last character isn't complete yet.
+                Err(error) if error.error_len().is_none() => error.valid_up_to(),
+                Err(error) => {
+                    return Err(invalid_utf8(
+                        self.offset + self.text.len() + error.valid_up_to(),
+                    ))
+                }
+            };
+            self.text
+                .push_str(str::from_utf8(&self.partial[..valid]).unwrap());
+            self.partial.drain(..valid);
+        }
+        return Ok(());
+    }
+
+    // A tokenizer over the text, reporting locations in the whole input.
+    fn tokenizer<'a>(&'a self, template: &Tokenizer<'static>, cursor: usize) -> Tokenizer<'a> {
+        let mut tokenizer = template.clone();
+        tokenizer.init(&self.text);
+        tokenizer.origin = self.origin;
+        tokenizer.cursor = cursor as u64;
+        return tokenizer;
+    }
+
+    // The number of bytes to read ahead of the cursor when what has been
+    // read so far isn't enough.
+    fn more(&self, cursor: usize, window: usize) -> usize {
+        return window.max(2 * (self.text.len() - cursor));
+    }
+}
+
+// Whether the text starts with a block comment or a string literal that
+// doesn't end before the text does.
+fn is_cut_short(text: &str) -> bool {
+    if let Some(comment) = text.strip_prefix("/*") {
+        return !comment.contains("*/");
+    }
+    let bytes = text.as_bytes();
+    let quote = match bytes.first() {
+        Some(quote @ (b'"' | b'\'')) => *quote,
+        _ => return false,
+    };
+    let mut i = 1;
+    while i < bytes.len() {
+        match bytes[i] {
+            b'\\' => i += 2,
+            b'\n' | b'\r' => return false,
+            byte if byte == quote => return false,
+            _ => i += 1,
+        }
+    }
+    return true;
+}
+
+// An error reading the input of a stream, or text in it that isn't a token
+// or doesn't parse.
+#[derive(Debug)]
+pub enum StreamError {
+    Io(io::Error),
+    Parse(ParseError),
+}
+
+impl std::fmt::Display for StreamError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            StreamError::Io(error) => write!(f, "{}", error),
+            StreamError::Parse(error) => write!(f, "{}", error),
+        }
+    }
+}
+
+impl std::error::Error for StreamError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            StreamError::Io(error) => Some(error),
+            StreamError::Parse(error) => Some(error),
+        }
+    }
+}
+
+impl From<io::Error> for StreamError {
+    fn from(error: io::Error) -> Self {
+        StreamError::Io(error)
+    }
+}
+
+impl From<ParseError> for StreamError {
+    fn from(error: ParseError) -> Self {
+        StreamError::Parse(error)
+    }
+}
+
+fn invalid_utf8(offset: usize) -> io::Error {
+    return io::Error::new(
+        io::ErrorKind::InvalidData,
+        format!("Invalid UTF-8 at offset {}", offset),
+    );
+}
+
+// Reads the tokens of an input too large to hold in memory at once, such
+// as a file or a socket, from a reader. The input is read in chunks, of
+// which only the part from the current token onwards is kept. Token spans
+// are byte offsets into the whole input, and errors report its lines and
+// columns.
+//
+// At least `window` bytes are kept read ahead of each token, so that a
+// token split between two chunks is read whole. More is read for a comment
+// or string literal that goes on past them.
+#[derive(Debug)]
+pub struct StreamTokenizer<R> {
+    // Reads the tokens of the text held, see `Tokenizer`. Its spec is that
+    // of the stream.
+    pub tokenizer: Tokenizer<'static>,
+    pub window: usize,
+    source: Source<R>,
+    cursor: usize,
+}
+
+impl<R: Read> StreamTokenizer<R> {
+    pub fn new(reader: R) -> Self {
+        Self {
+            tokenizer: Tokenizer::new(),
+            window: WINDOW,
+            source: Source::new(reader),
+            cursor: 0,
+        }
+    }
+
+    // Reads the next token, which is valid until the next call. Errors
+    // reading the input and text that isn't a token are returned.
+    pub fn next_token(&mut self) -> Result<Option<Token<'_>>, StreamError> {
+        let mut ahead = self.window;
+        let (typ, start, end, newline_before) = loop {
+            self.source.ensure(&mut self.cursor, ahead)?;
+            let mut tokenizer = self.source.tokenizer(&self.tokenizer, self.cursor);
+            let text = self.source.text.as_str();
+            let len = text.len();
+            let eof = self.source.eof;
+
+            match tokenizer.try_next() {
+                // A token running to the end of the text read so far may go
+                // on in the next chunk, and a `/` may start a comment that
+                // does.
+                Ok(Some(token))
+                    if eof || (token.end < len && !is_cut_short(&text[token.start..])) =>
+                {
+                    break (token.typ, token.start, token.end, token.newline_before)
+                }
+                Ok(None) if eof => return Ok(None),
+                Err(message)
+                    if eof
+                        || (tokenizer.cursor as usize + self.window <= len
+                            && !is_cut_short(&text[tokenizer.cursor as usize..])) =>
+                {
+                    return Err(StreamError::Parse(ParseError::SyntaxError { message }))
+                }
+                _ => ahead = self.source.more(self.cursor, self.window),
+            }
+        };
+
+        self.cursor = end;
+        let offset = self.source.offset;
+        return Ok(Some(Token {
+            typ,
+            value: &self.source.text[start..end],
+            start: offset + start,
+            end: offset + end,
+            newline_before,
+        }));
+    }
+}
+
+// Where the statement at the cursor may end.
+enum Extent {
+    // There are no tokens before the end of the text.
+    Empty,
+    // The statement may go on past the end of the text.
+    Open,
+    Until(usize),
+}
+
+// Parses the top-level statements of an input too large to hold in memory
+// at once from a reader, one statement at a time. Only the text of the
+// statement being parsed is kept, along with `window` bytes read ahead of
+// it. Statements borrow that text, which is replaced as more is read, so
+// each is handed to a callback as it's parsed:
+//
+//     let mut stream = StatementStream::new(File::open("bundle.js")?);
+//     while let Some(name) = stream.next_statement(|statement| describe(&statement))? {
+//         ...
+//     }
+//
+// Statements are parsed as they would be in the body of the whole Program,
+// with its directive prologue and hashbang, and errors report the lines
+// and columns of the whole input. As statements are parsed on their own,
+// declarations aren't checked against those of other statements, and the
+// limits of the options apply to each statement rather than to the input.
+#[derive(Debug)]
+pub struct StatementStream<R> {
+    // Updated to strict mode by a "use strict" directive.
+    pub options: ParserOptions,
+    // Reads the tokens of the text held.
+    pub tokenizer: Tokenizer<'static>,
+    pub window: usize,
+    source: Source<R>,
+    cursor: usize,
+    // Kept from one statement to the next, so that its storage is reused.
+    parser: Option<Parser<'static>>,
+    started: bool,
+    in_prologue: bool,
+    hashbang: Option<String>,
+}
+
+impl<R: Read> StatementStream<R> {
+    pub fn new(reader: R) -> Self {
+        Self::with_options(reader, ParserOptions::default())
+    }
+
+    pub fn with_options(reader: R, options: ParserOptions) -> Self {
+        Self {
+            options,
+            tokenizer: Tokenizer::new(),
+            window: WINDOW,
+            source: Source::new(reader),
+            cursor: 0,
+            parser: None,
+            started: false,
+            in_prologue: true,
+            hashbang: None,
+        }
+    }
+
+    // The `#!` line of the input, without the `#!`, once the first statement
+    // has been read.
+    pub fn hashbang(&self) -> Option<&str> {
+        return self.hashbang.as_deref();
+    }
+
+    // Parses the next statement and returns what `f` makes of it, or None
+    // at the end of the input. Errors reading the input and syntax errors
+    // are returned.
+    pub fn next_statement<T>(
+        &mut self,
+        f: impl FnOnce(Statement<'_>) -> T,
+    ) -> Result<Option<T>, StreamError> {
+        if !self.started {
+            self.started = true;
+            self.skip_hashbang()?;
+        }
+
+        let mut ahead = self.window;
+        loop {
+            self.source.ensure(&mut self.cursor, ahead)?;
+            let eof = self.source.eof;
+            let len = self.source.text.len();
+
+            // Parsing a statement only to find that the text read so far
+            // ends inside it is costly, so its text is skimmed first to
+            // read enough of it.
+            match self.extent() {
+                Extent::Empty if eof => return Ok(None),
+                Extent::Until(end) if eof || end + self.window <= len => {}
+                Extent::Until(end) => {
+                    ahead = end - self.cursor + self.window;
+                    continue;
+                }
+                Extent::Empty | Extent::Open => {
+                    ahead = self.source.more(self.cursor, self.window);
+                    continue;
+                }
+            }
+
+            // The parser borrows the text only while it parses a statement,
+            // and is reset after that to be used for the next one.
+            let mut parser = self.parser.take().unwrap_or_default();
+            std::mem::swap(&mut parser.options, &mut self.options);
+            parser.tokenizer = self.tokenizer.clone();
+            parser.tokenizer.origin = self.source.origin;
+            let text = self.source.text.as_str();
+            let (cursor, in_prologue) = (self.cursor, self.in_prologue);
+            let result = parser.statement_at(text, cursor, in_prologue);
+            let end = parser.tokenizer.cursor as usize;
+            let lookahead = parser.lookahead.map(|token| token.start);
+            let mut parser = parser.reset();
+            std::mem::swap(&mut parser.options, &mut self.options);
+            self.parser = Some(parser);
+
+            let statement = match result {
+                Ok(statement) => statement,
+                Err(error) => {
+                    if eof || end + self.window <= len {
+                        return Err(StreamError::Parse(error));
+                    }
+                    ahead = self.source.more(self.cursor, self.window);
+                    continue;
+                }
+            };
+            // The statement is only known to be whole when the token after
+            // it could be read whole.
+            let next = match lookahead {
+                Some(start) if eof || start + self.window <= len => start,
+                None if eof => len,
+                _ => {
+                    ahead = self.source.more(self.cursor, self.window);
+                    continue;
+                }
+            };
+
+            let (is_directive, use_strict) = match &statement {
+                Statement::Directive(directive) => (true, directive.directive == "use strict"),
+                _ => (false, false),
+            };
+            let value = f(statement);
+            self.cursor = next;
+            self.in_prologue = is_directive;
+            self.options.strict |= use_strict;
+            return Ok(Some(value));
+        }
+    }
+
+    // Parses the remaining statements, handing each to `f`.
+    pub fn for_each_statement(
+        &mut self,
+        mut f: impl FnMut(Statement<'_>),
+    ) -> Result<(), StreamError> {
+        while self.next_statement(&mut f)?.is_some() {}
+        return Ok(());
+    }
+
+    fn skip_hashbang(&mut self) -> io::Result<()> {
+        self.source.fill(2)?;
+        if !self.source.text.starts_with("#!") {
+            return Ok(());
+        }
+        while !self.source.eof && !self.source.text.contains(is_line_terminator) {
+            self.source.fill(self.source.text.len() + self.window)?;
+        }
+
+        let mut tokenizer = self.source.tokenizer(&self.tokenizer, 0);
+        self.hashbang = tokenizer.hashbang().map(String::from);
+        self.cursor = tokenizer.cursor as usize;
+        return Ok(());
+    }
+
+    // Skims the text from the cursor for the end of the statement: the first
+    // `;`, or the first token on a new line, outside of brackets. This is
+    // only a guess, which saves parsing the statement again when it goes on
+    // past the text read so far, so strings and comments are skipped but the
+    // text isn't tokenized.
+    fn extent(&self) -> Extent {
+        let text = &self.source.text[self.cursor..];
+        let bytes = text.as_bytes();
+        let mut depth = 0;
+        let mut started = false;
+        let mut newline = false;
+        let mut i = 0;
+
+        while i < bytes.len() {
+            match (bytes[i], bytes.get(i + 1)) {
+                (b'\n' | b'\r', _) => {
+                    newline = true;
+                    i += 1;
+                    continue;
+                }
+                (b' ' | b'\t' | b'\x0b' | b'\x0c', _) => {
+                    i += 1;
+                    continue;
+                }
+                (b'/', Some(b'/')) => {
+                    i = text[i..]
+                        .find(['\n', '\r'])
+                        .map_or(bytes.len(), |end| i + end);
+                    continue;
+                }
+                (b'/', Some(b'*')) => match text[i + 2..].find("*/") {
+                    Some(end) => {
+                        newline |= text[i + 2..i + 2 + end].contains(is_line_terminator);
+                        i += end + 4;
+                        continue;
+                    }
+                    // Parsing reports it if the comment is never closed.
+                    None => {
+                        started = true;
+                        break;
+                    }
+                },
+                (byte, _) if byte >= 0x80 => {
+                    let c = text[i..].chars().next().unwrap();
+                    if c.is_whitespace() {
+                        newline |= is_line_terminator(c);
+                        i += c.len_utf8();
+                        continue;
+                    }
+                }
+                _ => {}
+            }
+
+            if started && newline && depth == 0 {
+                return Extent::Until(self.cursor + i);
+            }
+            started = true;
+            newline = false;
+
+            match bytes[i] {
+                b'(' | b'[' | b'{' => depth += 1,
+                b')' | b']' | b'}' if depth == 0 => return Extent::Until(self.cursor + i + 1),
+                b')' | b']' | b'}' => depth -= 1,
+                b';' if depth == 0 => return Extent::Until(self.cursor + i + 1),
+                quote @ (b'"' | b'\'') => {
+                    i += 1;
+                    while i < bytes.len() && bytes[i] != quote && bytes[i] != b'\n' {
+                        i += if bytes[i] == b'\\' { 2 } else { 1 };
+                    }
+                }
+                _ => {}
+            }
+            i += 1;
+        }
+
+        if !started {
+            return Extent::Empty;
+        }
+        if self.source.eof {
+            return Extent::Until(self.source.text.len());
+        }
+        return Extent::Open;
+    }
+}
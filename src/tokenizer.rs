@@ -1,39 +1,267 @@
 use super::nodes::*;
 use regex::Regex;
+use std::sync::{Arc, OnceLock};
 
-const SPEC: [(&str, &str); 27] = [
+// Identifiers follow the ECMAScript IdentifierName rules: an ID_Start
+// character, `$` or `_`, followed by ID_Continue characters, `$`, ZWNJ or
+// ZWJ. Any of them may also be written as a `\u` escape.
+const IDENTIFIER: &str = r"^(?:[\p{ID_Start}$_]|\\u[0-9a-fA-F]{4}|\\u\{[0-9a-fA-F]+\})(?:[\p{ID_Continue}$\x{200C}\x{200D}]|\\u[0-9a-fA-F]{4}|\\u\{[0-9a-fA-F]+\})*";
+
+// A `#name` of a private class member.
+const PRIVATE_NAME: &str = r"^#(?:[\p{ID_Start}$_]|\\u[0-9a-fA-F]{4}|\\u\{[0-9a-fA-F]+\})(?:[\p{ID_Continue}$\x{200C}\x{200D}]|\\u[0-9a-fA-F]{4}|\\u\{[0-9a-fA-F]+\})*";
+
+// JSX names may also contain dashes, as in `data-id`.
+const JSX_IDENTIFIER: &str = r"^[\p{ID_Start}$_][\p{ID_Continue}$\x{200C}\x{200D}\-]*";
+
+// Words matched by the IDENTIFIER rule that are tokenized as keywords.
+pub const KEYWORDS: [&str; 18] = [
+    "let", "const", "var", "if", "else", "true", "false", "null", "function", "return", "throw",
+    "import", "export", "default", "class", "extends", "this", "super",
+];
+
+const SPEC: [(&str, &str); 35] = [
     (r"^\s+", "WHITESPACE"),
     (r"^//.*", "COMMENT"),
-    (r"^/*[\s\S]*?*/.*", "COMMENT"),
+    (r"^/\*[\s\S]*?\*/", "COMMENT"),
     (r"^;", ";"),
     (r"^\{", "{"),
     (r"^\}", "}"),
     (r"^\(", "("),
     (r"^\)", ")"),
     (r"^,", ","),
-    (r"^\blet\b", "let"),
-    (r"^\bif\b", "if"),
-    (r"^\belse\b", "else"),
-    (r"^\btrue\b", "true"),
-    (r"^\bfalse\b", "false"),
-    (r"^\bnull\b", "null"),
-    (r"^\d+", "NUMBER"),
-    (r"^\w+", "IDENTIFIER"),
-    (r"^[=!]=", "EQUALITY_OPERATOR"),
+    (r"^\[", "["),
+    (r"^\]", "]"),
+    (
+        r"^(?:0[xXoObB][0-9A-Za-z_]*|(?:[0-9][0-9_]*(?:\.[0-9_]*)?|\.[0-9][0-9_]*)(?:[eE][+\-]?[0-9_]*)?)",
+        "NUMBER",
+    ),
+    (IDENTIFIER, "IDENTIFIER"),
+    (PRIVATE_NAME, "PRIVATE_NAME"),
+    (r"^\.\.\.", "..."),
+    (r"^\.", "."),
+    (r"^[=!]==?", "EQUALITY_OPERATOR"),
     (r"^=", "SIMPLE_ASSIGN"),
-    (r"^[\*/\+\-]=", "COMPLEX_ASSIGN"),
+    (r"^(?:\*\*|>>>|<<|>>|[*/%+\-&|^])=", "COMPLEX_ASSIGN"),
     (r"^[+\-]", "ADDITIVE_OPERATOR"),
-    (r"^[*\\/]", "MULTIPLICATIVE_OPERATOR"),
+    (r"^\*\*", "EXPONENTIATION_OPERATOR"),
+    (r"^[*/%]", "MULTIPLICATIVE_OPERATOR"),
+    (r"^(?:>>>|<<|>>)", "SHIFT_OPERATOR"),
     (r"^[><]=?", "RELATIONAL_OPERATOR"),
     (r"^&&", "LOGICAL_AND"),
     (r"^\|\|", "LOGICAL_OR"),
-    (r#"^"[^"]*""#, "STRING"),
-    (r#"^'[^']*'"#, "STRING"),
+    (r"^\?\?", "NULLISH_COALESCING"),
+    (r"^\?\.", "OPTIONAL_CHAINING"),
+    (r"^!", "LOGICAL_NOT"),
+    (r"^&", "BITWISE_AND"),
+    (r"^\|", "BITWISE_OR"),
+    (r"^\^", "BITWISE_XOR"),
+    (r"^~", "BITWISE_NOT"),
+    (r#"^"(?:[^"\\\n\r]|\\[\s\S])*""#, "STRING"),
+    (r#"^'(?:[^'\\\n\r]|\\[\s\S])*'"#, "STRING"),
 ];
-#[derive(Default, Debug)]
+
+// Punctuators of the type annotations dialect, tried after SPEC so that `?`
+// doesn't shadow `??` and `?.`.
+#[cfg(feature = "types")]
+const TYPES_SPEC: [(&str, &str); 2] = [(r"^:", ":"), (r"^\?", "?")];
+
+#[cfg(feature = "types")]
+fn spec() -> impl Iterator<Item = (&'static str, &'static str)> {
+    return SPEC.into_iter().chain(TYPES_SPEC);
+}
+
+#[cfg(not(feature = "types"))]
+fn spec() -> impl Iterator<Item = (&'static str, &'static str)> {
+    return SPEC.into_iter();
+}
+
+// A rule producing tokens of type `typ` from the text its pattern matches at
+// the cursor. Skipped rules match trivia such as whitespace and comments,
+// which separate tokens but aren't returned.
+#[derive(Debug, Clone)]
+pub struct TokenRule {
+    pub typ: &'static str,
+    pub skip: bool,
+    regexp: Regex,
+}
+
+impl TokenRule {
+    pub fn new(pattern: &str, typ: &'static str) -> Result<Self, regex::Error> {
+        let regexp = Regex::new(&format!("^(?:{})", pattern))?;
+        return Ok(Self {
+            typ,
+            skip: false,
+            regexp,
+        });
+    }
+
+    pub fn pattern(&self) -> &str {
+        let pattern = self.regexp.as_str();
+        return &pattern[4..pattern.len() - 1];
+    }
+}
+
+// The rules a Tokenizer tries in order, the first match winning, along with
+// the words of the IDENTIFIER rule that are tokenized as keywords instead.
+// Patterns are compiled when a rule is added, so a spec can be built once
+// and shared between tokenizers.
+#[derive(Debug, Clone)]
+pub struct TokenSpec {
+    pub rules: Vec<TokenRule>,
+    pub keywords: Vec<&'static str>,
+}
+
+impl Default for TokenSpec {
+    fn default() -> Self {
+        let mut rules: Vec<TokenRule> = spec()
+            .map(|(pattern, typ)| TokenRule::new(pattern, typ).unwrap())
+            .collect();
+        for rule in rules.iter_mut() {
+            rule.skip = rule.typ == "WHITESPACE" || rule.typ == "COMMENT";
+        }
+        return Self {
+            rules,
+            keywords: KEYWORDS.to_vec(),
+        };
+    }
+}
+
+impl TokenSpec {
+    // A spec without any rules or keywords.
+    pub fn new() -> Self {
+        Self {
+            rules: Vec::new(),
+            keywords: Vec::new(),
+        }
+    }
+
+    pub fn add_rule(&mut self, pattern: &str, typ: &'static str) -> Result<(), regex::Error> {
+        self.rules.push(TokenRule::new(pattern, typ)?);
+        return Ok(());
+    }
+
+    // Adds a rule tried right before the first rule of type `before`, so it
+    // can take precedence over it.
+    pub fn insert_rule(
+        &mut self,
+        before: &str,
+        pattern: &str,
+        typ: &'static str,
+    ) -> Result<(), TokenSpecError> {
+        let index = self.require(before)?;
+        let rule = TokenRule::new(pattern, typ).map_err(TokenSpecError::InvalidPattern)?;
+        self.rules.insert(index, rule);
+        return Ok(());
+    }
+
+    // Removes every rule of type `typ`.
+    pub fn remove_rule(&mut self, typ: &str) {
+        self.rules.retain(|rule| rule.typ != typ);
+    }
+
+    // Moves the rules of type `typ` right before the first rule of type
+    // `before`, keeping their relative order.
+    pub fn move_rule(&mut self, typ: &str, before: &str) -> Result<(), TokenSpecError> {
+        self.require(typ)?;
+        self.require(before)?;
+        if typ == before {
+            return Ok(());
+        }
+        let (moved, rest): (Vec<TokenRule>, Vec<TokenRule>) =
+            self.rules.drain(..).partition(|rule| rule.typ == typ);
+        self.rules = rest;
+        let index = self.require(before)?;
+        self.rules.splice(index..index, moved);
+        return Ok(());
+    }
+
+    // Marks the rules of type `typ` as trivia.
+    pub fn skip(&mut self, typ: &str) -> Result<(), TokenSpecError> {
+        self.require(typ)?;
+        for rule in self.rules.iter_mut().filter(|rule| rule.typ == typ) {
+            rule.skip = true;
+        }
+        return Ok(());
+    }
+
+    // Registers a word that the IDENTIFIER rule tokenizes as a token of its
+    // own, typed as the word itself.
+    pub fn add_keyword(&mut self, keyword: &'static str) {
+        if !self.keywords.contains(&keyword) {
+            self.keywords.push(keyword);
+        }
+    }
+
+    pub fn remove_keyword(&mut self, keyword: &str) {
+        self.keywords.retain(|k| *k != keyword);
+    }
+
+    // The index of the first rule of type `typ`.
+    pub fn position(&self, typ: &str) -> Option<usize> {
+        return self.rules.iter().position(|rule| rule.typ == typ);
+    }
+
+    fn require(&self, typ: &str) -> Result<usize, TokenSpecError> {
+        return self
+            .position(typ)
+            .ok_or_else(|| TokenSpecError::UnknownRule(typ.to_string()));
+    }
+}
+
+// An error building a TokenSpec: a pattern that isn't a valid regex, or a
+// reference to a rule type the spec doesn't have.
+#[derive(Debug, Clone)]
+pub enum TokenSpecError {
+    InvalidPattern(regex::Error),
+    UnknownRule(String),
+}
+
+impl std::fmt::Display for TokenSpecError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            TokenSpecError::InvalidPattern(error) => write!(f, "Invalid token pattern: {}", error),
+            TokenSpecError::UnknownRule(typ) => write!(f, "No token rule of type {}", typ),
+        }
+    }
+}
+
+impl std::error::Error for TokenSpecError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            TokenSpecError::InvalidPattern(error) => Some(error),
+            TokenSpecError::UnknownRule(_) => None,
+        }
+    }
+}
+
+// The default spec, compiled once and shared by every thread.
+static DEFAULT_SPEC: OnceLock<Arc<TokenSpec>> = OnceLock::new();
+
+thread_local! {
+    // Unicode classes are slow to compile, so these are compiled once per
+    // thread.
+    static IDENTIFIER_REGEXP: Regex = Regex::new(IDENTIFIER).unwrap();
+    static JSX_IDENTIFIER_REGEXP: Regex = Regex::new(JSX_IDENTIFIER).unwrap();
+}
+
+#[derive(Debug, Clone)]
 pub struct Tokenizer<'a> {
     pub string: &'a str,
     pub cursor: u64,
+    // Line and column at which `string` starts, for text that is part of a
+    // larger source.
+    pub origin: (usize, usize),
+    // Set when skipped whitespace or comments contain a line terminator.
+    line_break: bool,
+    spec: Arc<TokenSpec>,
+}
+
+impl<'a> Default for Tokenizer<'a> {
+    fn default() -> Self {
+        Self::with_spec(Arc::clone(
+            DEFAULT_SPEC.get_or_init(|| Arc::new(TokenSpec::default())),
+        ))
+    }
 }
 
 impl<'a> Tokenizer<'a> {
@@ -41,24 +269,176 @@ impl<'a> Tokenizer<'a> {
         Self::default()
     }
 
+    pub fn with_spec(spec: Arc<TokenSpec>) -> Self {
+        Self {
+            string: "",
+            cursor: 0,
+            origin: (1, 1),
+            line_break: false,
+            spec,
+        }
+    }
+
+    pub fn spec(&self) -> &TokenSpec {
+        return &self.spec;
+    }
+
     pub fn init(&mut self, string: &'a str) {
         self.string = string;
+        self.cursor = 0;
+        self.line_break = false;
     }
 
-    fn has_more_tokens(&self) -> bool {
-        return self.cursor < self.string.len().try_into().unwrap();
+    // Skips a `#!` line at the very start of the source, returning its text
+    // without the `#!`.
+    pub fn hashbang(&mut self) -> Option<&'a str> {
+        if self.cursor != 0 || !self.string.starts_with("#!") {
+            return None;
+        }
+        let end = self
+            .string
+            .find(is_line_terminator)
+            .unwrap_or(self.string.len());
+        self.cursor = end as u64;
+        return Some(&self.string[2..end]);
+    }
+
+    // Translates a byte offset into the source to a 1-based line and column.
+    pub fn location(&self, offset: usize) -> (usize, usize) {
+        let before = &self.string[..offset];
+        let lines = before.matches('\n').count();
+        let column = before.chars().rev().take_while(|c| *c != '\n').count();
+        let (line, first_column) = self.origin;
+        if lines == 0 {
+            return (line, first_column + column);
+        }
+        return (line + lines, column + 1);
+    }
+
+    // Re-reads the source from `start` as a regular expression literal. The
+    // tokenizer can't tell a regex from a division on its own, so the parser
+    // calls this when it finds a `/` or `/=` where an expression may begin.
+    pub fn read_regexp(&mut self, start: usize) -> Token<'a> {
+        return match self.try_read_regexp(start) {
+            Ok(token) => token,
+            Err(message) => panic!("{}", message),
+        };
+    }
+
+    pub(crate) fn try_read_regexp(&mut self, start: usize) -> Result<Token<'a>, String> {
+        let source = &self.string[start..];
+        let mut in_class = false;
+        let mut escaped = false;
+        let mut body_end = None;
+
+        for (i, c) in source.char_indices().skip(1) {
+            if is_line_terminator(c) {
+                break;
+            }
+            if escaped {
+                escaped = false;
+                continue;
+            }
+            match c {
+                '\\' => escaped = true,
+                '[' => in_class = true,
+                ']' => in_class = false,
+                '/' if !in_class => {
+                    body_end = Some(i);
+                    break;
+                }
+                _ => {}
+            }
+        }
+
+        let body_end = match body_end {
+            Some(i) => i,
+            None => {
+                let (line, column) = self.location(start);
+                return Err(format!(
+                    "Unterminated regular expression literal ({}:{})",
+                    line, column
+                ));
+            }
+        };
+        let flags = &source[body_end + 1..];
+        let flags_len = flags
+            .find(|c: char| !(c.is_alphanumeric() || c == '_' || c == '$'))
+            .unwrap_or(flags.len());
+        let end = start + body_end + 1 + flags_len;
+        self.cursor = end as u64;
+
+        return Ok(Token {
+            typ: "REGEXP",
+            value: &self.string[start..end],
+            start,
+            end,
+            newline_before: false,
+        });
+    }
+
+    // Reads the text of JSX children from the cursor up to the next `{` or
+    // `<`, returning `None` when there is no text before them.
+    pub fn read_jsx_text(&mut self) -> Option<Token<'a>> {
+        let start = self.cursor as usize;
+        let end = match self.string[start..].find(['{', '<']) {
+            Some(i) => start + i,
+            None => self.string.len(),
+        };
+        if end == start {
+            return None;
+        }
+        self.cursor = end as u64;
+
+        return Some(Token {
+            typ: "JSX_TEXT",
+            value: &self.string[start..end],
+            start,
+            end,
+            newline_before: false,
+        });
+    }
+
+    // Re-reads the source from `start` as a JSX name, which unlike an
+    // identifier may contain dashes.
+    pub fn read_jsx_identifier(&mut self, start: usize) -> Token<'a> {
+        return match self.try_read_jsx_identifier(start) {
+            Ok(token) => token,
+            Err(message) => panic!("{}", message),
+        };
     }
 
-    fn match_regexp(&mut self, regexp: &str, string: &str) -> Option<String> {
-        let re = Regex::new(regexp).unwrap();
-        return match re.captures(&string) {
-            Some(v) => {
-                let matched = v.get(0).map_or("", |m| m.as_str()).to_string();
-                self.cursor += matched.len() as u64;
-                return Some(matched);
+    pub(crate) fn try_read_jsx_identifier(&mut self, start: usize) -> Result<Token<'a>, String> {
+        let rest = &self.string[start..];
+        let name = match JSX_IDENTIFIER_REGEXP.with(|regexp| regexp.find(rest)) {
+            Some(m) => m.as_str(),
+            None => {
+                let (line, column) = self.location(start);
+                return Err(format!("Expected JSX identifier ({}:{})", line, column));
             }
-            None => None,
         };
+        let end = start + name.len();
+        self.cursor = end as u64;
+
+        return Ok(Token {
+            typ: "JSX_IDENTIFIER",
+            value: name,
+            start,
+            end,
+            newline_before: false,
+        });
+    }
+
+    // A numeric literal can't be immediately followed by a digit or by
+    // something that starts an identifier, as in `3in` or `2abc`.
+    fn is_identifier_start(&self) -> bool {
+        let rest = &self.string[self.cursor as usize..];
+        return rest.starts_with(|c: char| c.is_ascii_digit() || c == '\\')
+            || IDENTIFIER_REGEXP.with(|regexp| regexp.is_match(rest));
+    }
+
+    fn has_more_tokens(&self) -> bool {
+        return self.cursor < self.string.len().try_into().unwrap();
     }
 }
 
@@ -66,25 +446,68 @@ impl<'a> Iterator for Tokenizer<'a> {
     type Item = Token<'a>;
 
     fn next(&mut self) -> Option<Self::Item> {
-        if !self.has_more_tokens() {
-            return None;
-        }
-        let idx = self.cursor as usize;
-        let string = self.string[idx..].to_string();
-
-        for (regexp, typ) in SPEC {
-            let token_value = self.match_regexp(regexp, &string);
-            match token_value {
-                Some(v) => {
-                    if typ == "WHITESPACE" || typ == "COMMENT" {
-                        return self.next();
-                    }
-                    return Some(Token { typ: typ, value: v });
+        return match self.try_next() {
+            Ok(token) => token,
+            Err(message) => panic!("{}", message),
+        };
+    }
+}
+
+impl<'a> Tokenizer<'a> {
+    // Reads the next token like `next`, but returns the error instead of
+    // panicking when the text at the cursor isn't a token.
+    pub(crate) fn try_next(&mut self) -> Result<Option<Token<'a>>, String> {
+        let spec = Arc::clone(&self.spec);
+
+        'token: while self.has_more_tokens() {
+            let idx = self.cursor as usize;
+            let rest = &self.string[idx..];
+
+            for rule in spec.rules.iter() {
+                let value = match rule.regexp.find(rest) {
+                    Some(m) if !m.is_empty() => m.as_str(),
+                    _ => continue,
+                };
+                self.cursor += value.len() as u64;
+                if rule.skip {
+                    self.line_break |= value.contains(is_line_terminator);
+                    continue 'token;
                 }
-                None => continue,
-            };
+                if rule.typ == "NUMBER" && self.is_identifier_start() {
+                    let (line, column) = self.location(self.cursor as usize);
+                    return Err(format!(
+                        "Identifier directly after number ({}:{})",
+                        line, column
+                    ));
+                }
+                let typ = match spec
+                    .keywords
+                    .iter()
+                    .find(|k| rule.typ == "IDENTIFIER" && **k == value)
+                {
+                    Some(keyword) => keyword,
+                    None => rule.typ,
+                };
+                return Ok(Some(Token {
+                    typ,
+                    value,
+                    start: idx,
+                    end: self.cursor as usize,
+                    newline_before: std::mem::take(&mut self.line_break),
+                }));
+            }
+
+            let (line, column) = self.location(idx);
+            return Err(match rest.chars().next().unwrap() {
+                '"' | '\'' => format!("Unterminated string literal ({}:{})", line, column),
+                c => format!("Unexpected token: {} ({}:{})", c, line, column),
+            });
         }
 
-        panic!("Unexpected token: {}", string.chars().next().unwrap());
+        return Ok(None);
     }
 }
+
+pub(crate) fn is_line_terminator(c: char) -> bool {
+    return matches!(c, '\n' | '\r' | '\u{2028}' | '\u{2029}');
+}
@@ -1,6 +1,7 @@
 #[cfg(test)]
 mod test {
     use letter_rdp_rust::*;
+    use std::borrow::Cow;
     #[test]
     fn handles_logical_and() {
         let mut parser = init();
@@ -11,40 +12,47 @@ mod test {
             result,
             Program {
                 typ: "Program",
+                hashbang: None,
                 body: vec![Statement::ExpressionStatement(ExpressionStatement {
                     typ: "ExpressionStatement",
                     expression: Expression::LogicalExpression(LogicalExpression {
                         typ: "LogicalExpression",
-                        operator: String::from("&&"),
+                        operator: Cow::from("&&"),
                         left: Box::new(Expression::BinaryExpression(BinaryExpression {
                             typ: "BinaryExpression",
-                            operator: String::from(">"),
+                            operator: Cow::from(">"),
                             left: Box::new(Expression::LeftHandSideExpression(
                                 LeftHandSideExpression::Identifier(Identifier {
                                     typ: "Identifier",
-                                    name: String::from("x")
+                                    name: Cow::from("x"),
+                                    type_annotation: None,
+                                    optional: false
                                 })
                             )),
                             right: Box::new(Expression::Literal(Literal::NumericLiteral(
                                 NumericLiteral {
                                     typ: "NumericLiteral",
-                                    value: 0
+                                    value: 0.0,
+                                    raw: Cow::from("0")
                                 }
                             )))
                         })),
                         right: Box::new(Expression::BinaryExpression(BinaryExpression {
                             typ: "BinaryExpression",
-                            operator: String::from("<"),
+                            operator: Cow::from("<"),
                             left: Box::new(Expression::LeftHandSideExpression(
                                 LeftHandSideExpression::Identifier(Identifier {
                                     typ: "Identifier",
-                                    name: String::from("y")
+                                    name: Cow::from("y"),
+                                    type_annotation: None,
+                                    optional: false
                                 })
                             )),
                             right: Box::new(Expression::Literal(Literal::NumericLiteral(
                                 NumericLiteral {
                                     typ: "NumericLiteral",
-                                    value: 1
+                                    value: 1.0,
+                                    raw: Cow::from("1")
                                 }
                             )))
                         }))
@@ -64,40 +72,47 @@ mod test {
             result,
             Program {
                 typ: "Program",
+                hashbang: None,
                 body: vec![Statement::ExpressionStatement(ExpressionStatement {
                     typ: "ExpressionStatement",
                     expression: Expression::LogicalExpression(LogicalExpression {
                         typ: "LogicalExpression",
-                        operator: String::from("||"),
+                        operator: Cow::from("||"),
                         left: Box::new(Expression::BinaryExpression(BinaryExpression {
                             typ: "BinaryExpression",
-                            operator: String::from(">"),
+                            operator: Cow::from(">"),
                             left: Box::new(Expression::LeftHandSideExpression(
                                 LeftHandSideExpression::Identifier(Identifier {
                                     typ: "Identifier",
-                                    name: String::from("x")
+                                    name: Cow::from("x"),
+                                    type_annotation: None,
+                                    optional: false
                                 })
                             )),
                             right: Box::new(Expression::Literal(Literal::NumericLiteral(
                                 NumericLiteral {
                                     typ: "NumericLiteral",
-                                    value: 0
+                                    value: 0.0,
+                                    raw: Cow::from("0")
                                 }
                             )))
                         })),
                         right: Box::new(Expression::BinaryExpression(BinaryExpression {
                             typ: "BinaryExpression",
-                            operator: String::from("<"),
+                            operator: Cow::from("<"),
                             left: Box::new(Expression::LeftHandSideExpression(
                                 LeftHandSideExpression::Identifier(Identifier {
                                     typ: "Identifier",
-                                    name: String::from("y")
+                                    name: Cow::from("y"),
+                                    type_annotation: None,
+                                    optional: false
                                 })
                             )),
                             right: Box::new(Expression::Literal(Literal::NumericLiteral(
                                 NumericLiteral {
                                     typ: "NumericLiteral",
-                                    value: 1
+                                    value: 1.0,
+                                    raw: Cow::from("1")
                                 }
                             )))
                         }))
@@ -106,4 +121,128 @@ mod test {
             }
         )
     }
+
+    #[test]
+    fn handles_nullish_coalescing() {
+        let mut parser = init();
+
+        let result = parser.parse("a ?? b ?? c;");
+
+        assert_eq!(
+            result,
+            Program {
+                typ: "Program",
+                hashbang: None,
+                body: vec![Statement::ExpressionStatement(ExpressionStatement {
+                    typ: "ExpressionStatement",
+                    expression: Expression::LogicalExpression(LogicalExpression {
+                        typ: "LogicalExpression",
+                        operator: Cow::from("??"),
+                        left: Box::new(Expression::LogicalExpression(LogicalExpression {
+                            typ: "LogicalExpression",
+                            operator: Cow::from("??"),
+                            left: Box::new(Expression::LeftHandSideExpression(
+                                LeftHandSideExpression::Identifier(Identifier {
+                                    typ: "Identifier",
+                                    name: Cow::from("a"),
+                                    type_annotation: None,
+                                    optional: false
+                                })
+                            )),
+                            right: Box::new(Expression::LeftHandSideExpression(
+                                LeftHandSideExpression::Identifier(Identifier {
+                                    typ: "Identifier",
+                                    name: Cow::from("b"),
+                                    type_annotation: None,
+                                    optional: false
+                                })
+                            ))
+                        })),
+                        right: Box::new(Expression::LeftHandSideExpression(
+                            LeftHandSideExpression::Identifier(Identifier {
+                                typ: "Identifier",
+                                name: Cow::from("c"),
+                                type_annotation: None,
+                                optional: false
+                            })
+                        ))
+                    })
+                })]
+            }
+        )
+    }
+
+    #[test]
+    fn handles_parenthesized_logical_in_nullish_coalescing() {
+        let mut parser = init();
+
+        let result = parser.parse("(a || b) ?? c;");
+
+        assert_eq!(
+            result,
+            Program {
+                typ: "Program",
+                hashbang: None,
+                body: vec![Statement::ExpressionStatement(ExpressionStatement {
+                    typ: "ExpressionStatement",
+                    expression: Expression::LogicalExpression(LogicalExpression {
+                        typ: "LogicalExpression",
+                        operator: Cow::from("??"),
+                        left: Box::new(Expression::LogicalExpression(LogicalExpression {
+                            typ: "LogicalExpression",
+                            operator: Cow::from("||"),
+                            left: Box::new(Expression::LeftHandSideExpression(
+                                LeftHandSideExpression::Identifier(Identifier {
+                                    typ: "Identifier",
+                                    name: Cow::from("a"),
+                                    type_annotation: None,
+                                    optional: false
+                                })
+                            )),
+                            right: Box::new(Expression::LeftHandSideExpression(
+                                LeftHandSideExpression::Identifier(Identifier {
+                                    typ: "Identifier",
+                                    name: Cow::from("b"),
+                                    type_annotation: None,
+                                    optional: false
+                                })
+                            ))
+                        })),
+                        right: Box::new(Expression::LeftHandSideExpression(
+                            LeftHandSideExpression::Identifier(Identifier {
+                                typ: "Identifier",
+                                name: Cow::from("c"),
+                                type_annotation: None,
+                                optional: false
+                            })
+                        ))
+                    })
+                })]
+            }
+        )
+    }
+
+    #[test]
+    #[should_panic(expected = "Cannot mix ?? with && or || without parentheses")]
+    fn rejects_logical_or_mixed_with_nullish_coalescing() {
+        let mut parser = init();
+
+        parser.parse("a || b ?? c;");
+    }
+
+    #[test]
+    #[should_panic(expected = "Cannot mix ?? with && or || without parentheses")]
+    fn rejects_nullish_coalescing_mixed_with_logical_and() {
+        let mut parser = init();
+
+        parser.parse("a ?? b && c;");
+    }
+
+    #[test]
+    #[should_panic(expected = "Cannot mix ?? with && or || without parentheses")]
+    fn rejects_logical_and_mixed_with_nullish_coalescing() {
+        let mut parser = init();
+
+        parser.parse("a && b ?? c;");
+    }
 }
@@ -0,0 +1,152 @@
+#[cfg(test)]
+mod test {
+    use letter_rdp_rust::arena::*;
+    use letter_rdp_rust::*;
+
+    fn round_trip(string: &str, options: ParserOptions) {
+        let mut parser = init_with(options);
+        let ast = Ast::from(parser.parse(string));
+
+        assert_eq!(ast.to_program(), parser.parse(string))
+    }
+
+    #[test]
+    fn converts_statements_back_to_a_program() {
+        round_trip(
+            "#!/usr/bin/env node\n'use strict'; let a = 1, b; const c = a ** 2; \
+             if (a) { b = a; } else if (b) return; else ; throw a;",
+            ParserOptions {
+                allow_return_outside_function: true,
+                ..ParserOptions::default()
+            },
+        )
+    }
+
+    #[test]
+    fn converts_expressions_back_to_a_program() {
+        round_trip(
+            "a + b * c, (a && b) ?? c; -a; x = y = 'str'; a?.b[c](d, e); /re/g; \
+             true, false, null, this, 1.5;",
+            ParserOptions::default(),
+        )
+    }
+
+    #[test]
+    fn converts_functions_and_classes_back_to_a_program() {
+        round_trip(
+            "async function f(a, b) { await a; } function* g() { yield* a; } \
+             let h = function () {}; class A extends B { static #x = 1; \
+             get y() { return super.y; } static { f(); } } let C = class {};",
+            ParserOptions::default(),
+        )
+    }
+
+    #[test]
+    fn converts_modules_back_to_a_program() {
+        round_trip(
+            "import a, { b as c } from 'd'; import * as e from 'f'; export { a as g }; \
+             export const h = 1; export default a; export * as i from 'j'; import('k');",
+            ParserOptions {
+                source_type: SourceType::Module,
+                ..ParserOptions::default()
+            },
+        );
+        round_trip(
+            "export default function f() {}",
+            ParserOptions {
+                source_type: SourceType::Module,
+                ..ParserOptions::default()
+            },
+        )
+    }
+
+    #[test]
+    fn converts_jsx_back_to_a_program() {
+        round_trip(
+            "<A.B c=\"d\" e={f} {...g} h=<i /> j><>text {k}</><l />{}</A.B>;",
+            ParserOptions {
+                jsx: true,
+                ..ParserOptions::default()
+            },
+        )
+    }
+
+    #[test]
+    fn stores_children_before_their_parents() {
+        let mut parser = init();
+        let mut ast = Ast::default();
+
+        let root = parser.parse_into("a + 1;", &mut ast);
+
+        assert_eq!(root, ast.root());
+        assert_eq!(ast.len(), 5);
+
+        let body = match ast.node(root) {
+            Node::Program { body, .. } => *body,
+            node => panic!("Unexpected node: {:?}", node),
+        };
+        let expression = match ast.node(ast.list(body)[0]) {
+            Node::ExpressionStatement { expression } => *expression,
+            node => panic!("Unexpected node: {:?}", node),
+        };
+        match ast.node(expression) {
+            Node::BinaryExpression {
+                operator,
+                left,
+                right,
+            } => {
+                assert_eq!(operator, "+");
+                assert!(left.index() < expression.index());
+                assert!(right.index() < expression.index());
+                assert!(matches!(
+                    ast.node(*right),
+                    Node::NumericLiteral { value, .. } if *value == 1.0
+                ));
+            }
+            node => panic!("Unexpected node: {:?}", node),
+        }
+    }
+
+    #[test]
+    fn keeps_nested_lists_together() {
+        let mut parser = init();
+        let mut ast = Ast::default();
+
+        let root = parser.parse_into("f(a, g(b, c), d);", &mut ast);
+
+        let body = match ast.node(root) {
+            Node::Program { body, .. } => *body,
+            node => panic!("Unexpected node: {:?}", node),
+        };
+        let call = match ast.node(ast.list(body)[0]) {
+            Node::ExpressionStatement { expression } => *expression,
+            node => panic!("Unexpected node: {:?}", node),
+        };
+        let names: Vec<&Node> = match ast.node(call) {
+            Node::CallExpression { arguments, .. } => ast
+                .list(*arguments)
+                .iter()
+                .map(|id| ast.node(*id))
+                .collect(),
+            node => panic!("Unexpected node: {:?}", node),
+        };
+
+        assert_eq!(names.len(), 3);
+        assert!(matches!(names[0], Node::Identifier { name, .. } if &*ast.resolve(*name) == "a"));
+        assert!(matches!(names[1], Node::CallExpression { .. }));
+        assert!(matches!(names[2], Node::Identifier { name, .. } if &*ast.resolve(*name) == "d"))
+    }
+
+    #[test]
+    fn reuses_an_ast_between_parses() {
+        let mut parser = init();
+        let mut ast = Ast::default();
+
+        parser.parse_into("a; b; c;", &mut ast);
+        let root = parser.parse_into("d;", &mut ast);
+
+        assert_eq!(ast.len(), 3);
+        assert_eq!(ast.to_program(), parser.parse("d;"));
+        assert_eq!(root.index(), 2)
+    }
+}
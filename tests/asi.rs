@@ -0,0 +1,422 @@
+#[cfg(test)]
+mod test {
+    use letter_rdp_rust::*;
+    use std::borrow::Cow;
+    #[test]
+    fn inserts_semicolon_before_newline() {
+        let mut parser = init();
+
+        let result = parser.parse(
+            "let x = 1
+let y = 2",
+        );
+
+        assert_eq!(
+            result,
+            Program {
+                typ: "Program",
+                hashbang: None,
+                body: vec![
+                    Statement::VariableStatement(VariableStatement {
+                        typ: "VariableStatement",
+                        kind: "let",
+                        declarations: vec![VariableDeclaration {
+                            typ: "VariableDeclaration",
+                            id: Identifier {
+                                typ: "Identifier",
+                                name: Cow::from("x"),
+                                type_annotation: None,
+                                optional: false
+                            },
+                            init: Some(Expression::Literal(Literal::NumericLiteral(
+                                NumericLiteral {
+                                    typ: "NumericLiteral",
+                                    value: 1.0,
+                                    raw: Cow::from("1")
+                                }
+                            )))
+                        }]
+                    }),
+                    Statement::VariableStatement(VariableStatement {
+                        typ: "VariableStatement",
+                        kind: "let",
+                        declarations: vec![VariableDeclaration {
+                            typ: "VariableDeclaration",
+                            id: Identifier {
+                                typ: "Identifier",
+                                name: Cow::from("y"),
+                                type_annotation: None,
+                                optional: false
+                            },
+                            init: Some(Expression::Literal(Literal::NumericLiteral(
+                                NumericLiteral {
+                                    typ: "NumericLiteral",
+                                    value: 2.0,
+                                    raw: Cow::from("2")
+                                }
+                            )))
+                        }]
+                    })
+                ]
+            }
+        )
+    }
+
+    #[test]
+    fn inserts_semicolon_between_expressions() {
+        let mut parser = init();
+
+        let result = parser.parse(
+            "a = 1
+b = 2",
+        );
+
+        assert_eq!(
+            result,
+            Program {
+                typ: "Program",
+                hashbang: None,
+                body: vec![
+                    Statement::ExpressionStatement(ExpressionStatement {
+                        typ: "ExpressionStatement",
+                        expression: Expression::AssignmentExpression(AssignmentExpression {
+                            typ: "AssignmentExpression",
+                            operator: Cow::from("="),
+                            left: Box::new(Expression::LeftHandSideExpression(
+                                LeftHandSideExpression::Identifier(Identifier {
+                                    typ: "Identifier",
+                                    name: Cow::from("a"),
+                                    type_annotation: None,
+                                    optional: false
+                                })
+                            )),
+                            right: Box::new(Expression::Literal(Literal::NumericLiteral(
+                                NumericLiteral {
+                                    typ: "NumericLiteral",
+                                    value: 1.0,
+                                    raw: Cow::from("1")
+                                }
+                            )))
+                        })
+                    }),
+                    Statement::ExpressionStatement(ExpressionStatement {
+                        typ: "ExpressionStatement",
+                        expression: Expression::AssignmentExpression(AssignmentExpression {
+                            typ: "AssignmentExpression",
+                            operator: Cow::from("="),
+                            left: Box::new(Expression::LeftHandSideExpression(
+                                LeftHandSideExpression::Identifier(Identifier {
+                                    typ: "Identifier",
+                                    name: Cow::from("b"),
+                                    type_annotation: None,
+                                    optional: false
+                                })
+                            )),
+                            right: Box::new(Expression::Literal(Literal::NumericLiteral(
+                                NumericLiteral {
+                                    typ: "NumericLiteral",
+                                    value: 2.0,
+                                    raw: Cow::from("2")
+                                }
+                            )))
+                        })
+                    })
+                ]
+            }
+        )
+    }
+
+    #[test]
+    fn inserts_semicolon_before_closing_brace() {
+        let mut parser = init();
+
+        let result = parser.parse("{ a } b");
+
+        assert_eq!(
+            result,
+            Program {
+                typ: "Program",
+                hashbang: None,
+                body: vec![
+                    Statement::BlockStatement(BlockStatement {
+                        typ: "BlockStatement",
+                        body: vec![Statement::ExpressionStatement(ExpressionStatement {
+                            typ: "ExpressionStatement",
+                            expression: Expression::LeftHandSideExpression(
+                                LeftHandSideExpression::Identifier(Identifier {
+                                    typ: "Identifier",
+                                    name: Cow::from("a"),
+                                    type_annotation: None,
+                                    optional: false
+                                })
+                            )
+                        })]
+                    }),
+                    Statement::ExpressionStatement(ExpressionStatement {
+                        typ: "ExpressionStatement",
+                        expression: Expression::LeftHandSideExpression(
+                            LeftHandSideExpression::Identifier(Identifier {
+                                typ: "Identifier",
+                                name: Cow::from("b"),
+                                type_annotation: None,
+                                optional: false
+                            })
+                        )
+                    })
+                ]
+            }
+        )
+    }
+
+    #[test]
+    fn does_not_insert_semicolon_before_parenthesis() {
+        let mut parser = init();
+
+        let result = parser.parse(
+            "a
+(b)",
+        );
+
+        assert_eq!(
+            result,
+            Program {
+                typ: "Program",
+                hashbang: None,
+                body: vec![Statement::ExpressionStatement(ExpressionStatement {
+                    typ: "ExpressionStatement",
+                    expression: Expression::LeftHandSideExpression(
+                        LeftHandSideExpression::CallExpression(CallExpression {
+                            typ: "CallExpression",
+                            callee: Box::new(Expression::LeftHandSideExpression(
+                                LeftHandSideExpression::Identifier(Identifier {
+                                    typ: "Identifier",
+                                    name: Cow::from("a"),
+                                    type_annotation: None,
+                                    optional: false
+                                })
+                            )),
+                            arguments: vec![Expression::LeftHandSideExpression(
+                                LeftHandSideExpression::Identifier(Identifier {
+                                    typ: "Identifier",
+                                    name: Cow::from("b"),
+                                    type_annotation: None,
+                                    optional: false
+                                })
+                            )],
+                            optional: false
+                        })
+                    )
+                })]
+            }
+        )
+    }
+
+    #[test]
+    fn handles_newline_after_return() {
+        let mut parser = init();
+
+        let result = parser.parse(
+            "function f() {
+    return
+    a + b
+}",
+        );
+
+        assert_eq!(
+            result,
+            Program {
+                typ: "Program",
+                hashbang: None,
+                body: vec![Statement::FunctionDeclaration(FunctionDeclaration {
+                    typ: "FunctionDeclaration",
+                    id: Identifier {
+                        typ: "Identifier",
+                        name: Cow::from("f"),
+                        type_annotation: None,
+                        optional: false
+                    },
+                    params: vec![],
+                    body: BlockStatement {
+                        typ: "BlockStatement",
+                        body: vec![
+                            Statement::ReturnStatement(ReturnStatement {
+                                typ: "ReturnStatement",
+                                argument: None
+                            }),
+                            Statement::ExpressionStatement(ExpressionStatement {
+                                typ: "ExpressionStatement",
+                                expression: Expression::BinaryExpression(BinaryExpression {
+                                    typ: "BinaryExpression",
+                                    operator: Cow::from("+"),
+                                    left: Box::new(Expression::LeftHandSideExpression(
+                                        LeftHandSideExpression::Identifier(Identifier {
+                                            typ: "Identifier",
+                                            name: Cow::from("a"),
+                                            type_annotation: None,
+                                            optional: false
+                                        })
+                                    )),
+                                    right: Box::new(Expression::LeftHandSideExpression(
+                                        LeftHandSideExpression::Identifier(Identifier {
+                                            typ: "Identifier",
+                                            name: Cow::from("b"),
+                                            type_annotation: None,
+                                            optional: false
+                                        })
+                                    ))
+                                })
+                            })
+                        ]
+                    },
+                    is_async: false,
+                    is_generator: false,
+                    return_type: None
+                })]
+            }
+        )
+    }
+
+    #[test]
+    fn handles_newline_after_yield() {
+        let mut parser = init();
+
+        let result = parser.parse(
+            "function* g() {
+    yield
+    x
+}",
+        );
+
+        assert_eq!(
+            result,
+            Program {
+                typ: "Program",
+                hashbang: None,
+                body: vec![Statement::FunctionDeclaration(FunctionDeclaration {
+                    typ: "FunctionDeclaration",
+                    id: Identifier {
+                        typ: "Identifier",
+                        name: Cow::from("g"),
+                        type_annotation: None,
+                        optional: false
+                    },
+                    params: vec![],
+                    body: BlockStatement {
+                        typ: "BlockStatement",
+                        body: vec![
+                            Statement::ExpressionStatement(ExpressionStatement {
+                                typ: "ExpressionStatement",
+                                expression: Expression::YieldExpression(YieldExpression {
+                                    typ: "YieldExpression",
+                                    argument: None,
+                                    delegate: false
+                                })
+                            }),
+                            Statement::ExpressionStatement(ExpressionStatement {
+                                typ: "ExpressionStatement",
+                                expression: Expression::LeftHandSideExpression(
+                                    LeftHandSideExpression::Identifier(Identifier {
+                                        typ: "Identifier",
+                                        name: Cow::from("x"),
+                                        type_annotation: None,
+                                        optional: false
+                                    })
+                                )
+                            })
+                        ]
+                    },
+                    is_async: false,
+                    is_generator: true,
+                    return_type: None
+                })]
+            }
+        )
+    }
+
+    #[test]
+    fn handles_throw_statement() {
+        let mut parser = init();
+
+        let result = parser.parse("throw x");
+
+        assert_eq!(
+            result,
+            Program {
+                typ: "Program",
+                hashbang: None,
+                body: vec![Statement::ThrowStatement(ThrowStatement {
+                    typ: "ThrowStatement",
+                    argument: Expression::LeftHandSideExpression(
+                        LeftHandSideExpression::Identifier(Identifier {
+                            typ: "Identifier",
+                            name: Cow::from("x"),
+                            type_annotation: None,
+                            optional: false
+                        })
+                    )
+                })]
+            }
+        )
+    }
+
+    #[test]
+    fn treats_comment_line_break_as_newline() {
+        let mut parser = init();
+
+        let result = parser.parse(
+            "a /*
+*/ b",
+        );
+
+        assert_eq!(
+            result,
+            Program {
+                typ: "Program",
+                hashbang: None,
+                body: vec![
+                    Statement::ExpressionStatement(ExpressionStatement {
+                        typ: "ExpressionStatement",
+                        expression: Expression::LeftHandSideExpression(
+                            LeftHandSideExpression::Identifier(Identifier {
+                                typ: "Identifier",
+                                name: Cow::from("a"),
+                                type_annotation: None,
+                                optional: false
+                            })
+                        )
+                    }),
+                    Statement::ExpressionStatement(ExpressionStatement {
+                        typ: "ExpressionStatement",
+                        expression: Expression::LeftHandSideExpression(
+                            LeftHandSideExpression::Identifier(Identifier {
+                                typ: "Identifier",
+                                name: Cow::from("b"),
+                                type_annotation: None,
+                                optional: false
+                            })
+                        )
+                    })
+                ]
+            }
+        )
+    }
+
+    #[test]
+    #[should_panic(expected = "Illegal newline after throw")]
+    fn rejects_newline_after_throw() {
+        let mut parser = init();
+
+        parser.parse(
+            "throw
+x;",
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Unexpected token: IDENTIFIER, expected: ;")]
+    fn rejects_missing_semicolon_on_same_line() {
+        let mut parser = init();
+
+        parser.parse("a b;");
+    }
+}
@@ -1,6 +1,7 @@
 #[cfg(test)]
 mod test {
     use letter_rdp_rust::*;
+    use std::borrow::Cow;
     #[test]
     fn handles_assignment_expression() {
         let mut parser = init();
@@ -11,19 +12,25 @@ mod test {
             result,
             Program {
                 typ: "Program",
+                hashbang: None,
                 body: vec![Statement::ExpressionStatement(ExpressionStatement {
                     typ: "ExpressionStatement",
                     expression: Expression::AssignmentExpression(AssignmentExpression {
                         typ: "AssignmentExpression",
-                        operator: String::from("="),
-                        left: Identifier {
-                            typ: "Identifier",
-                            name: String::from("x")
-                        },
+                        operator: Cow::from("="),
+                        left: Box::new(Expression::LeftHandSideExpression(
+                            LeftHandSideExpression::Identifier(Identifier {
+                                typ: "Identifier",
+                                name: Cow::from("x"),
+                                type_annotation: None,
+                                optional: false
+                            })
+                        )),
                         right: Box::new(Expression::Literal(Literal::NumericLiteral(
                             NumericLiteral {
                                 typ: "NumericLiteral",
-                                value: 42
+                                value: 42.0,
+                                raw: Cow::from("42")
                             }
                         )))
                     })
@@ -42,26 +49,36 @@ mod test {
             result,
             Program {
                 typ: "Program",
+                hashbang: None,
                 body: vec![Statement::ExpressionStatement(ExpressionStatement {
                     typ: "ExpressionStatement",
                     expression: Expression::AssignmentExpression(AssignmentExpression {
                         typ: "AssignmentExpression",
-                        operator: String::from("="),
-                        left: Identifier {
-                            typ: "Identifier",
-                            name: String::from("x")
-                        },
+                        operator: Cow::from("="),
+                        left: Box::new(Expression::LeftHandSideExpression(
+                            LeftHandSideExpression::Identifier(Identifier {
+                                typ: "Identifier",
+                                name: Cow::from("x"),
+                                type_annotation: None,
+                                optional: false
+                            })
+                        )),
                         right: Box::new(Expression::AssignmentExpression(AssignmentExpression {
                             typ: "AssignmentExpression",
-                            operator: String::from("="),
-                            left: Identifier {
-                                typ: "Identifier",
-                                name: String::from("y")
-                            },
+                            operator: Cow::from("="),
+                            left: Box::new(Expression::LeftHandSideExpression(
+                                LeftHandSideExpression::Identifier(Identifier {
+                                    typ: "Identifier",
+                                    name: Cow::from("y"),
+                                    type_annotation: None,
+                                    optional: false
+                                })
+                            )),
                             right: Box::new(Expression::Literal(Literal::NumericLiteral(
                                 NumericLiteral {
                                     typ: "NumericLiteral",
-                                    value: 42
+                                    value: 42.0,
+                                    raw: Cow::from("42")
                                 }
                             )))
                         }))
@@ -70,4 +87,98 @@ mod test {
             }
         )
     }
+
+    #[test]
+    fn handles_member_assignment() {
+        let mut parser = init();
+
+        let result = parser.parse("a.b = 1;");
+
+        assert_eq!(
+            result,
+            Program {
+                typ: "Program",
+                hashbang: None,
+                body: vec![Statement::ExpressionStatement(ExpressionStatement {
+                    typ: "ExpressionStatement",
+                    expression: Expression::AssignmentExpression(AssignmentExpression {
+                        typ: "AssignmentExpression",
+                        operator: Cow::from("="),
+                        left: Box::new(Expression::LeftHandSideExpression(
+                            LeftHandSideExpression::MemberExpression(MemberExpression {
+                                typ: "MemberExpression",
+                                object: Box::new(Expression::LeftHandSideExpression(
+                                    LeftHandSideExpression::Identifier(Identifier {
+                                        typ: "Identifier",
+                                        name: Cow::from("a"),
+                                        type_annotation: None,
+                                        optional: false
+                                    })
+                                )),
+                                property: Box::new(Expression::LeftHandSideExpression(
+                                    LeftHandSideExpression::Identifier(Identifier {
+                                        typ: "Identifier",
+                                        name: Cow::from("b"),
+                                        type_annotation: None,
+                                        optional: false
+                                    })
+                                )),
+                                computed: false,
+                                optional: false
+                            })
+                        )),
+                        right: Box::new(Expression::Literal(Literal::NumericLiteral(
+                            NumericLiteral {
+                                typ: "NumericLiteral",
+                                value: 1.0,
+                                raw: Cow::from("1")
+                            }
+                        )))
+                    })
+                })]
+            }
+        )
+    }
+
+    #[test]
+    fn handles_computed_and_this_member_assignment() {
+        let mut parser = init();
+
+        let result = parser.parse("a[i] += 1; this.x = 1;");
+
+        let targets: Vec<_> = result
+            .body
+            .iter()
+            .map(|statement| match statement {
+                Statement::ExpressionStatement(ExpressionStatement {
+                    expression: Expression::AssignmentExpression(assignment),
+                    ..
+                }) => match &*assignment.left {
+                    Expression::LeftHandSideExpression(
+                        LeftHandSideExpression::MemberExpression(member),
+                    ) => member.computed,
+                    left => panic!("Unexpected assignment target: {:?}", left),
+                },
+                statement => panic!("Unexpected statement: {:?}", statement),
+            })
+            .collect();
+
+        assert_eq!(targets, vec![true, false])
+    }
+
+    #[test]
+    #[should_panic(expected = "Invalid left-hand side in assignment expression")]
+    fn rejects_optional_chain_assignment() {
+        let mut parser = init();
+
+        parser.parse("a?.b = 1;");
+    }
+
+    #[test]
+    #[should_panic(expected = "Invalid left-hand side in assignment expression")]
+    fn rejects_call_assignment() {
+        let mut parser = init();
+
+        parser.parse("f() = 1;");
+    }
 }
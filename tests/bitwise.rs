@@ -0,0 +1,224 @@
+#[cfg(test)]
+mod test {
+    use letter_rdp_rust::*;
+    use std::borrow::Cow;
+    #[test]
+    fn bitwise_operators_precedence() {
+        let mut parser = init();
+
+        let result = parser.parse("a | b ^ c & d;");
+
+        assert_eq!(
+            result,
+            Program {
+                typ: "Program",
+                hashbang: None,
+                body: vec![Statement::ExpressionStatement(ExpressionStatement {
+                    typ: "ExpressionStatement",
+                    expression: Expression::BinaryExpression(BinaryExpression {
+                        typ: "BinaryExpression",
+                        operator: Cow::from("|"),
+                        left: Box::new(Expression::LeftHandSideExpression(
+                            LeftHandSideExpression::Identifier(Identifier {
+                                typ: "Identifier",
+                                name: Cow::from("a"),
+                                type_annotation: None,
+                                optional: false
+                            })
+                        )),
+                        right: Box::new(Expression::BinaryExpression(BinaryExpression {
+                            typ: "BinaryExpression",
+                            operator: Cow::from("^"),
+                            left: Box::new(Expression::LeftHandSideExpression(
+                                LeftHandSideExpression::Identifier(Identifier {
+                                    typ: "Identifier",
+                                    name: Cow::from("b"),
+                                    type_annotation: None,
+                                    optional: false
+                                })
+                            )),
+                            right: Box::new(Expression::BinaryExpression(BinaryExpression {
+                                typ: "BinaryExpression",
+                                operator: Cow::from("&"),
+                                left: Box::new(Expression::LeftHandSideExpression(
+                                    LeftHandSideExpression::Identifier(Identifier {
+                                        typ: "Identifier",
+                                        name: Cow::from("c"),
+                                        type_annotation: None,
+                                        optional: false
+                                    })
+                                )),
+                                right: Box::new(Expression::LeftHandSideExpression(
+                                    LeftHandSideExpression::Identifier(Identifier {
+                                        typ: "Identifier",
+                                        name: Cow::from("d"),
+                                        type_annotation: None,
+                                        optional: false
+                                    })
+                                ))
+                            }))
+                        }))
+                    })
+                })]
+            }
+        )
+    }
+
+    #[test]
+    fn bitwise_and_binds_looser_than_equality() {
+        let mut parser = init();
+
+        let result = parser.parse("a & b == c;");
+
+        assert_eq!(
+            result,
+            Program {
+                typ: "Program",
+                hashbang: None,
+                body: vec![Statement::ExpressionStatement(ExpressionStatement {
+                    typ: "ExpressionStatement",
+                    expression: Expression::BinaryExpression(BinaryExpression {
+                        typ: "BinaryExpression",
+                        operator: Cow::from("&"),
+                        left: Box::new(Expression::LeftHandSideExpression(
+                            LeftHandSideExpression::Identifier(Identifier {
+                                typ: "Identifier",
+                                name: Cow::from("a"),
+                                type_annotation: None,
+                                optional: false
+                            })
+                        )),
+                        right: Box::new(Expression::BinaryExpression(BinaryExpression {
+                            typ: "BinaryExpression",
+                            operator: Cow::from("=="),
+                            left: Box::new(Expression::LeftHandSideExpression(
+                                LeftHandSideExpression::Identifier(Identifier {
+                                    typ: "Identifier",
+                                    name: Cow::from("b"),
+                                    type_annotation: None,
+                                    optional: false
+                                })
+                            )),
+                            right: Box::new(Expression::LeftHandSideExpression(
+                                LeftHandSideExpression::Identifier(Identifier {
+                                    typ: "Identifier",
+                                    name: Cow::from("c"),
+                                    type_annotation: None,
+                                    optional: false
+                                })
+                            ))
+                        }))
+                    })
+                })]
+            }
+        )
+    }
+
+    #[test]
+    fn shift_binds_looser_than_additive() {
+        let mut parser = init();
+
+        let result = parser.parse("a << 1 + 2;");
+
+        assert_eq!(
+            result,
+            Program {
+                typ: "Program",
+                hashbang: None,
+                body: vec![Statement::ExpressionStatement(ExpressionStatement {
+                    typ: "ExpressionStatement",
+                    expression: Expression::BinaryExpression(BinaryExpression {
+                        typ: "BinaryExpression",
+                        operator: Cow::from("<<"),
+                        left: Box::new(Expression::LeftHandSideExpression(
+                            LeftHandSideExpression::Identifier(Identifier {
+                                typ: "Identifier",
+                                name: Cow::from("a"),
+                                type_annotation: None,
+                                optional: false
+                            })
+                        )),
+                        right: Box::new(Expression::BinaryExpression(BinaryExpression {
+                            typ: "BinaryExpression",
+                            operator: Cow::from("+"),
+                            left: Box::new(Expression::Literal(Literal::NumericLiteral(
+                                NumericLiteral {
+                                    typ: "NumericLiteral",
+                                    value: 1.0,
+                                    raw: Cow::from("1")
+                                }
+                            ))),
+                            right: Box::new(Expression::Literal(Literal::NumericLiteral(
+                                NumericLiteral {
+                                    typ: "NumericLiteral",
+                                    value: 2.0,
+                                    raw: Cow::from("2")
+                                }
+                            )))
+                        }))
+                    })
+                })]
+            }
+        )
+    }
+
+    #[test]
+    fn shift_binds_tighter_than_relational() {
+        let mut parser = init();
+
+        let result = parser.parse("a >>> 2 > b >> 1;");
+
+        assert_eq!(
+            result,
+            Program {
+                typ: "Program",
+                hashbang: None,
+                body: vec![Statement::ExpressionStatement(ExpressionStatement {
+                    typ: "ExpressionStatement",
+                    expression: Expression::BinaryExpression(BinaryExpression {
+                        typ: "BinaryExpression",
+                        operator: Cow::from(">"),
+                        left: Box::new(Expression::BinaryExpression(BinaryExpression {
+                            typ: "BinaryExpression",
+                            operator: Cow::from(">>>"),
+                            left: Box::new(Expression::LeftHandSideExpression(
+                                LeftHandSideExpression::Identifier(Identifier {
+                                    typ: "Identifier",
+                                    name: Cow::from("a"),
+                                    type_annotation: None,
+                                    optional: false
+                                })
+                            )),
+                            right: Box::new(Expression::Literal(Literal::NumericLiteral(
+                                NumericLiteral {
+                                    typ: "NumericLiteral",
+                                    value: 2.0,
+                                    raw: Cow::from("2")
+                                }
+                            )))
+                        })),
+                        right: Box::new(Expression::BinaryExpression(BinaryExpression {
+                            typ: "BinaryExpression",
+                            operator: Cow::from(">>"),
+                            left: Box::new(Expression::LeftHandSideExpression(
+                                LeftHandSideExpression::Identifier(Identifier {
+                                    typ: "Identifier",
+                                    name: Cow::from("b"),
+                                    type_annotation: None,
+                                    optional: false
+                                })
+                            )),
+                            right: Box::new(Expression::Literal(Literal::NumericLiteral(
+                                NumericLiteral {
+                                    typ: "NumericLiteral",
+                                    value: 1.0,
+                                    raw: Cow::from("1")
+                                }
+                            )))
+                        }))
+                    })
+                })]
+            }
+        )
+    }
+}
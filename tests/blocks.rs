@@ -1,6 +1,7 @@
 #[cfg(test)]
 mod test {
     use letter_rdp_rust::*;
+    use std::borrow::Cow;
     #[test]
     fn handles_block() {
         let mut parser = init();
@@ -17,6 +18,7 @@ mod test {
             result,
             Program {
                 typ: "Program",
+                hashbang: None,
                 body: vec![Statement::BlockStatement(BlockStatement {
                     typ: "BlockStatement",
                     body: vec![
@@ -25,7 +27,8 @@ mod test {
                             expression: Expression::Literal(Literal::StringLiteral(
                                 StringLiteral {
                                     typ: "StringLiteral",
-                                    value: String::from("hello")
+                                    value: Cow::from("hello"),
+                                    raw: Cow::from("\"hello\"")
                                 }
                             ))
                         }),
@@ -34,7 +37,8 @@ mod test {
                             expression: Expression::Literal(Literal::NumericLiteral(
                                 NumericLiteral {
                                     typ: "NumericLiteral",
-                                    value: 42
+                                    value: 42.0,
+                                    raw: Cow::from("42")
                                 }
                             ))
                         })
@@ -54,6 +58,7 @@ mod test {
             result,
             Program {
                 typ: "Program",
+                hashbang: None,
                 body: vec![Statement::BlockStatement(BlockStatement {
                     typ: "BlockStatement",
                     body: vec![]
@@ -81,6 +86,7 @@ mod test {
             result,
             Program {
                 typ: "Program",
+                hashbang: None,
                 body: vec![Statement::BlockStatement(BlockStatement {
                     typ: "BlockStatement",
                     body: vec![Statement::BlockStatement(BlockStatement {
@@ -91,7 +97,8 @@ mod test {
                                 expression: Expression::Literal(Literal::StringLiteral(
                                     StringLiteral {
                                         typ: "StringLiteral",
-                                        value: String::from("hello")
+                                        value: Cow::from("hello"),
+                                        raw: Cow::from("'hello'")
                                     }
                                 ))
                             }),
@@ -100,7 +107,8 @@ mod test {
                                 expression: Expression::Literal(Literal::NumericLiteral(
                                     NumericLiteral {
                                         typ: "NumericLiteral",
-                                        value: 42
+                                        value: 42.0,
+                                        raw: Cow::from("42")
                                     }
                                 ))
                             })
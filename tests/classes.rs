@@ -0,0 +1,1213 @@
+#[cfg(test)]
+mod test {
+    use letter_rdp_rust::*;
+    use std::borrow::Cow;
+    #[test]
+    fn handles_empty_class() {
+        let mut parser = init();
+
+        let result = parser.parse("class A {}");
+
+        assert_eq!(
+            result,
+            Program {
+                typ: "Program",
+                hashbang: None,
+                body: vec![Statement::ClassDeclaration(ClassDeclaration {
+                    typ: "ClassDeclaration",
+                    id: Identifier {
+                        typ: "Identifier",
+                        name: Cow::from("A"),
+                        type_annotation: None,
+                        optional: false
+                    },
+                    super_class: None,
+                    body: ClassBody {
+                        typ: "ClassBody",
+                        body: vec![]
+                    }
+                })]
+            }
+        )
+    }
+
+    #[test]
+    fn handles_class_heritage() {
+        let mut parser = init();
+
+        let result = parser.parse("class B extends A.Base {}");
+
+        assert_eq!(
+            result,
+            Program {
+                typ: "Program",
+                hashbang: None,
+                body: vec![Statement::ClassDeclaration(ClassDeclaration {
+                    typ: "ClassDeclaration",
+                    id: Identifier {
+                        typ: "Identifier",
+                        name: Cow::from("B"),
+                        type_annotation: None,
+                        optional: false
+                    },
+                    super_class: Some(Box::new(Expression::LeftHandSideExpression(
+                        LeftHandSideExpression::MemberExpression(MemberExpression {
+                            typ: "MemberExpression",
+                            object: Box::new(Expression::LeftHandSideExpression(
+                                LeftHandSideExpression::Identifier(Identifier {
+                                    typ: "Identifier",
+                                    name: Cow::from("A"),
+                                    type_annotation: None,
+                                    optional: false
+                                })
+                            )),
+                            property: Box::new(Expression::LeftHandSideExpression(
+                                LeftHandSideExpression::Identifier(Identifier {
+                                    typ: "Identifier",
+                                    name: Cow::from("Base"),
+                                    type_annotation: None,
+                                    optional: false
+                                })
+                            )),
+                            computed: false,
+                            optional: false
+                        })
+                    ))),
+                    body: ClassBody {
+                        typ: "ClassBody",
+                        body: vec![]
+                    }
+                })]
+            }
+        )
+    }
+
+    #[test]
+    fn handles_methods_with_kinds() {
+        let mut parser = init();
+
+        let result = parser.parse(
+            "class A { constructor(x) {} area() { return this.w; } get size() {} set size(v) {} }",
+        );
+
+        let this_w = Expression::LeftHandSideExpression(LeftHandSideExpression::MemberExpression(
+            MemberExpression {
+                typ: "MemberExpression",
+                object: Box::new(Expression::LeftHandSideExpression(
+                    LeftHandSideExpression::ThisExpression(ThisExpression {
+                        typ: "ThisExpression",
+                    }),
+                )),
+                property: Box::new(Expression::LeftHandSideExpression(
+                    LeftHandSideExpression::Identifier(Identifier {
+                        typ: "Identifier",
+                        name: Cow::from("w"),
+                        type_annotation: None,
+                        optional: false,
+                    }),
+                )),
+                computed: false,
+                optional: false,
+            },
+        ));
+
+        assert_eq!(
+            result,
+            Program {
+                typ: "Program",
+                hashbang: None,
+                body: vec![Statement::ClassDeclaration(ClassDeclaration {
+                    typ: "ClassDeclaration",
+                    id: Identifier {
+                        typ: "Identifier",
+                        name: Cow::from("A"),
+                        type_annotation: None,
+                        optional: false
+                    },
+                    super_class: None,
+                    body: ClassBody {
+                        typ: "ClassBody",
+                        body: vec![
+                            ClassElement::MethodDefinition(MethodDefinition {
+                                typ: "MethodDefinition",
+                                key: Box::new(Expression::LeftHandSideExpression(
+                                    LeftHandSideExpression::Identifier(Identifier {
+                                        typ: "Identifier",
+                                        name: Cow::from("constructor"),
+                                        type_annotation: None,
+                                        optional: false
+                                    })
+                                )),
+                                value: FunctionExpression {
+                                    typ: "FunctionExpression",
+                                    id: None,
+                                    params: vec![Identifier {
+                                        typ: "Identifier",
+                                        name: Cow::from("x"),
+                                        type_annotation: None,
+                                        optional: false
+                                    }],
+                                    body: BlockStatement {
+                                        typ: "BlockStatement",
+                                        body: vec![]
+                                    },
+                                    is_async: false,
+                                    is_generator: false,
+                                    return_type: None
+                                },
+                                kind: "constructor",
+                                computed: false,
+                                is_static: false
+                            }),
+                            ClassElement::MethodDefinition(MethodDefinition {
+                                typ: "MethodDefinition",
+                                key: Box::new(Expression::LeftHandSideExpression(
+                                    LeftHandSideExpression::Identifier(Identifier {
+                                        typ: "Identifier",
+                                        name: Cow::from("area"),
+                                        type_annotation: None,
+                                        optional: false
+                                    })
+                                )),
+                                value: FunctionExpression {
+                                    typ: "FunctionExpression",
+                                    id: None,
+                                    params: vec![],
+                                    body: BlockStatement {
+                                        typ: "BlockStatement",
+                                        body: vec![Statement::ReturnStatement(ReturnStatement {
+                                            typ: "ReturnStatement",
+                                            argument: Some(this_w)
+                                        })]
+                                    },
+                                    is_async: false,
+                                    is_generator: false,
+                                    return_type: None
+                                },
+                                kind: "method",
+                                computed: false,
+                                is_static: false
+                            }),
+                            ClassElement::MethodDefinition(MethodDefinition {
+                                typ: "MethodDefinition",
+                                key: Box::new(Expression::LeftHandSideExpression(
+                                    LeftHandSideExpression::Identifier(Identifier {
+                                        typ: "Identifier",
+                                        name: Cow::from("size"),
+                                        type_annotation: None,
+                                        optional: false
+                                    })
+                                )),
+                                value: FunctionExpression {
+                                    typ: "FunctionExpression",
+                                    id: None,
+                                    params: vec![],
+                                    body: BlockStatement {
+                                        typ: "BlockStatement",
+                                        body: vec![]
+                                    },
+                                    is_async: false,
+                                    is_generator: false,
+                                    return_type: None
+                                },
+                                kind: "get",
+                                computed: false,
+                                is_static: false
+                            }),
+                            ClassElement::MethodDefinition(MethodDefinition {
+                                typ: "MethodDefinition",
+                                key: Box::new(Expression::LeftHandSideExpression(
+                                    LeftHandSideExpression::Identifier(Identifier {
+                                        typ: "Identifier",
+                                        name: Cow::from("size"),
+                                        type_annotation: None,
+                                        optional: false
+                                    })
+                                )),
+                                value: FunctionExpression {
+                                    typ: "FunctionExpression",
+                                    id: None,
+                                    params: vec![Identifier {
+                                        typ: "Identifier",
+                                        name: Cow::from("v"),
+                                        type_annotation: None,
+                                        optional: false
+                                    }],
+                                    body: BlockStatement {
+                                        typ: "BlockStatement",
+                                        body: vec![]
+                                    },
+                                    is_async: false,
+                                    is_generator: false,
+                                    return_type: None
+                                },
+                                kind: "set",
+                                computed: false,
+                                is_static: false
+                            })
+                        ]
+                    }
+                })]
+            }
+        )
+    }
+
+    #[test]
+    fn handles_async_and_generator_methods() {
+        let mut parser = init();
+
+        let result =
+            parser.parse("class A { async load() {} *items() {} static async *stream() {} }");
+
+        assert_eq!(
+            result,
+            Program {
+                typ: "Program",
+                hashbang: None,
+                body: vec![Statement::ClassDeclaration(ClassDeclaration {
+                    typ: "ClassDeclaration",
+                    id: Identifier {
+                        typ: "Identifier",
+                        name: Cow::from("A"),
+                        type_annotation: None,
+                        optional: false
+                    },
+                    super_class: None,
+                    body: ClassBody {
+                        typ: "ClassBody",
+                        body: vec![
+                            ClassElement::MethodDefinition(MethodDefinition {
+                                typ: "MethodDefinition",
+                                key: Box::new(Expression::LeftHandSideExpression(
+                                    LeftHandSideExpression::Identifier(Identifier {
+                                        typ: "Identifier",
+                                        name: Cow::from("load"),
+                                        type_annotation: None,
+                                        optional: false
+                                    })
+                                )),
+                                value: FunctionExpression {
+                                    typ: "FunctionExpression",
+                                    id: None,
+                                    params: vec![],
+                                    body: BlockStatement {
+                                        typ: "BlockStatement",
+                                        body: vec![]
+                                    },
+                                    is_async: true,
+                                    is_generator: false,
+                                    return_type: None
+                                },
+                                kind: "method",
+                                computed: false,
+                                is_static: false
+                            }),
+                            ClassElement::MethodDefinition(MethodDefinition {
+                                typ: "MethodDefinition",
+                                key: Box::new(Expression::LeftHandSideExpression(
+                                    LeftHandSideExpression::Identifier(Identifier {
+                                        typ: "Identifier",
+                                        name: Cow::from("items"),
+                                        type_annotation: None,
+                                        optional: false
+                                    })
+                                )),
+                                value: FunctionExpression {
+                                    typ: "FunctionExpression",
+                                    id: None,
+                                    params: vec![],
+                                    body: BlockStatement {
+                                        typ: "BlockStatement",
+                                        body: vec![]
+                                    },
+                                    is_async: false,
+                                    is_generator: true,
+                                    return_type: None
+                                },
+                                kind: "method",
+                                computed: false,
+                                is_static: false
+                            }),
+                            ClassElement::MethodDefinition(MethodDefinition {
+                                typ: "MethodDefinition",
+                                key: Box::new(Expression::LeftHandSideExpression(
+                                    LeftHandSideExpression::Identifier(Identifier {
+                                        typ: "Identifier",
+                                        name: Cow::from("stream"),
+                                        type_annotation: None,
+                                        optional: false
+                                    })
+                                )),
+                                value: FunctionExpression {
+                                    typ: "FunctionExpression",
+                                    id: None,
+                                    params: vec![],
+                                    body: BlockStatement {
+                                        typ: "BlockStatement",
+                                        body: vec![]
+                                    },
+                                    is_async: true,
+                                    is_generator: true,
+                                    return_type: None
+                                },
+                                kind: "method",
+                                computed: false,
+                                is_static: true
+                            })
+                        ]
+                    }
+                })]
+            }
+        )
+    }
+
+    #[test]
+    fn handles_fields() {
+        let mut parser = init();
+
+        let result = parser.parse(r#"class A { x = 1; static count = 0; y; ["z"] = 2 }"#);
+
+        assert_eq!(
+            result,
+            Program {
+                typ: "Program",
+                hashbang: None,
+                body: vec![Statement::ClassDeclaration(ClassDeclaration {
+                    typ: "ClassDeclaration",
+                    id: Identifier {
+                        typ: "Identifier",
+                        name: Cow::from("A"),
+                        type_annotation: None,
+                        optional: false
+                    },
+                    super_class: None,
+                    body: ClassBody {
+                        typ: "ClassBody",
+                        body: vec![
+                            ClassElement::PropertyDefinition(PropertyDefinition {
+                                typ: "PropertyDefinition",
+                                key: Box::new(Expression::LeftHandSideExpression(
+                                    LeftHandSideExpression::Identifier(Identifier {
+                                        typ: "Identifier",
+                                        name: Cow::from("x"),
+                                        type_annotation: None,
+                                        optional: false
+                                    })
+                                )),
+                                value: Some(Box::new(Expression::Literal(
+                                    Literal::NumericLiteral(NumericLiteral {
+                                        typ: "NumericLiteral",
+                                        value: 1.0,
+                                        raw: Cow::from("1")
+                                    })
+                                ))),
+                                computed: false,
+                                is_static: false
+                            }),
+                            ClassElement::PropertyDefinition(PropertyDefinition {
+                                typ: "PropertyDefinition",
+                                key: Box::new(Expression::LeftHandSideExpression(
+                                    LeftHandSideExpression::Identifier(Identifier {
+                                        typ: "Identifier",
+                                        name: Cow::from("count"),
+                                        type_annotation: None,
+                                        optional: false
+                                    })
+                                )),
+                                value: Some(Box::new(Expression::Literal(
+                                    Literal::NumericLiteral(NumericLiteral {
+                                        typ: "NumericLiteral",
+                                        value: 0.0,
+                                        raw: Cow::from("0")
+                                    })
+                                ))),
+                                computed: false,
+                                is_static: true
+                            }),
+                            ClassElement::PropertyDefinition(PropertyDefinition {
+                                typ: "PropertyDefinition",
+                                key: Box::new(Expression::LeftHandSideExpression(
+                                    LeftHandSideExpression::Identifier(Identifier {
+                                        typ: "Identifier",
+                                        name: Cow::from("y"),
+                                        type_annotation: None,
+                                        optional: false
+                                    })
+                                )),
+                                value: None,
+                                computed: false,
+                                is_static: false
+                            }),
+                            ClassElement::PropertyDefinition(PropertyDefinition {
+                                typ: "PropertyDefinition",
+                                key: Box::new(Expression::Literal(Literal::StringLiteral(
+                                    StringLiteral {
+                                        typ: "StringLiteral",
+                                        value: Cow::from("z"),
+                                        raw: Cow::from("\"z\"")
+                                    }
+                                ))),
+                                value: Some(Box::new(Expression::Literal(
+                                    Literal::NumericLiteral(NumericLiteral {
+                                        typ: "NumericLiteral",
+                                        value: 2.0,
+                                        raw: Cow::from("2")
+                                    })
+                                ))),
+                                computed: true,
+                                is_static: false
+                            })
+                        ]
+                    }
+                })]
+            }
+        )
+    }
+
+    #[test]
+    fn handles_modifiers_as_names() {
+        let mut parser = init();
+
+        let result = parser.parse(
+            "class A { static; get() {} set = 1; async
+ x }",
+        );
+
+        assert_eq!(
+            result,
+            Program {
+                typ: "Program",
+                hashbang: None,
+                body: vec![Statement::ClassDeclaration(ClassDeclaration {
+                    typ: "ClassDeclaration",
+                    id: Identifier {
+                        typ: "Identifier",
+                        name: Cow::from("A"),
+                        type_annotation: None,
+                        optional: false
+                    },
+                    super_class: None,
+                    body: ClassBody {
+                        typ: "ClassBody",
+                        body: vec![
+                            ClassElement::PropertyDefinition(PropertyDefinition {
+                                typ: "PropertyDefinition",
+                                key: Box::new(Expression::LeftHandSideExpression(
+                                    LeftHandSideExpression::Identifier(Identifier {
+                                        typ: "Identifier",
+                                        name: Cow::from("static"),
+                                        type_annotation: None,
+                                        optional: false
+                                    })
+                                )),
+                                value: None,
+                                computed: false,
+                                is_static: false
+                            }),
+                            ClassElement::MethodDefinition(MethodDefinition {
+                                typ: "MethodDefinition",
+                                key: Box::new(Expression::LeftHandSideExpression(
+                                    LeftHandSideExpression::Identifier(Identifier {
+                                        typ: "Identifier",
+                                        name: Cow::from("get"),
+                                        type_annotation: None,
+                                        optional: false
+                                    })
+                                )),
+                                value: FunctionExpression {
+                                    typ: "FunctionExpression",
+                                    id: None,
+                                    params: vec![],
+                                    body: BlockStatement {
+                                        typ: "BlockStatement",
+                                        body: vec![]
+                                    },
+                                    is_async: false,
+                                    is_generator: false,
+                                    return_type: None
+                                },
+                                kind: "method",
+                                computed: false,
+                                is_static: false
+                            }),
+                            ClassElement::PropertyDefinition(PropertyDefinition {
+                                typ: "PropertyDefinition",
+                                key: Box::new(Expression::LeftHandSideExpression(
+                                    LeftHandSideExpression::Identifier(Identifier {
+                                        typ: "Identifier",
+                                        name: Cow::from("set"),
+                                        type_annotation: None,
+                                        optional: false
+                                    })
+                                )),
+                                value: Some(Box::new(Expression::Literal(
+                                    Literal::NumericLiteral(NumericLiteral {
+                                        typ: "NumericLiteral",
+                                        value: 1.0,
+                                        raw: Cow::from("1")
+                                    })
+                                ))),
+                                computed: false,
+                                is_static: false
+                            }),
+                            ClassElement::PropertyDefinition(PropertyDefinition {
+                                typ: "PropertyDefinition",
+                                key: Box::new(Expression::LeftHandSideExpression(
+                                    LeftHandSideExpression::Identifier(Identifier {
+                                        typ: "Identifier",
+                                        name: Cow::from("async"),
+                                        type_annotation: None,
+                                        optional: false
+                                    })
+                                )),
+                                value: None,
+                                computed: false,
+                                is_static: false
+                            }),
+                            ClassElement::PropertyDefinition(PropertyDefinition {
+                                typ: "PropertyDefinition",
+                                key: Box::new(Expression::LeftHandSideExpression(
+                                    LeftHandSideExpression::Identifier(Identifier {
+                                        typ: "Identifier",
+                                        name: Cow::from("x"),
+                                        type_annotation: None,
+                                        optional: false
+                                    })
+                                )),
+                                value: None,
+                                computed: false,
+                                is_static: false
+                            })
+                        ]
+                    }
+                })]
+            }
+        )
+    }
+
+    #[test]
+    fn handles_private_members() {
+        let mut parser = init();
+
+        let result = parser.parse("class A { #secret = 1; #read() { return this.#secret; } }");
+
+        let this_secret = Expression::LeftHandSideExpression(
+            LeftHandSideExpression::MemberExpression(MemberExpression {
+                typ: "MemberExpression",
+                object: Box::new(Expression::LeftHandSideExpression(
+                    LeftHandSideExpression::ThisExpression(ThisExpression {
+                        typ: "ThisExpression",
+                    }),
+                )),
+                property: Box::new(Expression::LeftHandSideExpression(
+                    LeftHandSideExpression::PrivateIdentifier(PrivateIdentifier {
+                        typ: "PrivateIdentifier",
+                        name: Cow::from("secret"),
+                    }),
+                )),
+                computed: false,
+                optional: false,
+            }),
+        );
+
+        assert_eq!(
+            result,
+            Program {
+                typ: "Program",
+                hashbang: None,
+                body: vec![Statement::ClassDeclaration(ClassDeclaration {
+                    typ: "ClassDeclaration",
+                    id: Identifier {
+                        typ: "Identifier",
+                        name: Cow::from("A"),
+                        type_annotation: None,
+                        optional: false
+                    },
+                    super_class: None,
+                    body: ClassBody {
+                        typ: "ClassBody",
+                        body: vec![
+                            ClassElement::PropertyDefinition(PropertyDefinition {
+                                typ: "PropertyDefinition",
+                                key: Box::new(Expression::LeftHandSideExpression(
+                                    LeftHandSideExpression::PrivateIdentifier(PrivateIdentifier {
+                                        typ: "PrivateIdentifier",
+                                        name: Cow::from("secret")
+                                    })
+                                )),
+                                value: Some(Box::new(Expression::Literal(
+                                    Literal::NumericLiteral(NumericLiteral {
+                                        typ: "NumericLiteral",
+                                        value: 1.0,
+                                        raw: Cow::from("1")
+                                    })
+                                ))),
+                                computed: false,
+                                is_static: false
+                            }),
+                            ClassElement::MethodDefinition(MethodDefinition {
+                                typ: "MethodDefinition",
+                                key: Box::new(Expression::LeftHandSideExpression(
+                                    LeftHandSideExpression::PrivateIdentifier(PrivateIdentifier {
+                                        typ: "PrivateIdentifier",
+                                        name: Cow::from("read")
+                                    })
+                                )),
+                                value: FunctionExpression {
+                                    typ: "FunctionExpression",
+                                    id: None,
+                                    params: vec![],
+                                    body: BlockStatement {
+                                        typ: "BlockStatement",
+                                        body: vec![Statement::ReturnStatement(ReturnStatement {
+                                            typ: "ReturnStatement",
+                                            argument: Some(this_secret)
+                                        })]
+                                    },
+                                    is_async: false,
+                                    is_generator: false,
+                                    return_type: None
+                                },
+                                kind: "method",
+                                computed: false,
+                                is_static: false
+                            })
+                        ]
+                    }
+                })]
+            }
+        )
+    }
+
+    #[test]
+    fn resolves_private_names_declared_later() {
+        let mut parser = init();
+
+        let result = parser.parse("class A { read() { return this.#x; } #x; }");
+
+        let this_x = Expression::LeftHandSideExpression(LeftHandSideExpression::MemberExpression(
+            MemberExpression {
+                typ: "MemberExpression",
+                object: Box::new(Expression::LeftHandSideExpression(
+                    LeftHandSideExpression::ThisExpression(ThisExpression {
+                        typ: "ThisExpression",
+                    }),
+                )),
+                property: Box::new(Expression::LeftHandSideExpression(
+                    LeftHandSideExpression::PrivateIdentifier(PrivateIdentifier {
+                        typ: "PrivateIdentifier",
+                        name: Cow::from("x"),
+                    }),
+                )),
+                computed: false,
+                optional: false,
+            },
+        ));
+
+        assert_eq!(
+            result,
+            Program {
+                typ: "Program",
+                hashbang: None,
+                body: vec![Statement::ClassDeclaration(ClassDeclaration {
+                    typ: "ClassDeclaration",
+                    id: Identifier {
+                        typ: "Identifier",
+                        name: Cow::from("A"),
+                        type_annotation: None,
+                        optional: false
+                    },
+                    super_class: None,
+                    body: ClassBody {
+                        typ: "ClassBody",
+                        body: vec![
+                            ClassElement::MethodDefinition(MethodDefinition {
+                                typ: "MethodDefinition",
+                                key: Box::new(Expression::LeftHandSideExpression(
+                                    LeftHandSideExpression::Identifier(Identifier {
+                                        typ: "Identifier",
+                                        name: Cow::from("read"),
+                                        type_annotation: None,
+                                        optional: false
+                                    })
+                                )),
+                                value: FunctionExpression {
+                                    typ: "FunctionExpression",
+                                    id: None,
+                                    params: vec![],
+                                    body: BlockStatement {
+                                        typ: "BlockStatement",
+                                        body: vec![Statement::ReturnStatement(ReturnStatement {
+                                            typ: "ReturnStatement",
+                                            argument: Some(this_x)
+                                        })]
+                                    },
+                                    is_async: false,
+                                    is_generator: false,
+                                    return_type: None
+                                },
+                                kind: "method",
+                                computed: false,
+                                is_static: false
+                            }),
+                            ClassElement::PropertyDefinition(PropertyDefinition {
+                                typ: "PropertyDefinition",
+                                key: Box::new(Expression::LeftHandSideExpression(
+                                    LeftHandSideExpression::PrivateIdentifier(PrivateIdentifier {
+                                        typ: "PrivateIdentifier",
+                                        name: Cow::from("x")
+                                    })
+                                )),
+                                value: None,
+                                computed: false,
+                                is_static: false
+                            })
+                        ]
+                    }
+                })]
+            }
+        )
+    }
+
+    #[test]
+    fn handles_assignment_to_private_field_in_setter() {
+        let mut parser = init();
+
+        let result = parser.parse("class A { #x; set x(v) { this.#x = v; } }");
+
+        let setter = match &result.body[0] {
+            Statement::ClassDeclaration(class) => match &class.body.body[1] {
+                ClassElement::MethodDefinition(method) => method,
+                element => panic!("Unexpected class element: {:?}", element),
+            },
+            statement => panic!("Unexpected statement: {:?}", statement),
+        };
+        assert_eq!(setter.kind, "set");
+        assert_eq!(
+            setter.value.body.body,
+            vec![Statement::ExpressionStatement(ExpressionStatement {
+                typ: "ExpressionStatement",
+                expression: Expression::AssignmentExpression(AssignmentExpression {
+                    typ: "AssignmentExpression",
+                    operator: Cow::from("="),
+                    left: Box::new(Expression::LeftHandSideExpression(
+                        LeftHandSideExpression::MemberExpression(MemberExpression {
+                            typ: "MemberExpression",
+                            object: Box::new(Expression::LeftHandSideExpression(
+                                LeftHandSideExpression::ThisExpression(ThisExpression {
+                                    typ: "ThisExpression",
+                                }),
+                            )),
+                            property: Box::new(Expression::LeftHandSideExpression(
+                                LeftHandSideExpression::PrivateIdentifier(PrivateIdentifier {
+                                    typ: "PrivateIdentifier",
+                                    name: Cow::from("x"),
+                                }),
+                            )),
+                            computed: false,
+                            optional: false,
+                        })
+                    )),
+                    right: Box::new(Expression::LeftHandSideExpression(
+                        LeftHandSideExpression::Identifier(Identifier {
+                            typ: "Identifier",
+                            name: Cow::from("v"),
+                            type_annotation: None,
+                            optional: false
+                        })
+                    ))
+                })
+            })]
+        );
+    }
+
+    #[test]
+    fn handles_assignment_to_this_in_constructor() {
+        let mut parser = init();
+
+        let result = parser.parse("class A { constructor(x) { this.x = x; } }");
+
+        let constructor = match &result.body[0] {
+            Statement::ClassDeclaration(class) => match &class.body.body[0] {
+                ClassElement::MethodDefinition(method) => method,
+                element => panic!("Unexpected class element: {:?}", element),
+            },
+            statement => panic!("Unexpected statement: {:?}", statement),
+        };
+        assert_eq!(constructor.kind, "constructor");
+        assert!(matches!(
+            &constructor.value.body.body[0],
+            Statement::ExpressionStatement(ExpressionStatement {
+                expression: Expression::AssignmentExpression(AssignmentExpression { left, .. }),
+                ..
+            }) if matches!(
+                &**left,
+                Expression::LeftHandSideExpression(LeftHandSideExpression::MemberExpression(_))
+            )
+        ))
+    }
+
+    #[test]
+    fn resolves_private_names_of_enclosing_class() {
+        let mut parser = init();
+
+        let result = parser.parse("class A { #x; m() { class B { n() { this.#x; } } } }");
+
+        let this_x = Statement::ExpressionStatement(ExpressionStatement {
+            typ: "ExpressionStatement",
+            expression: Expression::LeftHandSideExpression(
+                LeftHandSideExpression::MemberExpression(MemberExpression {
+                    typ: "MemberExpression",
+                    object: Box::new(Expression::LeftHandSideExpression(
+                        LeftHandSideExpression::ThisExpression(ThisExpression {
+                            typ: "ThisExpression",
+                        }),
+                    )),
+                    property: Box::new(Expression::LeftHandSideExpression(
+                        LeftHandSideExpression::PrivateIdentifier(PrivateIdentifier {
+                            typ: "PrivateIdentifier",
+                            name: Cow::from("x"),
+                        }),
+                    )),
+                    computed: false,
+                    optional: false,
+                }),
+            ),
+        });
+        let class_b = Statement::ClassDeclaration(ClassDeclaration {
+            typ: "ClassDeclaration",
+            id: Identifier {
+                typ: "Identifier",
+                name: Cow::from("B"),
+                type_annotation: None,
+                optional: false,
+            },
+            super_class: None,
+            body: ClassBody {
+                typ: "ClassBody",
+                body: vec![ClassElement::MethodDefinition(MethodDefinition {
+                    typ: "MethodDefinition",
+                    key: Box::new(Expression::LeftHandSideExpression(
+                        LeftHandSideExpression::Identifier(Identifier {
+                            typ: "Identifier",
+                            name: Cow::from("n"),
+                            type_annotation: None,
+                            optional: false,
+                        }),
+                    )),
+                    value: FunctionExpression {
+                        typ: "FunctionExpression",
+                        id: None,
+                        params: vec![],
+                        body: BlockStatement {
+                            typ: "BlockStatement",
+                            body: vec![this_x],
+                        },
+                        is_async: false,
+                        is_generator: false,
+                        return_type: None,
+                    },
+                    kind: "method",
+                    computed: false,
+                    is_static: false,
+                })],
+            },
+        });
+
+        assert_eq!(
+            result,
+            Program {
+                typ: "Program",
+                hashbang: None,
+                body: vec![Statement::ClassDeclaration(ClassDeclaration {
+                    typ: "ClassDeclaration",
+                    id: Identifier {
+                        typ: "Identifier",
+                        name: Cow::from("A"),
+                        type_annotation: None,
+                        optional: false
+                    },
+                    super_class: None,
+                    body: ClassBody {
+                        typ: "ClassBody",
+                        body: vec![
+                            ClassElement::PropertyDefinition(PropertyDefinition {
+                                typ: "PropertyDefinition",
+                                key: Box::new(Expression::LeftHandSideExpression(
+                                    LeftHandSideExpression::PrivateIdentifier(PrivateIdentifier {
+                                        typ: "PrivateIdentifier",
+                                        name: Cow::from("x")
+                                    })
+                                )),
+                                value: None,
+                                computed: false,
+                                is_static: false
+                            }),
+                            ClassElement::MethodDefinition(MethodDefinition {
+                                typ: "MethodDefinition",
+                                key: Box::new(Expression::LeftHandSideExpression(
+                                    LeftHandSideExpression::Identifier(Identifier {
+                                        typ: "Identifier",
+                                        name: Cow::from("m"),
+                                        type_annotation: None,
+                                        optional: false
+                                    })
+                                )),
+                                value: FunctionExpression {
+                                    typ: "FunctionExpression",
+                                    id: None,
+                                    params: vec![],
+                                    body: BlockStatement {
+                                        typ: "BlockStatement",
+                                        body: vec![class_b]
+                                    },
+                                    is_async: false,
+                                    is_generator: false,
+                                    return_type: None
+                                },
+                                kind: "method",
+                                computed: false,
+                                is_static: false
+                            })
+                        ]
+                    }
+                })]
+            }
+        )
+    }
+
+    #[test]
+    fn handles_static_blocks() {
+        let mut parser = init();
+
+        let result = parser.parse("class A { static { let x = 1; } }");
+
+        assert_eq!(
+            result,
+            Program {
+                typ: "Program",
+                hashbang: None,
+                body: vec![Statement::ClassDeclaration(ClassDeclaration {
+                    typ: "ClassDeclaration",
+                    id: Identifier {
+                        typ: "Identifier",
+                        name: Cow::from("A"),
+                        type_annotation: None,
+                        optional: false
+                    },
+                    super_class: None,
+                    body: ClassBody {
+                        typ: "ClassBody",
+                        body: vec![ClassElement::StaticBlock(StaticBlock {
+                            typ: "StaticBlock",
+                            body: vec![Statement::VariableStatement(VariableStatement {
+                                typ: "VariableStatement",
+                                kind: "let",
+                                declarations: vec![VariableDeclaration {
+                                    typ: "VariableDeclaration",
+                                    id: Identifier {
+                                        typ: "Identifier",
+                                        name: Cow::from("x"),
+                                        type_annotation: None,
+                                        optional: false
+                                    },
+                                    init: Some(Expression::Literal(Literal::NumericLiteral(
+                                        NumericLiteral {
+                                            typ: "NumericLiteral",
+                                            value: 1.0,
+                                            raw: Cow::from("1")
+                                        }
+                                    )))
+                                }]
+                            })]
+                        })]
+                    }
+                })]
+            }
+        )
+    }
+
+    #[test]
+    fn handles_class_expressions() {
+        let mut parser = init();
+
+        let result = parser.parse("(class extends B {});");
+
+        assert_eq!(
+            result,
+            Program {
+                typ: "Program",
+                hashbang: None,
+                body: vec![Statement::ExpressionStatement(ExpressionStatement {
+                    typ: "ExpressionStatement",
+                    expression: Expression::ClassExpression(ClassExpression {
+                        typ: "ClassExpression",
+                        id: None,
+                        super_class: Some(Box::new(Expression::LeftHandSideExpression(
+                            LeftHandSideExpression::Identifier(Identifier {
+                                typ: "Identifier",
+                                name: Cow::from("B"),
+                                type_annotation: None,
+                                optional: false
+                            })
+                        ))),
+                        body: ClassBody {
+                            typ: "ClassBody",
+                            body: vec![]
+                        }
+                    })
+                })]
+            }
+        )
+    }
+    #[test]
+    #[should_panic(expected = "Identifier '#x' has already been declared")]
+    fn rejects_duplicate_private_names() {
+        let mut parser = init();
+
+        parser.parse("class A { #x; #x() {} }");
+    }
+
+    #[test]
+    fn allows_private_accessor_pairs() {
+        let mut parser = init();
+
+        let result = parser.parse("class A { get #x() {} set #x(v) {} }");
+
+        assert_eq!(
+            result,
+            Program {
+                typ: "Program",
+                hashbang: None,
+                body: vec![Statement::ClassDeclaration(ClassDeclaration {
+                    typ: "ClassDeclaration",
+                    id: Identifier {
+                        typ: "Identifier",
+                        name: Cow::from("A"),
+                        type_annotation: None,
+                        optional: false
+                    },
+                    super_class: None,
+                    body: ClassBody {
+                        typ: "ClassBody",
+                        body: vec![
+                            ClassElement::MethodDefinition(MethodDefinition {
+                                typ: "MethodDefinition",
+                                key: Box::new(Expression::LeftHandSideExpression(
+                                    LeftHandSideExpression::PrivateIdentifier(PrivateIdentifier {
+                                        typ: "PrivateIdentifier",
+                                        name: Cow::from("x")
+                                    })
+                                )),
+                                value: FunctionExpression {
+                                    typ: "FunctionExpression",
+                                    id: None,
+                                    params: vec![],
+                                    body: BlockStatement {
+                                        typ: "BlockStatement",
+                                        body: vec![]
+                                    },
+                                    is_async: false,
+                                    is_generator: false,
+                                    return_type: None
+                                },
+                                kind: "get",
+                                computed: false,
+                                is_static: false
+                            }),
+                            ClassElement::MethodDefinition(MethodDefinition {
+                                typ: "MethodDefinition",
+                                key: Box::new(Expression::LeftHandSideExpression(
+                                    LeftHandSideExpression::PrivateIdentifier(PrivateIdentifier {
+                                        typ: "PrivateIdentifier",
+                                        name: Cow::from("x")
+                                    })
+                                )),
+                                value: FunctionExpression {
+                                    typ: "FunctionExpression",
+                                    id: None,
+                                    params: vec![Identifier {
+                                        typ: "Identifier",
+                                        name: Cow::from("v"),
+                                        type_annotation: None,
+                                        optional: false
+                                    }],
+                                    body: BlockStatement {
+                                        typ: "BlockStatement",
+                                        body: vec![]
+                                    },
+                                    is_async: false,
+                                    is_generator: false,
+                                    return_type: None
+                                },
+                                kind: "set",
+                                computed: false,
+                                is_static: false
+                            })
+                        ]
+                    }
+                })]
+            }
+        )
+    }
+
+    #[test]
+    #[should_panic(expected = "Private field '#x' must be declared in an enclosing class (1:3)")]
+    fn rejects_private_name_outside_class() {
+        let mut parser = init();
+
+        parser.parse("a.#x;");
+    }
+
+    #[test]
+    #[should_panic(expected = "Private field '#y' must be declared in an enclosing class (1:22)")]
+    fn rejects_assignment_to_undeclared_private_name() {
+        let mut parser = init();
+
+        parser.parse("class A { m() { this.#y = 1; } }");
+    }
+
+    #[test]
+    #[should_panic(expected = "Private field '#y' must be declared in an enclosing class (2:14)")]
+    fn rejects_undeclared_private_name() {
+        let mut parser = init();
+
+        parser.parse(
+            "class A {
+  m() { this.#y; }
+}",
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "A class may only have one constructor")]
+    fn rejects_duplicate_constructor() {
+        let mut parser = init();
+
+        parser.parse("class A { constructor() {} constructor() {} }");
+    }
+
+    #[test]
+    #[should_panic(expected = "Class constructor may not be an accessor")]
+    fn rejects_accessor_constructor() {
+        let mut parser = init();
+
+        parser.parse("class A { get constructor() {} }");
+    }
+
+    #[test]
+    #[should_panic(expected = "Getter must not have any formal parameters")]
+    fn rejects_getter_with_parameters() {
+        let mut parser = init();
+
+        parser.parse("class A { get x(v) {} }");
+    }
+
+    #[test]
+    #[should_panic(expected = "'super' keyword unexpected here")]
+    fn rejects_super_outside_class() {
+        let mut parser = init();
+
+        parser.parse("super.x;");
+    }
+}
@@ -0,0 +1,372 @@
+#[cfg(test)]
+mod test {
+    use letter_rdp_rust::*;
+    use std::borrow::Cow;
+    #[test]
+    fn exposes_hashbang() {
+        let mut parser = init();
+
+        let result = parser.parse(
+            "#!/usr/bin/env letter
+1;",
+        );
+
+        assert_eq!(
+            result,
+            Program {
+                typ: "Program",
+                hashbang: Some(Cow::from("/usr/bin/env letter")),
+                body: vec![Statement::ExpressionStatement(ExpressionStatement {
+                    typ: "ExpressionStatement",
+                    expression: Expression::Literal(Literal::NumericLiteral(NumericLiteral {
+                        typ: "NumericLiteral",
+                        value: 1.0,
+                        raw: Cow::from("1")
+                    }))
+                })]
+            }
+        )
+    }
+
+    #[test]
+    fn handles_hashbang_only() {
+        let mut parser = init();
+
+        let result = parser.parse("#!/usr/bin/env letter");
+
+        assert_eq!(
+            result,
+            Program {
+                typ: "Program",
+                hashbang: Some(Cow::from("/usr/bin/env letter")),
+                body: vec![]
+            }
+        )
+    }
+
+    #[test]
+    #[should_panic(expected = "Unexpected token: # (2:1)")]
+    fn rejects_hashbang_after_first_line() {
+        let mut parser = init();
+
+        parser.parse(
+            "
+#!/usr/bin/env letter",
+        );
+    }
+
+    #[test]
+    fn handles_program_directives() {
+        let mut parser = init();
+
+        let result = parser.parse(
+            r#""use strict";
+'use asm'
+x;"#,
+        );
+
+        assert_eq!(
+            result,
+            Program {
+                typ: "Program",
+                hashbang: None,
+                body: vec![
+                    Statement::Directive(Directive {
+                        typ: "Directive",
+                        expression: StringLiteral {
+                            typ: "StringLiteral",
+                            value: Cow::from("use strict"),
+                            raw: Cow::from("\"use strict\"")
+                        },
+                        directive: Cow::from("use strict")
+                    }),
+                    Statement::Directive(Directive {
+                        typ: "Directive",
+                        expression: StringLiteral {
+                            typ: "StringLiteral",
+                            value: Cow::from("use asm"),
+                            raw: Cow::from("'use asm'")
+                        },
+                        directive: Cow::from("use asm")
+                    }),
+                    Statement::ExpressionStatement(ExpressionStatement {
+                        typ: "ExpressionStatement",
+                        expression: Expression::LeftHandSideExpression(
+                            LeftHandSideExpression::Identifier(Identifier {
+                                typ: "Identifier",
+                                name: Cow::from("x"),
+                                type_annotation: None,
+                                optional: false
+                            })
+                        )
+                    })
+                ]
+            }
+        )
+    }
+
+    #[test]
+    fn handles_function_directives() {
+        let mut parser = init();
+
+        let result = parser.parse(r#"function f() { "use strict"; return; }"#);
+
+        assert_eq!(
+            result,
+            Program {
+                typ: "Program",
+                hashbang: None,
+                body: vec![Statement::FunctionDeclaration(FunctionDeclaration {
+                    typ: "FunctionDeclaration",
+                    id: Identifier {
+                        typ: "Identifier",
+                        name: Cow::from("f"),
+                        type_annotation: None,
+                        optional: false
+                    },
+                    params: vec![],
+                    body: BlockStatement {
+                        typ: "BlockStatement",
+                        body: vec![
+                            Statement::Directive(Directive {
+                                typ: "Directive",
+                                expression: StringLiteral {
+                                    typ: "StringLiteral",
+                                    value: Cow::from("use strict"),
+                                    raw: Cow::from("\"use strict\"")
+                                },
+                                directive: Cow::from("use strict")
+                            }),
+                            Statement::ReturnStatement(ReturnStatement {
+                                typ: "ReturnStatement",
+                                argument: None
+                            })
+                        ]
+                    },
+                    is_async: false,
+                    is_generator: false,
+                    return_type: None
+                })]
+            }
+        )
+    }
+
+    #[test]
+    fn ends_prologue_at_first_other_statement() {
+        let mut parser = init();
+
+        let result = parser.parse(r#""a" + "b"; "use strict";"#);
+
+        assert_eq!(
+            result,
+            Program {
+                typ: "Program",
+                hashbang: None,
+                body: vec![
+                    Statement::ExpressionStatement(ExpressionStatement {
+                        typ: "ExpressionStatement",
+                        expression: Expression::BinaryExpression(BinaryExpression {
+                            typ: "BinaryExpression",
+                            operator: Cow::from("+"),
+                            left: Box::new(Expression::Literal(Literal::StringLiteral(
+                                StringLiteral {
+                                    typ: "StringLiteral",
+                                    value: Cow::from("a"),
+                                    raw: Cow::from("\"a\"")
+                                }
+                            ))),
+                            right: Box::new(Expression::Literal(Literal::StringLiteral(
+                                StringLiteral {
+                                    typ: "StringLiteral",
+                                    value: Cow::from("b"),
+                                    raw: Cow::from("\"b\"")
+                                }
+                            )))
+                        })
+                    }),
+                    Statement::ExpressionStatement(ExpressionStatement {
+                        typ: "ExpressionStatement",
+                        expression: Expression::Literal(Literal::StringLiteral(StringLiteral {
+                            typ: "StringLiteral",
+                            value: Cow::from("use strict"),
+                            raw: Cow::from("\"use strict\"")
+                        }))
+                    })
+                ]
+            }
+        )
+    }
+
+    #[test]
+    fn allows_strict_reserved_words_in_sloppy_mode() {
+        let mut parser = init();
+
+        let result = parser.parse("var implements = 1;");
+
+        assert_eq!(
+            result,
+            Program {
+                typ: "Program",
+                hashbang: None,
+                body: vec![Statement::VariableStatement(VariableStatement {
+                    typ: "VariableStatement",
+                    kind: "var",
+                    declarations: vec![VariableDeclaration {
+                        typ: "VariableDeclaration",
+                        id: Identifier {
+                            typ: "Identifier",
+                            name: Cow::from("implements"),
+                            type_annotation: None,
+                            optional: false
+                        },
+                        init: Some(Expression::Literal(Literal::NumericLiteral(
+                            NumericLiteral {
+                                typ: "NumericLiteral",
+                                value: 1.0,
+                                raw: Cow::from("1")
+                            }
+                        )))
+                    }]
+                })]
+            }
+        )
+    }
+
+    #[test]
+    #[should_panic(expected = "Unexpected strict mode reserved word 'implements'")]
+    fn rejects_strict_reserved_word() {
+        let mut parser = init();
+
+        parser.parse("\"use strict\"; var implements = 1;");
+    }
+
+    #[test]
+    #[should_panic(expected = "Unexpected strict mode reserved word 'package'")]
+    fn rejects_strict_reserved_word_in_strict_function() {
+        let mut parser = init();
+
+        parser.parse("function f() { \"use strict\"; package; }");
+    }
+
+    #[test]
+    #[should_panic(expected = "Unexpected strict mode reserved word 'public'")]
+    fn applies_function_directive_to_parameters() {
+        let mut parser = init();
+
+        parser.parse("function f(public) { \"use strict\"; }");
+    }
+
+    #[test]
+    #[should_panic(expected = "Unexpected strict mode reserved word 'package'")]
+    fn applies_function_directive_to_the_name() {
+        let mut parser = init();
+
+        parser.parse("function package() { \"use strict\"; }");
+    }
+
+    #[test]
+    #[should_panic(expected = "Unexpected eval or arguments in strict mode")]
+    fn applies_function_directive_to_the_name_of_expressions() {
+        let mut parser = init();
+
+        parser.parse("(function eval() { \"use strict\"; });");
+    }
+
+    #[test]
+    fn allows_reserved_function_names_outside_strict_mode() {
+        let mut parser = init();
+
+        assert_eq!(parser.parse("function package() {}").body.len(), 1)
+    }
+
+    #[test]
+    #[should_panic(expected = "Duplicate parameter name not allowed in this context")]
+    fn rejects_duplicate_parameters_in_strict_function() {
+        let mut parser = init();
+
+        parser.parse("function f(a, a) { \"use strict\"; }");
+    }
+
+    #[test]
+    #[should_panic(expected = "Unexpected eval or arguments in strict mode")]
+    fn rejects_assignment_to_eval() {
+        let mut parser = init();
+
+        parser.parse("\"use strict\"; eval = 1;");
+    }
+
+    #[test]
+    #[should_panic(expected = "Unexpected strict mode reserved word 'private'")]
+    fn treats_modules_as_strict() {
+        let mut parser = init_with(ParserOptions {
+            source_type: SourceType::Module,
+            ..ParserOptions::default()
+        });
+
+        parser.parse("let private = 1;");
+    }
+
+    #[test]
+    #[should_panic(expected = "Unexpected strict mode reserved word 'interface'")]
+    fn treats_classes_as_strict() {
+        let mut parser = init();
+
+        parser.parse("class A { m() { interface; } }");
+    }
+
+    #[test]
+    fn restores_sloppy_mode_after_function() {
+        let mut parser = init();
+
+        let result = parser.parse(
+            r#"function f() { "use strict"; }
+protected;"#,
+        );
+
+        assert_eq!(
+            result,
+            Program {
+                typ: "Program",
+                hashbang: None,
+                body: vec![
+                    Statement::FunctionDeclaration(FunctionDeclaration {
+                        typ: "FunctionDeclaration",
+                        id: Identifier {
+                            typ: "Identifier",
+                            name: Cow::from("f"),
+                            type_annotation: None,
+                            optional: false
+                        },
+                        params: vec![],
+                        body: BlockStatement {
+                            typ: "BlockStatement",
+                            body: vec![Statement::Directive(Directive {
+                                typ: "Directive",
+                                expression: StringLiteral {
+                                    typ: "StringLiteral",
+                                    value: Cow::from("use strict"),
+                                    raw: Cow::from("\"use strict\"")
+                                },
+                                directive: Cow::from("use strict")
+                            })]
+                        },
+                        is_async: false,
+                        is_generator: false,
+                        return_type: None
+                    }),
+                    Statement::ExpressionStatement(ExpressionStatement {
+                        typ: "ExpressionStatement",
+                        expression: Expression::LeftHandSideExpression(
+                            LeftHandSideExpression::Identifier(Identifier {
+                                typ: "Identifier",
+                                name: Cow::from("protected"),
+                                type_annotation: None,
+                                optional: false
+                            })
+                        )
+                    })
+                ]
+            }
+        )
+    }
+}
@@ -11,6 +11,7 @@ mod test {
             result,
             Program {
                 typ: "Program",
+                hashbang: None,
                 body: vec![Statement::EmptyStatement {
                     typ: "EmptyStatement",
                 }]
@@ -0,0 +1,199 @@
+#[cfg(test)]
+mod test {
+    use letter_rdp_rust::*;
+    use std::borrow::Cow;
+    #[test]
+    fn parses_standalone_expression() {
+        let mut parser = init();
+
+        let result = parser.parse_expression("age >= 18 && active");
+
+        assert_eq!(
+            result,
+            Expression::LogicalExpression(LogicalExpression {
+                typ: "LogicalExpression",
+                operator: Cow::from("&&"),
+                left: Box::new(Expression::BinaryExpression(BinaryExpression {
+                    typ: "BinaryExpression",
+                    operator: Cow::from(">="),
+                    left: Box::new(Expression::LeftHandSideExpression(
+                        LeftHandSideExpression::Identifier(Identifier {
+                            typ: "Identifier",
+                            name: Cow::from("age"),
+                            type_annotation: None,
+                            optional: false
+                        })
+                    )),
+                    right: Box::new(Expression::Literal(Literal::NumericLiteral(
+                        NumericLiteral {
+                            typ: "NumericLiteral",
+                            value: 18.0,
+                            raw: Cow::from("18")
+                        }
+                    )))
+                })),
+                right: Box::new(Expression::LeftHandSideExpression(
+                    LeftHandSideExpression::Identifier(Identifier {
+                        typ: "Identifier",
+                        name: Cow::from("active"),
+                        type_annotation: None,
+                        optional: false
+                    })
+                ))
+            })
+        )
+    }
+
+    #[test]
+    #[should_panic(expected = "Unexpected token: IDENTIFIER, expected: end of input")]
+    fn rejects_trailing_tokens_after_expression() {
+        let mut parser = init();
+
+        parser.parse_expression("a b");
+    }
+
+    #[test]
+    #[should_panic(expected = "Unexpected token: ;, expected: end of input")]
+    fn rejects_semicolon_after_expression() {
+        let mut parser = init();
+
+        parser.parse_expression("a;");
+    }
+
+    #[test]
+    fn parses_standalone_statement() {
+        let mut parser = init();
+
+        let result = parser.parse_statement("let x = 1");
+
+        assert_eq!(
+            result,
+            Statement::VariableStatement(VariableStatement {
+                typ: "VariableStatement",
+                kind: "let",
+                declarations: vec![VariableDeclaration {
+                    typ: "VariableDeclaration",
+                    id: Identifier {
+                        typ: "Identifier",
+                        name: Cow::from("x"),
+                        type_annotation: None,
+                        optional: false
+                    },
+                    init: Some(Expression::Literal(Literal::NumericLiteral(
+                        NumericLiteral {
+                            typ: "NumericLiteral",
+                            value: 1.0,
+                            raw: Cow::from("1")
+                        }
+                    )))
+                }]
+            })
+        )
+    }
+
+    #[test]
+    #[should_panic(expected = "Unexpected token: IDENTIFIER, expected: end of input")]
+    fn rejects_more_than_one_statement() {
+        let mut parser = init();
+
+        parser.parse_statement("a; b;");
+    }
+
+    #[test]
+    fn parses_module() {
+        let mut parser = init();
+
+        let result = parser.parse_module("export { a };");
+
+        assert_eq!(
+            result,
+            Program {
+                typ: "Program",
+                hashbang: None,
+                body: vec![Statement::ModuleDeclaration(
+                    ModuleDeclaration::ExportNamedDeclaration(ExportNamedDeclaration {
+                        typ: "ExportNamedDeclaration",
+                        declaration: None,
+                        specifiers: vec![ExportSpecifier {
+                            typ: "ExportSpecifier",
+                            local: Identifier {
+                                typ: "Identifier",
+                                name: Cow::from("a"),
+                                type_annotation: None,
+                                optional: false
+                            },
+                            exported: Identifier {
+                                typ: "Identifier",
+                                name: Cow::from("a"),
+                                type_annotation: None,
+                                optional: false
+                            }
+                        }],
+                        source: None
+                    })
+                )]
+            }
+        )
+    }
+
+    #[test]
+    #[should_panic(expected = "'export' may only be used in a module")]
+    fn parse_module_keeps_source_type() {
+        let mut parser = init();
+        parser.parse_module("export { a };");
+
+        parser.parse("export { a };");
+    }
+
+    #[test]
+    fn parses_empty_program() {
+        let mut parser = init();
+
+        let result = parser.parse("");
+
+        assert_eq!(
+            result,
+            Program {
+                typ: "Program",
+                hashbang: None,
+                body: vec![]
+            }
+        )
+    }
+
+    #[test]
+    fn parses_comment_only_program() {
+        let mut parser = init();
+
+        let result = parser.parse(
+            "// comment
+/* another */
+",
+        );
+
+        assert_eq!(
+            result,
+            Program {
+                typ: "Program",
+                hashbang: None,
+                body: vec![]
+            }
+        )
+    }
+
+    #[test]
+    fn parses_empty_module() {
+        let mut parser = init();
+
+        let result = parser.parse_module("   ");
+
+        assert_eq!(
+            result,
+            Program {
+                typ: "Program",
+                hashbang: None,
+                body: vec![]
+            }
+        )
+    }
+}
@@ -1,6 +1,7 @@
 #[cfg(test)]
 mod test {
     use letter_rdp_rust::*;
+    use std::borrow::Cow;
     #[test]
     fn handles_equality_expression() {
         let mut parser = init();
@@ -11,24 +12,28 @@ mod test {
             result,
             Program {
                 typ: "Program",
+                hashbang: None,
                 body: vec![Statement::ExpressionStatement(ExpressionStatement {
                     typ: "ExpressionStatement",
                     expression: Expression::BinaryExpression(BinaryExpression {
                         typ: "BinaryExpression",
-                        operator: String::from("=="),
+                        operator: Cow::from("=="),
                         left: Box::new(Expression::BinaryExpression(BinaryExpression {
                             typ: "BinaryExpression",
-                            operator: String::from(">"),
+                            operator: Cow::from(">"),
                             left: Box::new(Expression::LeftHandSideExpression(
                                 LeftHandSideExpression::Identifier(Identifier {
                                     typ: "Identifier",
-                                    name: String::from("x")
+                                    name: Cow::from("x"),
+                                    type_annotation: None,
+                                    optional: false
                                 })
                             )),
                             right: Box::new(Expression::Literal(Literal::NumericLiteral(
                                 NumericLiteral {
                                     typ: "NumericLiteral",
-                                    value: 0
+                                    value: 0.0,
+                                    raw: Cow::from("0")
                                 }
                             )))
                         })),
@@ -54,24 +59,28 @@ mod test {
             result,
             Program {
                 typ: "Program",
+                hashbang: None,
                 body: vec![Statement::ExpressionStatement(ExpressionStatement {
                     typ: "ExpressionStatement",
                     expression: Expression::BinaryExpression(BinaryExpression {
                         typ: "BinaryExpression",
-                        operator: String::from("!="),
+                        operator: Cow::from("!="),
                         left: Box::new(Expression::BinaryExpression(BinaryExpression {
                             typ: "BinaryExpression",
-                            operator: String::from(">"),
+                            operator: Cow::from(">"),
                             left: Box::new(Expression::LeftHandSideExpression(
                                 LeftHandSideExpression::Identifier(Identifier {
                                     typ: "Identifier",
-                                    name: String::from("x")
+                                    name: Cow::from("x"),
+                                    type_annotation: None,
+                                    optional: false
                                 })
                             )),
                             right: Box::new(Expression::Literal(Literal::NumericLiteral(
                                 NumericLiteral {
                                     typ: "NumericLiteral",
-                                    value: 0
+                                    value: 0.0,
+                                    raw: Cow::from("0")
                                 }
                             )))
                         })),
@@ -86,4 +95,75 @@ mod test {
             }
         )
     }
+
+    #[test]
+    fn handles_strict_equality() {
+        let mut parser = init();
+
+        let result = parser.parse("a === b;");
+
+        assert_eq!(
+            result,
+            Program {
+                typ: "Program",
+                hashbang: None,
+                body: vec![Statement::ExpressionStatement(ExpressionStatement {
+                    typ: "ExpressionStatement",
+                    expression: Expression::BinaryExpression(BinaryExpression {
+                        typ: "BinaryExpression",
+                        operator: Cow::from("==="),
+                        left: Box::new(Expression::LeftHandSideExpression(
+                            LeftHandSideExpression::Identifier(Identifier {
+                                typ: "Identifier",
+                                name: Cow::from("a"),
+                                type_annotation: None,
+                                optional: false
+                            })
+                        )),
+                        right: Box::new(Expression::LeftHandSideExpression(
+                            LeftHandSideExpression::Identifier(Identifier {
+                                typ: "Identifier",
+                                name: Cow::from("b"),
+                                type_annotation: None,
+                                optional: false
+                            })
+                        ))
+                    })
+                })]
+            }
+        )
+    }
+
+    #[test]
+    fn handles_strict_inequality() {
+        let mut parser = init();
+
+        let result = parser.parse("a !== null;");
+
+        assert_eq!(
+            result,
+            Program {
+                typ: "Program",
+                hashbang: None,
+                body: vec![Statement::ExpressionStatement(ExpressionStatement {
+                    typ: "ExpressionStatement",
+                    expression: Expression::BinaryExpression(BinaryExpression {
+                        typ: "BinaryExpression",
+                        operator: Cow::from("!=="),
+                        left: Box::new(Expression::LeftHandSideExpression(
+                            LeftHandSideExpression::Identifier(Identifier {
+                                typ: "Identifier",
+                                name: Cow::from("a"),
+                                type_annotation: None,
+                                optional: false
+                            })
+                        )),
+                        right: Box::new(Expression::Literal(Literal::NullLiteral(NullLiteral {
+                            typ: "NullLiteral"
+                        })))
+                    })
+                })]
+            }
+        )
+    }
 }
@@ -0,0 +1,496 @@
+#[cfg(test)]
+mod test {
+    use letter_rdp_rust::*;
+    use std::borrow::Cow;
+    #[test]
+    fn handles_function_declaration() {
+        let mut parser = init();
+
+        let result = parser.parse("function add(a, b) { return a + b; }");
+
+        assert_eq!(
+            result,
+            Program {
+                typ: "Program",
+                hashbang: None,
+                body: vec![Statement::FunctionDeclaration(FunctionDeclaration {
+                    typ: "FunctionDeclaration",
+                    id: Identifier {
+                        typ: "Identifier",
+                        name: Cow::from("add"),
+                        type_annotation: None,
+                        optional: false
+                    },
+                    params: vec![
+                        Identifier {
+                            typ: "Identifier",
+                            name: Cow::from("a"),
+                            type_annotation: None,
+                            optional: false
+                        },
+                        Identifier {
+                            typ: "Identifier",
+                            name: Cow::from("b"),
+                            type_annotation: None,
+                            optional: false
+                        }
+                    ],
+                    body: BlockStatement {
+                        typ: "BlockStatement",
+                        body: vec![Statement::ReturnStatement(ReturnStatement {
+                            typ: "ReturnStatement",
+                            argument: Some(Expression::BinaryExpression(BinaryExpression {
+                                typ: "BinaryExpression",
+                                operator: Cow::from("+"),
+                                left: Box::new(Expression::LeftHandSideExpression(
+                                    LeftHandSideExpression::Identifier(Identifier {
+                                        typ: "Identifier",
+                                        name: Cow::from("a"),
+                                        type_annotation: None,
+                                        optional: false
+                                    })
+                                )),
+                                right: Box::new(Expression::LeftHandSideExpression(
+                                    LeftHandSideExpression::Identifier(Identifier {
+                                        typ: "Identifier",
+                                        name: Cow::from("b"),
+                                        type_annotation: None,
+                                        optional: false
+                                    })
+                                ))
+                            }))
+                        })]
+                    },
+                    is_async: false,
+                    is_generator: false,
+                    return_type: None
+                })]
+            }
+        )
+    }
+
+    #[test]
+    fn handles_empty_return() {
+        let mut parser = init();
+
+        let result = parser.parse("function f() { return; }");
+
+        assert_eq!(
+            result,
+            Program {
+                typ: "Program",
+                hashbang: None,
+                body: vec![Statement::FunctionDeclaration(FunctionDeclaration {
+                    typ: "FunctionDeclaration",
+                    id: Identifier {
+                        typ: "Identifier",
+                        name: Cow::from("f"),
+                        type_annotation: None,
+                        optional: false
+                    },
+                    params: vec![],
+                    body: BlockStatement {
+                        typ: "BlockStatement",
+                        body: vec![Statement::ReturnStatement(ReturnStatement {
+                            typ: "ReturnStatement",
+                            argument: None
+                        })]
+                    },
+                    is_async: false,
+                    is_generator: false,
+                    return_type: None
+                })]
+            }
+        )
+    }
+
+    #[test]
+    fn handles_async_function_with_await() {
+        let mut parser = init();
+
+        let result = parser.parse("async function f() { await g(); }");
+
+        assert_eq!(
+            result,
+            Program {
+                typ: "Program",
+                hashbang: None,
+                body: vec![Statement::FunctionDeclaration(FunctionDeclaration {
+                    typ: "FunctionDeclaration",
+                    id: Identifier {
+                        typ: "Identifier",
+                        name: Cow::from("f"),
+                        type_annotation: None,
+                        optional: false
+                    },
+                    params: vec![],
+                    body: BlockStatement {
+                        typ: "BlockStatement",
+                        body: vec![Statement::ExpressionStatement(ExpressionStatement {
+                            typ: "ExpressionStatement",
+                            expression: Expression::AwaitExpression(AwaitExpression {
+                                typ: "AwaitExpression",
+                                argument: Box::new(Expression::LeftHandSideExpression(
+                                    LeftHandSideExpression::CallExpression(CallExpression {
+                                        typ: "CallExpression",
+                                        callee: Box::new(Expression::LeftHandSideExpression(
+                                            LeftHandSideExpression::Identifier(Identifier {
+                                                typ: "Identifier",
+                                                name: Cow::from("g"),
+                                                type_annotation: None,
+                                                optional: false
+                                            })
+                                        )),
+                                        arguments: vec![],
+                                        optional: false
+                                    })
+                                ))
+                            })
+                        })]
+                    },
+                    is_async: true,
+                    is_generator: false,
+                    return_type: None
+                })]
+            }
+        )
+    }
+
+    #[test]
+    fn handles_generator_with_yield() {
+        let mut parser = init();
+
+        let result = parser.parse("function* gen() { yield 1; yield; yield* other(); }");
+
+        assert_eq!(
+            result,
+            Program {
+                typ: "Program",
+                hashbang: None,
+                body: vec![Statement::FunctionDeclaration(FunctionDeclaration {
+                    typ: "FunctionDeclaration",
+                    id: Identifier {
+                        typ: "Identifier",
+                        name: Cow::from("gen"),
+                        type_annotation: None,
+                        optional: false
+                    },
+                    params: vec![],
+                    body: BlockStatement {
+                        typ: "BlockStatement",
+                        body: vec![
+                            Statement::ExpressionStatement(ExpressionStatement {
+                                typ: "ExpressionStatement",
+                                expression: Expression::YieldExpression(YieldExpression {
+                                    typ: "YieldExpression",
+                                    argument: Some(Box::new(Expression::Literal(
+                                        Literal::NumericLiteral(NumericLiteral {
+                                            typ: "NumericLiteral",
+                                            value: 1.0,
+                                            raw: Cow::from("1")
+                                        })
+                                    ))),
+                                    delegate: false
+                                })
+                            }),
+                            Statement::ExpressionStatement(ExpressionStatement {
+                                typ: "ExpressionStatement",
+                                expression: Expression::YieldExpression(YieldExpression {
+                                    typ: "YieldExpression",
+                                    argument: None,
+                                    delegate: false
+                                })
+                            }),
+                            Statement::ExpressionStatement(ExpressionStatement {
+                                typ: "ExpressionStatement",
+                                expression: Expression::YieldExpression(YieldExpression {
+                                    typ: "YieldExpression",
+                                    argument: Some(Box::new(Expression::LeftHandSideExpression(
+                                        LeftHandSideExpression::CallExpression(CallExpression {
+                                            typ: "CallExpression",
+                                            callee: Box::new(Expression::LeftHandSideExpression(
+                                                LeftHandSideExpression::Identifier(Identifier {
+                                                    typ: "Identifier",
+                                                    name: Cow::from("other"),
+                                                    type_annotation: None,
+                                                    optional: false
+                                                })
+                                            )),
+                                            arguments: vec![],
+                                            optional: false
+                                        })
+                                    ))),
+                                    delegate: true
+                                })
+                            })
+                        ]
+                    },
+                    is_async: false,
+                    is_generator: true,
+                    return_type: None
+                })]
+            }
+        )
+    }
+
+    #[test]
+    fn handles_async_generator_expression() {
+        let mut parser = init();
+
+        let result = parser.parse("f = async function* () { yield await x; };");
+
+        assert_eq!(
+            result,
+            Program {
+                typ: "Program",
+                hashbang: None,
+                body: vec![Statement::ExpressionStatement(ExpressionStatement {
+                    typ: "ExpressionStatement",
+                    expression: Expression::AssignmentExpression(AssignmentExpression {
+                        typ: "AssignmentExpression",
+                        operator: Cow::from("="),
+                        left: Box::new(Expression::LeftHandSideExpression(
+                            LeftHandSideExpression::Identifier(Identifier {
+                                typ: "Identifier",
+                                name: Cow::from("f"),
+                                type_annotation: None,
+                                optional: false
+                            })
+                        )),
+                        right: Box::new(Expression::FunctionExpression(FunctionExpression {
+                            typ: "FunctionExpression",
+                            id: None,
+                            params: vec![],
+                            body: BlockStatement {
+                                typ: "BlockStatement",
+                                body: vec![Statement::ExpressionStatement(ExpressionStatement {
+                                    typ: "ExpressionStatement",
+                                    expression: Expression::YieldExpression(YieldExpression {
+                                        typ: "YieldExpression",
+                                        argument: Some(Box::new(Expression::AwaitExpression(
+                                            AwaitExpression {
+                                                typ: "AwaitExpression",
+                                                argument: Box::new(
+                                                    Expression::LeftHandSideExpression(
+                                                        LeftHandSideExpression::Identifier(
+                                                            Identifier {
+                                                                typ: "Identifier",
+                                                                name: Cow::from("x"),
+                                                                type_annotation: None,
+                                                                optional: false
+                                                            }
+                                                        )
+                                                    )
+                                                )
+                                            }
+                                        ))),
+                                        delegate: false
+                                    })
+                                })]
+                            },
+                            is_async: true,
+                            is_generator: true,
+                            return_type: None
+                        }))
+                    })
+                })]
+            }
+        )
+    }
+
+    #[test]
+    fn treats_await_and_yield_as_identifiers_outside() {
+        let mut parser = init();
+
+        let result = parser.parse("await(yield);");
+
+        assert_eq!(
+            result,
+            Program {
+                typ: "Program",
+                hashbang: None,
+                body: vec![Statement::ExpressionStatement(ExpressionStatement {
+                    typ: "ExpressionStatement",
+                    expression: Expression::LeftHandSideExpression(
+                        LeftHandSideExpression::CallExpression(CallExpression {
+                            typ: "CallExpression",
+                            callee: Box::new(Expression::LeftHandSideExpression(
+                                LeftHandSideExpression::Identifier(Identifier {
+                                    typ: "Identifier",
+                                    name: Cow::from("await"),
+                                    type_annotation: None,
+                                    optional: false
+                                })
+                            )),
+                            arguments: vec![Expression::LeftHandSideExpression(
+                                LeftHandSideExpression::Identifier(Identifier {
+                                    typ: "Identifier",
+                                    name: Cow::from("yield"),
+                                    type_annotation: None,
+                                    optional: false
+                                })
+                            )],
+                            optional: false
+                        })
+                    )
+                })]
+            }
+        )
+    }
+
+    #[test]
+    fn treats_await_as_identifier_in_plain_function_nested_in_async() {
+        let mut parser = init();
+
+        let result = parser.parse("async function f() { function g() { await; } }");
+
+        assert_eq!(
+            result,
+            Program {
+                typ: "Program",
+                hashbang: None,
+                body: vec![Statement::FunctionDeclaration(FunctionDeclaration {
+                    typ: "FunctionDeclaration",
+                    id: Identifier {
+                        typ: "Identifier",
+                        name: Cow::from("f"),
+                        type_annotation: None,
+                        optional: false
+                    },
+                    params: vec![],
+                    body: BlockStatement {
+                        typ: "BlockStatement",
+                        body: vec![Statement::FunctionDeclaration(FunctionDeclaration {
+                            typ: "FunctionDeclaration",
+                            id: Identifier {
+                                typ: "Identifier",
+                                name: Cow::from("g"),
+                                type_annotation: None,
+                                optional: false
+                            },
+                            params: vec![],
+                            body: BlockStatement {
+                                typ: "BlockStatement",
+                                body: vec![Statement::ExpressionStatement(ExpressionStatement {
+                                    typ: "ExpressionStatement",
+                                    expression: Expression::LeftHandSideExpression(
+                                        LeftHandSideExpression::Identifier(Identifier {
+                                            typ: "Identifier",
+                                            name: Cow::from("await"),
+                                            type_annotation: None,
+                                            optional: false
+                                        })
+                                    )
+                                })]
+                            },
+                            is_async: false,
+                            is_generator: false,
+                            return_type: None
+                        })]
+                    },
+                    is_async: true,
+                    is_generator: false,
+                    return_type: None
+                })]
+            }
+        )
+    }
+
+    #[test]
+    fn handles_top_level_await_in_module() {
+        let mut parser = init_with(ParserOptions {
+            source_type: SourceType::Module,
+            ..ParserOptions::default()
+        });
+
+        let result = parser.parse("await x;");
+
+        assert_eq!(
+            result,
+            Program {
+                typ: "Program",
+                hashbang: None,
+                body: vec![Statement::ExpressionStatement(ExpressionStatement {
+                    typ: "ExpressionStatement",
+                    expression: Expression::AwaitExpression(AwaitExpression {
+                        typ: "AwaitExpression",
+                        argument: Box::new(Expression::LeftHandSideExpression(
+                            LeftHandSideExpression::Identifier(Identifier {
+                                typ: "Identifier",
+                                name: Cow::from("x"),
+                                type_annotation: None,
+                                optional: false
+                            })
+                        ))
+                    })
+                })]
+            }
+        )
+    }
+
+    #[test]
+    fn treats_async_as_identifier() {
+        let mut parser = init();
+
+        let result = parser.parse("async;");
+
+        assert_eq!(
+            result,
+            Program {
+                typ: "Program",
+                hashbang: None,
+                body: vec![Statement::ExpressionStatement(ExpressionStatement {
+                    typ: "ExpressionStatement",
+                    expression: Expression::LeftHandSideExpression(
+                        LeftHandSideExpression::Identifier(Identifier {
+                            typ: "Identifier",
+                            name: Cow::from("async"),
+                            type_annotation: None,
+                            optional: false
+                        })
+                    )
+                })]
+            }
+        )
+    }
+
+    #[test]
+    #[should_panic(expected = "Cannot use 'await' as an identifier here")]
+    fn rejects_await_binding_in_async_function() {
+        let mut parser = init();
+
+        parser.parse("async function f() { let await; }");
+    }
+
+    #[test]
+    #[should_panic(expected = "Cannot use 'yield' as an identifier here")]
+    fn rejects_yield_parameter_in_generator() {
+        let mut parser = init();
+
+        parser.parse("function* g(yield) {}");
+    }
+
+    #[test]
+    #[should_panic(expected = "Unary operator used immediately before exponentiation expression")]
+    fn rejects_await_before_exponentiation() {
+        let mut parser = init();
+
+        parser.parse("async function f() { await x ** 2; }");
+    }
+
+    #[test]
+    #[should_panic(expected = "Illegal return statement")]
+    fn rejects_return_outside_function() {
+        let mut parser = init();
+
+        parser.parse("return 1;");
+    }
+
+    #[test]
+    #[should_panic(expected = "Identifier 'a' has already been declared")]
+    fn rejects_let_redeclaring_parameter() {
+        let mut parser = init();
+
+        parser.parse("function f(a) { let a; }");
+    }
+}
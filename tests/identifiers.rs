@@ -0,0 +1,308 @@
+#[cfg(test)]
+mod test {
+    use letter_rdp_rust::*;
+    use std::borrow::Cow;
+    use std::sync::Arc;
+    #[test]
+    fn handles_dollar_and_underscore() {
+        let mut parser = init();
+
+        let result = parser.parse("$; _$el;");
+
+        assert_eq!(
+            result,
+            Program {
+                typ: "Program",
+                hashbang: None,
+                body: vec![
+                    Statement::ExpressionStatement(ExpressionStatement {
+                        typ: "ExpressionStatement",
+                        expression: Expression::LeftHandSideExpression(
+                            LeftHandSideExpression::Identifier(Identifier {
+                                typ: "Identifier",
+                                name: Cow::from("$"),
+                                type_annotation: None,
+                                optional: false
+                            })
+                        )
+                    }),
+                    Statement::ExpressionStatement(ExpressionStatement {
+                        typ: "ExpressionStatement",
+                        expression: Expression::LeftHandSideExpression(
+                            LeftHandSideExpression::Identifier(Identifier {
+                                typ: "Identifier",
+                                name: Cow::from("_$el"),
+                                type_annotation: None,
+                                optional: false
+                            })
+                        )
+                    })
+                ]
+            }
+        )
+    }
+
+    #[test]
+    fn handles_unicode_identifiers() {
+        let mut parser = init();
+
+        let result = parser.parse("ünïcødé + π;");
+
+        assert_eq!(
+            result,
+            Program {
+                typ: "Program",
+                hashbang: None,
+                body: vec![Statement::ExpressionStatement(ExpressionStatement {
+                    typ: "ExpressionStatement",
+                    expression: Expression::BinaryExpression(BinaryExpression {
+                        typ: "BinaryExpression",
+                        operator: Cow::from("+"),
+                        left: Box::new(Expression::LeftHandSideExpression(
+                            LeftHandSideExpression::Identifier(Identifier {
+                                typ: "Identifier",
+                                name: Cow::from("ünïcødé"),
+                                type_annotation: None,
+                                optional: false
+                            })
+                        )),
+                        right: Box::new(Expression::LeftHandSideExpression(
+                            LeftHandSideExpression::Identifier(Identifier {
+                                typ: "Identifier",
+                                name: Cow::from("π"),
+                                type_annotation: None,
+                                optional: false
+                            })
+                        ))
+                    })
+                })]
+            }
+        )
+    }
+
+    #[test]
+    fn decodes_unicode_escapes() {
+        let mut parser = init();
+
+        let result = parser.parse(r#"abc + a\u{62};"#);
+
+        assert_eq!(
+            result,
+            Program {
+                typ: "Program",
+                hashbang: None,
+                body: vec![Statement::ExpressionStatement(ExpressionStatement {
+                    typ: "ExpressionStatement",
+                    expression: Expression::BinaryExpression(BinaryExpression {
+                        typ: "BinaryExpression",
+                        operator: Cow::from("+"),
+                        left: Box::new(Expression::LeftHandSideExpression(
+                            LeftHandSideExpression::Identifier(Identifier {
+                                typ: "Identifier",
+                                name: Cow::from("abc"),
+                                type_annotation: None,
+                                optional: false
+                            })
+                        )),
+                        right: Box::new(Expression::LeftHandSideExpression(
+                            LeftHandSideExpression::Identifier(Identifier {
+                                typ: "Identifier",
+                                name: Cow::from("ab"),
+                                type_annotation: None,
+                                optional: false
+                            })
+                        ))
+                    })
+                })]
+            }
+        )
+    }
+
+    #[test]
+    fn allows_reserved_words_as_property_names() {
+        let mut parser = init();
+
+        let result = parser.parse("a.if.default;");
+
+        assert_eq!(
+            result,
+            Program {
+                typ: "Program",
+                hashbang: None,
+                body: vec![Statement::ExpressionStatement(ExpressionStatement {
+                    typ: "ExpressionStatement",
+                    expression: Expression::LeftHandSideExpression(
+                        LeftHandSideExpression::MemberExpression(MemberExpression {
+                            typ: "MemberExpression",
+                            object: Box::new(Expression::LeftHandSideExpression(
+                                LeftHandSideExpression::MemberExpression(MemberExpression {
+                                    typ: "MemberExpression",
+                                    object: Box::new(Expression::LeftHandSideExpression(
+                                        LeftHandSideExpression::Identifier(Identifier {
+                                            typ: "Identifier",
+                                            name: Cow::from("a"),
+                                            type_annotation: None,
+                                            optional: false
+                                        })
+                                    )),
+                                    property: Box::new(Expression::LeftHandSideExpression(
+                                        LeftHandSideExpression::Identifier(Identifier {
+                                            typ: "Identifier",
+                                            name: Cow::from("if"),
+                                            type_annotation: None,
+                                            optional: false
+                                        })
+                                    )),
+                                    computed: false,
+                                    optional: false
+                                })
+                            )),
+                            property: Box::new(Expression::LeftHandSideExpression(
+                                LeftHandSideExpression::Identifier(Identifier {
+                                    typ: "Identifier",
+                                    name: Cow::from("default"),
+                                    type_annotation: None,
+                                    optional: false
+                                })
+                            )),
+                            computed: false,
+                            optional: false
+                        })
+                    )
+                })]
+            }
+        )
+    }
+
+    #[test]
+    fn allows_contextual_keywords_as_identifiers() {
+        let mut parser = init();
+
+        let result = parser.parse("async + of;");
+
+        assert_eq!(
+            result,
+            Program {
+                typ: "Program",
+                hashbang: None,
+                body: vec![Statement::ExpressionStatement(ExpressionStatement {
+                    typ: "ExpressionStatement",
+                    expression: Expression::BinaryExpression(BinaryExpression {
+                        typ: "BinaryExpression",
+                        operator: Cow::from("+"),
+                        left: Box::new(Expression::LeftHandSideExpression(
+                            LeftHandSideExpression::Identifier(Identifier {
+                                typ: "Identifier",
+                                name: Cow::from("async"),
+                                type_annotation: None,
+                                optional: false
+                            })
+                        )),
+                        right: Box::new(Expression::LeftHandSideExpression(
+                            LeftHandSideExpression::Identifier(Identifier {
+                                typ: "Identifier",
+                                name: Cow::from("of"),
+                                type_annotation: None,
+                                optional: false
+                            })
+                        ))
+                    })
+                })]
+            }
+        )
+    }
+
+    #[test]
+    #[should_panic(expected = "Unexpected reserved word 'if'")]
+    fn rejects_keyword_as_binding() {
+        let mut parser = init();
+
+        parser.parse("let if = 1;");
+    }
+
+    #[test]
+    #[should_panic(expected = "Unexpected reserved word 'rule'")]
+    fn rejects_custom_keyword_as_binding() {
+        let mut spec = TokenSpec::default();
+        spec.add_keyword("rule");
+        let mut parser = init();
+        parser.tokenizer = Tokenizer::with_spec(Arc::new(spec));
+
+        parser.parse("let rule = 1;");
+    }
+
+    #[test]
+    fn allows_custom_keyword_as_property_name() {
+        let mut spec = TokenSpec::default();
+        spec.add_keyword("rule");
+        let mut parser = init();
+        parser.tokenizer = Tokenizer::with_spec(Arc::new(spec));
+
+        let result = parser.parse("a.rule;");
+
+        assert_eq!(result, init().parse("a.rule;"))
+    }
+
+    #[test]
+    #[should_panic(expected = "Unexpected reserved word 'while'")]
+    fn rejects_reserved_word_as_binding() {
+        let mut parser = init();
+
+        parser.parse("let while = 1;");
+    }
+
+    #[test]
+    #[should_panic(expected = "Unexpected reserved word 'if'")]
+    fn rejects_escaped_reserved_word() {
+        let mut parser = init();
+
+        parser.parse(r"var \u0069f = 1;");
+    }
+
+    #[test]
+    #[should_panic(expected = "Invalid Unicode escape sequence in identifier (1:1)")]
+    fn rejects_escape_outside_identifier_chars() {
+        let mut parser = init();
+
+        parser.parse(r"a\u002d;");
+    }
+
+    #[test]
+    #[should_panic(expected = "Identifier directly after number (1:2)")]
+    fn rejects_identifier_directly_after_number() {
+        let mut parser = init();
+
+        parser.parse("2abc;");
+    }
+
+    #[test]
+    fn borrows_names_without_escapes() {
+        let mut parser = init();
+
+        let result = parser.parse_expression(r"abc + a\u{62}");
+
+        match result {
+            Expression::BinaryExpression(BinaryExpression { left, right, .. }) => {
+                assert!(matches!(
+                    *left,
+                    Expression::LeftHandSideExpression(LeftHandSideExpression::Identifier(
+                        Identifier {
+                            name: Cow::Borrowed("abc"),
+                            ..
+                        }
+                    ))
+                ));
+                assert!(matches!(
+                    *right,
+                    Expression::LeftHandSideExpression(LeftHandSideExpression::Identifier(
+                        Identifier {
+                            name: Cow::Owned(_),
+                            ..
+                        }
+                    ))
+                ));
+            }
+            _ => panic!("Expected a binary expression"),
+        }
+    }
+}
@@ -1,6 +1,7 @@
 #[cfg(test)]
 mod test {
     use letter_rdp_rust::*;
+    use std::borrow::Cow;
     #[test]
     fn handles_if_else_statement() {
         let mut parser = init();
@@ -19,27 +20,37 @@ mod test {
             result,
             Program {
                 typ: "Program",
+                hashbang: None,
                 body: vec![Statement::IfStatement(IfStatement {
                     typ: "IfStatement",
-                    test: Test::Identifier(Identifier {
-                        typ: "Identifier",
-                        name: String::from("x")
-                    }),
+                    test: Expression::LeftHandSideExpression(LeftHandSideExpression::Identifier(
+                        Identifier {
+                            typ: "Identifier",
+                            name: Cow::from("x"),
+                            type_annotation: None,
+                            optional: false
+                        }
+                    )),
                     consequent: Box::new(Statement::BlockStatement(BlockStatement {
                         typ: "BlockStatement",
                         body: vec![Statement::ExpressionStatement(ExpressionStatement {
                             typ: "ExpressionStatement",
                             expression: Expression::AssignmentExpression(AssignmentExpression {
                                 typ: "AssignmentExpression",
-                                operator: String::from("="),
-                                left: Identifier {
-                                    typ: "Identifier",
-                                    name: String::from("x")
-                                },
+                                operator: Cow::from("="),
+                                left: Box::new(Expression::LeftHandSideExpression(
+                                    LeftHandSideExpression::Identifier(Identifier {
+                                        typ: "Identifier",
+                                        name: Cow::from("x"),
+                                        type_annotation: None,
+                                        optional: false
+                                    })
+                                )),
                                 right: Box::new(Expression::Literal(Literal::NumericLiteral(
                                     NumericLiteral {
                                         typ: "NumericLiteral",
-                                        value: 1
+                                        value: 1.0,
+                                        raw: Cow::from("1")
                                     }
                                 )))
                             })
@@ -51,15 +62,20 @@ mod test {
                             typ: "ExpressionStatement",
                             expression: Expression::AssignmentExpression(AssignmentExpression {
                                 typ: "AssignmentExpression",
-                                operator: String::from("="),
-                                left: Identifier {
-                                    typ: "Identifier",
-                                    name: String::from("x")
-                                },
+                                operator: Cow::from("="),
+                                left: Box::new(Expression::LeftHandSideExpression(
+                                    LeftHandSideExpression::Identifier(Identifier {
+                                        typ: "Identifier",
+                                        name: Cow::from("x"),
+                                        type_annotation: None,
+                                        optional: false
+                                    })
+                                )),
                                 right: Box::new(Expression::Literal(Literal::NumericLiteral(
                                     NumericLiteral {
                                         typ: "NumericLiteral",
-                                        value: 2
+                                        value: 2.0,
+                                        raw: Cow::from("2")
                                     }
                                 )))
                             })
@@ -86,27 +102,37 @@ mod test {
             result,
             Program {
                 typ: "Program",
+                hashbang: None,
                 body: vec![Statement::IfStatement(IfStatement {
                     typ: "IfStatement",
-                    test: Test::Identifier(Identifier {
-                        typ: "Identifier",
-                        name: String::from("x")
-                    }),
+                    test: Expression::LeftHandSideExpression(LeftHandSideExpression::Identifier(
+                        Identifier {
+                            typ: "Identifier",
+                            name: Cow::from("x"),
+                            type_annotation: None,
+                            optional: false
+                        }
+                    )),
                     consequent: Box::new(Statement::BlockStatement(BlockStatement {
                         typ: "BlockStatement",
                         body: vec![Statement::ExpressionStatement(ExpressionStatement {
                             typ: "ExpressionStatement",
                             expression: Expression::AssignmentExpression(AssignmentExpression {
                                 typ: "AssignmentExpression",
-                                operator: String::from("="),
-                                left: Identifier {
-                                    typ: "Identifier",
-                                    name: String::from("x")
-                                },
+                                operator: Cow::from("="),
+                                left: Box::new(Expression::LeftHandSideExpression(
+                                    LeftHandSideExpression::Identifier(Identifier {
+                                        typ: "Identifier",
+                                        name: Cow::from("x"),
+                                        type_annotation: None,
+                                        optional: false
+                                    })
+                                )),
                                 right: Box::new(Expression::Literal(Literal::NumericLiteral(
                                     NumericLiteral {
                                         typ: "NumericLiteral",
-                                        value: 1
+                                        value: 1.0,
+                                        raw: Cow::from("1")
                                     }
                                 )))
                             })
@@ -132,25 +158,35 @@ mod test {
             result,
             Program {
                 typ: "Program",
+                hashbang: None,
                 body: vec![Statement::IfStatement(IfStatement {
                     typ: "IfStatement",
-                    test: Test::Identifier(Identifier {
-                        typ: "Identifier",
-                        name: String::from("x")
-                    }),
+                    test: Expression::LeftHandSideExpression(LeftHandSideExpression::Identifier(
+                        Identifier {
+                            typ: "Identifier",
+                            name: Cow::from("x"),
+                            type_annotation: None,
+                            optional: false
+                        }
+                    )),
                     consequent: Box::new(Statement::ExpressionStatement(ExpressionStatement {
                         typ: "ExpressionStatement",
                         expression: Expression::AssignmentExpression(AssignmentExpression {
                             typ: "AssignmentExpression",
-                            operator: String::from("="),
-                            left: Identifier {
-                                typ: "Identifier",
-                                name: String::from("x")
-                            },
+                            operator: Cow::from("="),
+                            left: Box::new(Expression::LeftHandSideExpression(
+                                LeftHandSideExpression::Identifier(Identifier {
+                                    typ: "Identifier",
+                                    name: Cow::from("x"),
+                                    type_annotation: None,
+                                    optional: false
+                                })
+                            )),
                             right: Box::new(Expression::Literal(Literal::NumericLiteral(
                                 NumericLiteral {
                                     typ: "NumericLiteral",
-                                    value: 1
+                                    value: 1.0,
+                                    raw: Cow::from("1")
                                 }
                             )))
                         })
@@ -175,18 +211,27 @@ mod test {
             result,
             Program {
                 typ: "Program",
+                hashbang: None,
                 body: vec![Statement::IfStatement(IfStatement {
                     typ: "IfStatement",
-                    test: Test::Identifier(Identifier {
-                        typ: "Identifier",
-                        name: String::from("x")
-                    }),
+                    test: Expression::LeftHandSideExpression(LeftHandSideExpression::Identifier(
+                        Identifier {
+                            typ: "Identifier",
+                            name: Cow::from("x"),
+                            type_annotation: None,
+                            optional: false
+                        }
+                    )),
                     consequent: Box::new(Statement::IfStatement(IfStatement {
                         typ: "IfStatement",
-                        test: Test::Identifier(Identifier {
-                            typ: "Identifier",
-                            name: String::from("y")
-                        }),
+                        test: Expression::LeftHandSideExpression(
+                            LeftHandSideExpression::Identifier(Identifier {
+                                typ: "Identifier",
+                                name: Cow::from("y"),
+                                type_annotation: None,
+                                optional: false
+                            })
+                        ),
                         consequent: Box::new(Statement::BlockStatement(BlockStatement {
                             typ: "BlockStatement",
                             body: vec![]
@@ -201,4 +246,187 @@ mod test {
             }
         )
     }
+
+    #[test]
+    fn handles_identifier_comparison_test() {
+        let mut parser = init();
+
+        let result = parser.parse("if (x > 1) {}");
+
+        assert_eq!(
+            result,
+            Program {
+                typ: "Program",
+                hashbang: None,
+                body: vec![Statement::IfStatement(IfStatement {
+                    typ: "IfStatement",
+                    test: Expression::BinaryExpression(BinaryExpression {
+                        typ: "BinaryExpression",
+                        operator: Cow::from(">"),
+                        left: Box::new(Expression::LeftHandSideExpression(
+                            LeftHandSideExpression::Identifier(Identifier {
+                                typ: "Identifier",
+                                name: Cow::from("x"),
+                                type_annotation: None,
+                                optional: false
+                            })
+                        )),
+                        right: Box::new(Expression::Literal(Literal::NumericLiteral(
+                            NumericLiteral {
+                                typ: "NumericLiteral",
+                                value: 1.0,
+                                raw: Cow::from("1")
+                            }
+                        )))
+                    }),
+                    consequent: Box::new(Statement::BlockStatement(BlockStatement {
+                        typ: "BlockStatement",
+                        body: vec![]
+                    })),
+                    alternate: None
+                })]
+            }
+        )
+    }
+
+    #[test]
+    fn handles_logical_test() {
+        let mut parser = init();
+
+        let result = parser.parse("if (a && b) {}");
+
+        assert_eq!(
+            result,
+            Program {
+                typ: "Program",
+                hashbang: None,
+                body: vec![Statement::IfStatement(IfStatement {
+                    typ: "IfStatement",
+                    test: Expression::LogicalExpression(LogicalExpression {
+                        typ: "LogicalExpression",
+                        operator: Cow::from("&&"),
+                        left: Box::new(Expression::LeftHandSideExpression(
+                            LeftHandSideExpression::Identifier(Identifier {
+                                typ: "Identifier",
+                                name: Cow::from("a"),
+                                type_annotation: None,
+                                optional: false
+                            })
+                        )),
+                        right: Box::new(Expression::LeftHandSideExpression(
+                            LeftHandSideExpression::Identifier(Identifier {
+                                typ: "Identifier",
+                                name: Cow::from("b"),
+                                type_annotation: None,
+                                optional: false
+                            })
+                        ))
+                    }),
+                    consequent: Box::new(Statement::BlockStatement(BlockStatement {
+                        typ: "BlockStatement",
+                        body: vec![]
+                    })),
+                    alternate: None
+                })]
+            }
+        )
+    }
+
+    #[test]
+    fn handles_nested_test() {
+        let mut parser = init();
+
+        let result = parser.parse("if ((a || b) && !c(d.e)) {}");
+
+        assert_eq!(
+            result,
+            Program {
+                typ: "Program",
+                hashbang: None,
+                body: vec![Statement::IfStatement(IfStatement {
+                    typ: "IfStatement",
+                    test: Expression::LogicalExpression(LogicalExpression {
+                        typ: "LogicalExpression",
+                        operator: Cow::from("&&"),
+                        left: Box::new(Expression::LogicalExpression(LogicalExpression {
+                            typ: "LogicalExpression",
+                            operator: Cow::from("||"),
+                            left: Box::new(Expression::LeftHandSideExpression(
+                                LeftHandSideExpression::Identifier(Identifier {
+                                    typ: "Identifier",
+                                    name: Cow::from("a"),
+                                    type_annotation: None,
+                                    optional: false
+                                })
+                            )),
+                            right: Box::new(Expression::LeftHandSideExpression(
+                                LeftHandSideExpression::Identifier(Identifier {
+                                    typ: "Identifier",
+                                    name: Cow::from("b"),
+                                    type_annotation: None,
+                                    optional: false
+                                })
+                            ))
+                        })),
+                        right: Box::new(Expression::UnaryExpression(UnaryExpression {
+                            typ: "UnaryExpression",
+                            operator: Cow::from("!"),
+                            argument: Box::new(Expression::LeftHandSideExpression(
+                                LeftHandSideExpression::CallExpression(CallExpression {
+                                    typ: "CallExpression",
+                                    callee: Box::new(Expression::LeftHandSideExpression(
+                                        LeftHandSideExpression::Identifier(Identifier {
+                                            typ: "Identifier",
+                                            name: Cow::from("c"),
+                                            type_annotation: None,
+                                            optional: false
+                                        })
+                                    )),
+                                    arguments: vec![Expression::LeftHandSideExpression(
+                                        LeftHandSideExpression::MemberExpression(
+                                            MemberExpression {
+                                                typ: "MemberExpression",
+                                                object: Box::new(
+                                                    Expression::LeftHandSideExpression(
+                                                        LeftHandSideExpression::Identifier(
+                                                            Identifier {
+                                                                typ: "Identifier",
+                                                                name: Cow::from("d"),
+                                                                type_annotation: None,
+                                                                optional: false
+                                                            }
+                                                        )
+                                                    )
+                                                ),
+                                                property: Box::new(
+                                                    Expression::LeftHandSideExpression(
+                                                        LeftHandSideExpression::Identifier(
+                                                            Identifier {
+                                                                typ: "Identifier",
+                                                                name: Cow::from("e"),
+                                                                type_annotation: None,
+                                                                optional: false
+                                                            }
+                                                        )
+                                                    )
+                                                ),
+                                                computed: false,
+                                                optional: false
+                                            }
+                                        )
+                                    )],
+                                    optional: false
+                                })
+                            ))
+                        }))
+                    }),
+                    consequent: Box::new(Statement::BlockStatement(BlockStatement {
+                        typ: "BlockStatement",
+                        body: vec![]
+                    })),
+                    alternate: None
+                })]
+            }
+        )
+    }
 }
@@ -0,0 +1,149 @@
+#[cfg(test)]
+mod test {
+    use letter_rdp_rust::arena::*;
+    use letter_rdp_rust::*;
+    use std::sync::{Arc, Mutex};
+
+    #[test]
+    fn interns_each_string_once() {
+        let mut interner = Interner::new();
+
+        let first = interner.intern("name");
+        let other = interner.intern("other");
+        let second = interner.intern("name");
+
+        assert_eq!(first, second);
+        assert_ne!(first, other);
+        assert_eq!(interner.len(), 2);
+        assert_eq!(interner.resolve(other), "other");
+        assert_eq!(interner.get("name"), Some(first));
+        assert_eq!(interner.get("missing"), None)
+    }
+
+    #[test]
+    fn interns_names_and_strings_of_an_ast() {
+        let mut parser = init();
+        let mut ast = Ast::default();
+
+        let root = parser.parse_into("f('f', \"\\x66\");", &mut ast);
+
+        let body = match ast.node(root) {
+            Node::Program { body, .. } => *body,
+            node => panic!("Unexpected node: {:?}", node),
+        };
+        let call = match ast.node(ast.list(body)[0]) {
+            Node::ExpressionStatement { expression } => *expression,
+            node => panic!("Unexpected node: {:?}", node),
+        };
+        let (callee, arguments) = match ast.node(call) {
+            Node::CallExpression {
+                callee, arguments, ..
+            } => (*callee, ast.list(*arguments)),
+            node => panic!("Unexpected node: {:?}", node),
+        };
+        let name = match ast.node(callee) {
+            Node::Identifier { name, .. } => *name,
+            node => panic!("Unexpected node: {:?}", node),
+        };
+        let values: Vec<Symbol> = arguments
+            .iter()
+            .map(|id| match ast.node(*id) {
+                Node::StringLiteral { value, .. } => *value,
+                node => panic!("Unexpected node: {:?}", node),
+            })
+            .collect();
+
+        assert_eq!(values, vec![name, name]);
+        assert_eq!(&*ast.resolve(name), "f");
+    }
+
+    #[test]
+    fn shares_the_interner_of_the_parser_across_parses() {
+        let mut parser = init();
+        let mut first = Ast::default();
+        let mut second = Ast::default();
+
+        parser.parse_into("total;", &mut first);
+        let root = parser.parse_into("count; total;", &mut second);
+
+        assert!(Arc::ptr_eq(first.interner(), second.interner()));
+        assert!(Arc::ptr_eq(first.interner(), parser.interner()));
+        assert_eq!(second.interner().lock().unwrap().len(), 2);
+        assert_eq!(second.to_program(), parser.parse("count; total;"));
+        assert_eq!(root, second.root())
+    }
+
+    #[test]
+    fn shares_an_interner_between_parsers() {
+        let interner = Arc::new(Mutex::new(Interner::new()));
+        let mut first = init();
+        first.set_interner(Arc::clone(&interner));
+        let mut second = init();
+        second.set_interner(Arc::clone(&interner));
+        let (mut a, mut b) = (Ast::default(), Ast::default());
+
+        first.parse_into("name;", &mut a);
+        second.parse_into("name;", &mut b);
+
+        assert!(Arc::ptr_eq(a.interner(), &interner));
+        assert!(Arc::ptr_eq(b.interner(), &interner));
+        assert_eq!(interner.lock().unwrap().len(), 1);
+    }
+
+    #[test]
+    fn shares_an_interner_between_threads() {
+        let interner = Arc::new(Mutex::new(Interner::new()));
+        let handles: Vec<_> = ["count; total;", "total;"]
+            .into_iter()
+            .map(|string| {
+                let interner = Arc::clone(&interner);
+                std::thread::spawn(move || {
+                    let mut parser = init();
+                    parser.set_interner(interner);
+                    let mut ast = Ast::default();
+                    parser.parse_into(string, &mut ast);
+                })
+            })
+            .collect();
+        for handle in handles {
+            handle.join().unwrap();
+        }
+
+        assert_eq!(interner.lock().unwrap().len(), 2);
+    }
+
+    #[test]
+    fn does_not_grow_the_interner_on_owned_parses() {
+        let statements: Vec<String> = (0..100)
+            .map(|i| format!("let name{} = 'value{}';", i, i))
+            .collect();
+        let expressions: Vec<String> = (0..100).map(|i| format!("a{}", i)).collect();
+        let mut parser = init();
+        let mut ast = Ast::default();
+        parser.parse_into("total;", &mut ast);
+
+        for (statement, expression) in statements.iter().zip(&expressions) {
+            assert_eq!(parser.parse(statement).body.len(), 1);
+            assert!(parser.try_parse_expression(expression).is_ok());
+        }
+
+        assert_eq!(parser.interner().lock().unwrap().len(), 1);
+        assert_eq!(
+            parser.parse("let \\u0061 = '\\x62';"),
+            init().parse("let \\u0061 = '\\x62';")
+        )
+    }
+
+    #[test]
+    fn clears_an_interner() {
+        let mut interner = Interner::new();
+        interner.intern("name");
+
+        interner.clear();
+
+        assert!(interner.is_empty());
+        assert_eq!(interner.get("name"), None);
+        let other = interner.intern("other");
+        assert_eq!(interner.resolve(other), "other")
+    }
+}
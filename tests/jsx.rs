@@ -0,0 +1,327 @@
+#[cfg(test)]
+mod test {
+    use letter_rdp_rust::*;
+    use std::borrow::Cow;
+    #[test]
+    fn handles_element_with_attributes_and_children() {
+        let mut parser = init_with(ParserOptions {
+            jsx: true,
+            ..ParserOptions::default()
+        });
+
+        let result = parser.parse("<Foo bar={x} {...rest}>text {expr}</Foo>;");
+
+        let attributes = vec![
+            JSXAttributeKind::JSXAttribute(JSXAttribute {
+                typ: "JSXAttribute",
+                name: JSXIdentifier {
+                    typ: "JSXIdentifier",
+                    name: Cow::from("bar"),
+                },
+                value: Some(JSXAttributeValue::JSXExpressionContainer(
+                    JSXExpressionContainer {
+                        typ: "JSXExpressionContainer",
+                        expression: Some(Box::new(Expression::LeftHandSideExpression(
+                            LeftHandSideExpression::Identifier(Identifier {
+                                typ: "Identifier",
+                                name: Cow::from("x"),
+                                type_annotation: None,
+                                optional: false,
+                            }),
+                        ))),
+                    },
+                )),
+            }),
+            JSXAttributeKind::JSXSpreadAttribute(JSXSpreadAttribute {
+                typ: "JSXSpreadAttribute",
+                argument: Expression::LeftHandSideExpression(LeftHandSideExpression::Identifier(
+                    Identifier {
+                        typ: "Identifier",
+                        name: Cow::from("rest"),
+                        type_annotation: None,
+                        optional: false,
+                    },
+                )),
+            }),
+        ];
+        let children = vec![
+            JSXChild::JSXText(JSXText {
+                typ: "JSXText",
+                value: Cow::from("text "),
+            }),
+            JSXChild::JSXExpressionContainer(JSXExpressionContainer {
+                typ: "JSXExpressionContainer",
+                expression: Some(Box::new(Expression::LeftHandSideExpression(
+                    LeftHandSideExpression::Identifier(Identifier {
+                        typ: "Identifier",
+                        name: Cow::from("expr"),
+                        type_annotation: None,
+                        optional: false,
+                    }),
+                ))),
+            }),
+        ];
+
+        assert_eq!(
+            result,
+            Program {
+                typ: "Program",
+                hashbang: None,
+                body: vec![Statement::ExpressionStatement(ExpressionStatement {
+                    typ: "ExpressionStatement",
+                    expression: Expression::JSXElement(JSXElement {
+                        typ: "JSXElement",
+                        name: JSXElementName::JSXIdentifier(JSXIdentifier {
+                            typ: "JSXIdentifier",
+                            name: Cow::from("Foo")
+                        }),
+                        attributes,
+                        children,
+                        self_closing: false
+                    })
+                })]
+            }
+        )
+    }
+
+    #[test]
+    fn handles_fragments() {
+        let mut parser = init_with(ParserOptions {
+            jsx: true,
+            ..ParserOptions::default()
+        });
+
+        let result = parser.parse("<><br /></>;");
+
+        assert_eq!(
+            result,
+            Program {
+                typ: "Program",
+                hashbang: None,
+                body: vec![Statement::ExpressionStatement(ExpressionStatement {
+                    typ: "ExpressionStatement",
+                    expression: Expression::JSXFragment(JSXFragment {
+                        typ: "JSXFragment",
+                        children: vec![JSXChild::JSXElement(JSXElement {
+                            typ: "JSXElement",
+                            name: JSXElementName::JSXIdentifier(JSXIdentifier {
+                                typ: "JSXIdentifier",
+                                name: Cow::from("br")
+                            }),
+                            attributes: vec![],
+                            children: vec![],
+                            self_closing: true
+                        })]
+                    })
+                })]
+            }
+        )
+    }
+
+    #[test]
+    fn handles_member_names_and_dashed_attributes() {
+        let mut parser = init_with(ParserOptions {
+            jsx: true,
+            ..ParserOptions::default()
+        });
+
+        let result = parser.parse(r#"<Foo.Bar data-id="a-b" disabled />;"#);
+
+        assert_eq!(
+            result,
+            Program {
+                typ: "Program",
+                hashbang: None,
+                body: vec![Statement::ExpressionStatement(ExpressionStatement {
+                    typ: "ExpressionStatement",
+                    expression: Expression::JSXElement(JSXElement {
+                        typ: "JSXElement",
+                        name: JSXElementName::JSXMemberExpression(JSXMemberExpression {
+                            typ: "JSXMemberExpression",
+                            object: Box::new(JSXElementName::JSXIdentifier(JSXIdentifier {
+                                typ: "JSXIdentifier",
+                                name: Cow::from("Foo")
+                            })),
+                            property: JSXIdentifier {
+                                typ: "JSXIdentifier",
+                                name: Cow::from("Bar")
+                            }
+                        }),
+                        attributes: vec![
+                            JSXAttributeKind::JSXAttribute(JSXAttribute {
+                                typ: "JSXAttribute",
+                                name: JSXIdentifier {
+                                    typ: "JSXIdentifier",
+                                    name: Cow::from("data-id")
+                                },
+                                value: Some(JSXAttributeValue::StringLiteral(StringLiteral {
+                                    typ: "StringLiteral",
+                                    value: Cow::from("a-b"),
+                                    raw: Cow::from("\"a-b\"")
+                                }))
+                            }),
+                            JSXAttributeKind::JSXAttribute(JSXAttribute {
+                                typ: "JSXAttribute",
+                                name: JSXIdentifier {
+                                    typ: "JSXIdentifier",
+                                    name: Cow::from("disabled")
+                                },
+                                value: None
+                            })
+                        ],
+                        children: vec![],
+                        self_closing: true
+                    })
+                })]
+            }
+        )
+    }
+
+    #[test]
+    fn reads_children_as_text() {
+        let mut parser = init_with(ParserOptions {
+            jsx: true,
+            ..ParserOptions::default()
+        });
+
+        let result = parser.parse(
+            "<p>it's // {/* empty */}</p>
+next;",
+        );
+
+        assert_eq!(
+            result,
+            Program {
+                typ: "Program",
+                hashbang: None,
+                body: vec![
+                    Statement::ExpressionStatement(ExpressionStatement {
+                        typ: "ExpressionStatement",
+                        expression: Expression::JSXElement(JSXElement {
+                            typ: "JSXElement",
+                            name: JSXElementName::JSXIdentifier(JSXIdentifier {
+                                typ: "JSXIdentifier",
+                                name: Cow::from("p")
+                            }),
+                            attributes: vec![],
+                            children: vec![
+                                JSXChild::JSXText(JSXText {
+                                    typ: "JSXText",
+                                    value: Cow::from("it's // ")
+                                }),
+                                JSXChild::JSXExpressionContainer(JSXExpressionContainer {
+                                    typ: "JSXExpressionContainer",
+                                    expression: None
+                                })
+                            ],
+                            self_closing: false
+                        })
+                    }),
+                    Statement::ExpressionStatement(ExpressionStatement {
+                        typ: "ExpressionStatement",
+                        expression: Expression::LeftHandSideExpression(
+                            LeftHandSideExpression::Identifier(Identifier {
+                                typ: "Identifier",
+                                name: Cow::from("next"),
+                                type_annotation: None,
+                                optional: false
+                            })
+                        )
+                    })
+                ]
+            }
+        )
+    }
+
+    #[test]
+    fn keeps_less_than_operator() {
+        let mut parser = init_with(ParserOptions {
+            jsx: true,
+            ..ParserOptions::default()
+        });
+
+        let result = parser.parse("a < b;");
+
+        assert_eq!(
+            result,
+            Program {
+                typ: "Program",
+                hashbang: None,
+                body: vec![Statement::ExpressionStatement(ExpressionStatement {
+                    typ: "ExpressionStatement",
+                    expression: Expression::BinaryExpression(BinaryExpression {
+                        typ: "BinaryExpression",
+                        operator: Cow::from("<"),
+                        left: Box::new(Expression::LeftHandSideExpression(
+                            LeftHandSideExpression::Identifier(Identifier {
+                                typ: "Identifier",
+                                name: Cow::from("a"),
+                                type_annotation: None,
+                                optional: false
+                            })
+                        )),
+                        right: Box::new(Expression::LeftHandSideExpression(
+                            LeftHandSideExpression::Identifier(Identifier {
+                                typ: "Identifier",
+                                name: Cow::from("b"),
+                                type_annotation: None,
+                                optional: false
+                            })
+                        ))
+                    })
+                })]
+            }
+        )
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "Expected corresponding JSX closing tag for <Foo> (1:1), found </Bar> (2:3)"
+    )]
+    fn rejects_mismatched_closing_tag() {
+        let mut parser = init_with(ParserOptions {
+            jsx: true,
+            ..ParserOptions::default()
+        });
+
+        parser.parse(
+            "<Foo>
+  </Bar>;",
+        );
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "Expected corresponding JSX closing tag for <> (1:1), found </div> (2:1)"
+    )]
+    fn rejects_unclosed_fragment() {
+        let mut parser = init_with(ParserOptions {
+            jsx: true,
+            ..ParserOptions::default()
+        });
+
+        parser.parse(
+            "<>
+</div>",
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Unterminated JSX contents (1:1)")]
+    fn rejects_unterminated_contents() {
+        let mut parser = init_with(ParserOptions {
+            jsx: true,
+            ..ParserOptions::default()
+        });
+
+        parser.parse("<a>text");
+    }
+
+    #[test]
+    #[should_panic(expected = "Unexpected token: RELATIONAL_OPERATOR, expected: IDENTIFIER")]
+    fn requires_jsx_option() {
+        let mut parser = init();
+
+        parser.parse("<a />;");
+    }
+}
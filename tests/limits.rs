@@ -0,0 +1,292 @@
+#[cfg(test)]
+mod test {
+    use letter_rdp_rust::*;
+
+    fn with_limits<'a>(
+        limits: ParserLimits,
+    ) -> impl FnMut(&'a str) -> Result<Program<'a>, ParseError> {
+        let mut parser = init_with(ParserOptions {
+            limits,
+            ..ParserOptions::default()
+        });
+        move |string| parser.try_parse(string)
+    }
+
+    #[test]
+    fn rejects_deep_nesting_without_overflowing_the_stack() {
+        let source = format!("{}1{};", "(".repeat(100_000), ")".repeat(100_000));
+        let mut parse = with_limits(ParserLimits {
+            max_depth: 64,
+            ..ParserLimits::default()
+        });
+
+        let result = parse(&source);
+
+        assert_eq!(
+            result,
+            Err(ParseError::LimitExceeded {
+                limit: Limit::Depth,
+                max: 64,
+                offset: 63
+            })
+        )
+    }
+
+    #[test]
+    fn rejects_deep_nesting_by_default() {
+        let source = format!("{}1{};", "(".repeat(100_000), ")".repeat(100_000));
+        let mut parser = init();
+
+        let result = parser.try_parse(&source);
+
+        assert!(matches!(
+            result,
+            Err(ParseError::LimitExceeded {
+                limit: Limit::Depth,
+                max: 128,
+                ..
+            })
+        ))
+    }
+
+    #[test]
+    fn rejects_deeply_nested_blocks() {
+        let source = "{".repeat(100_000);
+        let mut parser = init();
+
+        let result = parser.try_parse(&source);
+
+        assert_eq!(
+            result,
+            Err(ParseError::LimitExceeded {
+                limit: Limit::Depth,
+                max: 128,
+                offset: 128
+            })
+        )
+    }
+
+    #[test]
+    fn rejects_long_unary_chains() {
+        let source = format!("{}a;", "!".repeat(100_000));
+        let mut parse = with_limits(ParserLimits {
+            max_depth: 128,
+            ..ParserLimits::default()
+        });
+
+        let result = parse(&source);
+
+        assert!(matches!(
+            result,
+            Err(ParseError::LimitExceeded {
+                limit: Limit::Depth,
+                ..
+            })
+        ))
+    }
+
+    #[test]
+    fn rejects_long_binary_chains() {
+        let source = format!("{}1;", "1+".repeat(100_000));
+        let mut parse = with_limits(ParserLimits {
+            max_depth: 128,
+            ..ParserLimits::default()
+        });
+
+        let result = parse(&source);
+
+        assert!(matches!(
+            result,
+            Err(ParseError::LimitExceeded {
+                limit: Limit::Depth,
+                ..
+            })
+        ))
+    }
+
+    #[test]
+    fn rejects_long_member_chains() {
+        let source = format!("a{};", ".b".repeat(100_000));
+        let mut parse = with_limits(ParserLimits {
+            max_depth: 128,
+            ..ParserLimits::default()
+        });
+
+        let result = parse(&source);
+
+        assert!(matches!(
+            result,
+            Err(ParseError::LimitExceeded {
+                limit: Limit::Depth,
+                ..
+            })
+        ))
+    }
+
+    #[test]
+    fn parses_chains_up_to_the_max_depth() {
+        let mut parse = with_limits(ParserLimits {
+            max_depth: 5,
+            ..ParserLimits::default()
+        });
+
+        assert!(parse("a + b + c;").is_ok());
+        assert!(parse("a.b.c;").is_ok());
+        assert!(matches!(
+            parse("a + b + c + d + e;"),
+            Err(ParseError::LimitExceeded {
+                limit: Limit::Depth,
+                ..
+            })
+        ))
+    }
+
+    #[test]
+    fn limits_only_nesting_by_default() {
+        let limits = ParserLimits::default();
+
+        assert_eq!(limits.max_depth, 128);
+        assert_eq!(limits.max_tokens, usize::MAX);
+        assert_eq!(limits.max_input_bytes, usize::MAX);
+        assert_eq!(limits.max_string_length, usize::MAX)
+    }
+
+    #[test]
+    fn parses_nesting_up_to_the_max_depth() {
+        let mut parse = with_limits(ParserLimits {
+            max_depth: 3,
+            ..ParserLimits::default()
+        });
+
+        assert!(parse("(1);").is_ok());
+        assert_eq!(
+            parse("((1));"),
+            Err(ParseError::LimitExceeded {
+                limit: Limit::Depth,
+                max: 3,
+                offset: 2
+            })
+        )
+    }
+
+    #[test]
+    fn limits_the_number_of_tokens() {
+        let mut parse = with_limits(ParserLimits {
+            max_tokens: 4,
+            ..ParserLimits::default()
+        });
+
+        assert!(parse("a + b;").is_ok());
+        assert_eq!(
+            parse("a + b + c;"),
+            Err(ParseError::LimitExceeded {
+                limit: Limit::Tokens,
+                max: 4,
+                offset: 8
+            })
+        )
+    }
+
+    #[test]
+    fn limits_the_input_size() {
+        let mut parse = with_limits(ParserLimits {
+            max_input_bytes: 8,
+            ..ParserLimits::default()
+        });
+
+        assert!(parse("let a;").is_ok());
+        assert_eq!(
+            parse("let abc = 1;"),
+            Err(ParseError::LimitExceeded {
+                limit: Limit::InputBytes,
+                max: 8,
+                offset: 8
+            })
+        )
+    }
+
+    #[test]
+    fn limits_the_length_of_string_literals() {
+        let mut parse = with_limits(ParserLimits {
+            max_string_length: 3,
+            ..ParserLimits::default()
+        });
+
+        assert!(parse(r#"a = "abc";"#).is_ok());
+        assert_eq!(
+            parse(r#"a = "abcd";"#),
+            Err(ParseError::LimitExceeded {
+                limit: Limit::StringLength,
+                max: 3,
+                offset: 4
+            })
+        )
+    }
+
+    #[test]
+    fn returns_syntax_errors() {
+        let mut parser = init();
+
+        let result = parser.try_parse("let;");
+
+        assert_eq!(
+            result,
+            Err(ParseError::SyntaxError {
+                message: String::from("Unexpected token: ;, expected: IDENTIFIER")
+            })
+        )
+    }
+
+    #[test]
+    fn returns_errors_from_the_other_entry_points() {
+        let source = format!("{}a", "!".repeat(1_000));
+        let mut parser = init();
+
+        assert!(matches!(
+            parser.try_parse_expression(&source),
+            Err(ParseError::LimitExceeded {
+                limit: Limit::Depth,
+                ..
+            })
+        ));
+        assert_eq!(
+            parser.try_parse_statement("let;"),
+            Err(ParseError::SyntaxError {
+                message: String::from("Unexpected token: ;, expected: IDENTIFIER")
+            })
+        );
+        assert!(matches!(
+            parser.try_parse_module(&format!("{};", source)),
+            Err(ParseError::LimitExceeded {
+                limit: Limit::Depth,
+                ..
+            })
+        ))
+    }
+
+    #[test]
+    fn parses_again_after_an_error() {
+        let mut parser = init();
+        let source = "{".repeat(1_000);
+
+        assert!(parser.try_parse(&source).is_err());
+        assert_eq!(
+            parser.try_parse("a;").map(|program| program.body.len()),
+            Ok(1)
+        )
+    }
+
+    #[test]
+    fn describes_exceeded_limits() {
+        let error = ParseError::LimitExceeded {
+            limit: Limit::Depth,
+            max: 128,
+            offset: 127,
+        };
+
+        assert_eq!(
+            error.to_string(),
+            "Maximum nesting depth of 128 exceeded at offset 127"
+        )
+    }
+}
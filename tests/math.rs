@@ -1,6 +1,7 @@
 #[cfg(test)]
 mod test {
     use letter_rdp_rust::*;
+    use std::borrow::Cow;
     #[test]
     fn binary_expression() {
         let mut parser = init();
@@ -11,21 +12,24 @@ mod test {
             result,
             Program {
                 typ: "Program",
+                hashbang: None,
                 body: vec![Statement::ExpressionStatement(ExpressionStatement {
                     typ: "ExpressionStatement",
                     expression: Expression::BinaryExpression(BinaryExpression {
                         typ: "BinaryExpression",
-                        operator: String::from("+"),
+                        operator: Cow::from("+"),
                         left: Box::new(Expression::Literal(Literal::NumericLiteral(
                             NumericLiteral {
                                 typ: "NumericLiteral",
-                                value: 2
+                                value: 2.0,
+                                raw: Cow::from("2")
                             }
                         ))),
                         right: Box::new(Expression::Literal(Literal::NumericLiteral(
                             NumericLiteral {
                                 typ: "NumericLiteral",
-                                value: 2
+                                value: 2.0,
+                                raw: Cow::from("2")
                             }
                         )))
                     })
@@ -44,31 +48,35 @@ mod test {
             result,
             Program {
                 typ: "Program",
+                hashbang: None,
                 body: vec![Statement::ExpressionStatement(ExpressionStatement {
                     typ: "ExpressionStatement",
                     expression: Expression::BinaryExpression(BinaryExpression {
                         typ: "BinaryExpression",
-                        operator: String::from("-"),
+                        operator: Cow::from("-"),
                         left: Box::new(Expression::BinaryExpression(BinaryExpression {
                             typ: "BinaryExpression",
-                            operator: String::from("+"),
+                            operator: Cow::from("+"),
                             left: Box::new(Expression::Literal(Literal::NumericLiteral(
                                 NumericLiteral {
                                     typ: "NumericLiteral",
-                                    value: 3
+                                    value: 3.0,
+                                    raw: Cow::from("3")
                                 }
                             ))),
                             right: Box::new(Expression::Literal(Literal::NumericLiteral(
                                 NumericLiteral {
                                     typ: "NumericLiteral",
-                                    value: 2
+                                    value: 2.0,
+                                    raw: Cow::from("2")
                                 }
                             )))
                         })),
                         right: Box::new(Expression::Literal(Literal::NumericLiteral(
                             NumericLiteral {
                                 typ: "NumericLiteral",
-                                value: 2
+                                value: 2.0,
+                                raw: Cow::from("2")
                             }
                         )))
                     })
@@ -87,21 +95,24 @@ mod test {
             result,
             Program {
                 typ: "Program",
+                hashbang: None,
                 body: vec![Statement::ExpressionStatement(ExpressionStatement {
                     typ: "ExpressionStatement",
                     expression: Expression::BinaryExpression(BinaryExpression {
                         typ: "BinaryExpression",
-                        operator: String::from("*"),
+                        operator: Cow::from("*"),
                         right: Box::new(Expression::Literal(Literal::NumericLiteral(
                             NumericLiteral {
                                 typ: "NumericLiteral",
-                                value: 2
+                                value: 2.0,
+                                raw: Cow::from("2")
                             }
                         ))),
                         left: Box::new(Expression::Literal(Literal::NumericLiteral(
                             NumericLiteral {
                                 typ: "NumericLiteral",
-                                value: 2
+                                value: 2.0,
+                                raw: Cow::from("2")
                             }
                         )))
                     })
@@ -120,31 +131,35 @@ mod test {
             result,
             Program {
                 typ: "Program",
+                hashbang: None,
                 body: vec![Statement::ExpressionStatement(ExpressionStatement {
                     typ: "ExpressionStatement",
                     expression: Expression::BinaryExpression(BinaryExpression {
                         typ: "BinaryExpression",
-                        operator: String::from("+"),
+                        operator: Cow::from("+"),
                         right: Box::new(Expression::BinaryExpression(BinaryExpression {
                             typ: "BinaryExpression",
-                            operator: String::from("*"),
+                            operator: Cow::from("*"),
                             left: Box::new(Expression::Literal(Literal::NumericLiteral(
                                 NumericLiteral {
                                     typ: "NumericLiteral",
-                                    value: 2
+                                    value: 2.0,
+                                    raw: Cow::from("2")
                                 }
                             ))),
                             right: Box::new(Expression::Literal(Literal::NumericLiteral(
                                 NumericLiteral {
                                     typ: "NumericLiteral",
-                                    value: 2
+                                    value: 2.0,
+                                    raw: Cow::from("2")
                                 }
                             )))
                         })),
                         left: Box::new(Expression::Literal(Literal::NumericLiteral(
                             NumericLiteral {
                                 typ: "NumericLiteral",
-                                value: 2
+                                value: 2.0,
+                                raw: Cow::from("2")
                             }
                         )))
                     })
@@ -163,31 +178,35 @@ mod test {
             result,
             Program {
                 typ: "Program",
+                hashbang: None,
                 body: vec![Statement::ExpressionStatement(ExpressionStatement {
                     typ: "ExpressionStatement",
                     expression: Expression::BinaryExpression(BinaryExpression {
                         typ: "BinaryExpression",
-                        operator: String::from("*"),
+                        operator: Cow::from("*"),
                         left: Box::new(Expression::BinaryExpression(BinaryExpression {
                             typ: "BinaryExpression",
-                            operator: String::from("+"),
+                            operator: Cow::from("+"),
                             left: Box::new(Expression::Literal(Literal::NumericLiteral(
                                 NumericLiteral {
                                     typ: "NumericLiteral",
-                                    value: 2
+                                    value: 2.0,
+                                    raw: Cow::from("2")
                                 }
                             ))),
                             right: Box::new(Expression::Literal(Literal::NumericLiteral(
                                 NumericLiteral {
                                     typ: "NumericLiteral",
-                                    value: 2
+                                    value: 2.0,
+                                    raw: Cow::from("2")
                                 }
                             )))
                         })),
                         right: Box::new(Expression::Literal(Literal::NumericLiteral(
                             NumericLiteral {
                                 typ: "NumericLiteral",
-                                value: 2
+                                value: 2.0,
+                                raw: Cow::from("2")
                             }
                         )))
                     })
@@ -195,4 +214,219 @@ mod test {
             }
         )
     }
+
+    #[test]
+    fn modulo_operator() {
+        let mut parser = init();
+
+        let result = parser.parse("10 % 3;");
+
+        assert_eq!(
+            result,
+            Program {
+                typ: "Program",
+                hashbang: None,
+                body: vec![Statement::ExpressionStatement(ExpressionStatement {
+                    typ: "ExpressionStatement",
+                    expression: Expression::BinaryExpression(BinaryExpression {
+                        typ: "BinaryExpression",
+                        operator: Cow::from("%"),
+                        left: Box::new(Expression::Literal(Literal::NumericLiteral(
+                            NumericLiteral {
+                                typ: "NumericLiteral",
+                                value: 10.0,
+                                raw: Cow::from("10")
+                            }
+                        ))),
+                        right: Box::new(Expression::Literal(Literal::NumericLiteral(
+                            NumericLiteral {
+                                typ: "NumericLiteral",
+                                value: 3.0,
+                                raw: Cow::from("3")
+                            }
+                        )))
+                    })
+                })]
+            }
+        )
+    }
+
+    #[test]
+    fn division_operator() {
+        let mut parser = init();
+
+        let result = parser.parse("x / 2;");
+
+        assert_eq!(
+            result,
+            Program {
+                typ: "Program",
+                hashbang: None,
+                body: vec![Statement::ExpressionStatement(ExpressionStatement {
+                    typ: "ExpressionStatement",
+                    expression: Expression::BinaryExpression(BinaryExpression {
+                        typ: "BinaryExpression",
+                        operator: Cow::from("/"),
+                        left: Box::new(Expression::LeftHandSideExpression(
+                            LeftHandSideExpression::Identifier(Identifier {
+                                typ: "Identifier",
+                                name: Cow::from("x"),
+                                type_annotation: None,
+                                optional: false
+                            })
+                        )),
+                        right: Box::new(Expression::Literal(Literal::NumericLiteral(
+                            NumericLiteral {
+                                typ: "NumericLiteral",
+                                value: 2.0,
+                                raw: Cow::from("2")
+                            }
+                        )))
+                    })
+                })]
+            }
+        )
+    }
+
+    #[test]
+    fn exponentiation_is_right_associative() {
+        let mut parser = init();
+
+        let result = parser.parse("2 ** 3 ** 2;");
+
+        assert_eq!(
+            result,
+            Program {
+                typ: "Program",
+                hashbang: None,
+                body: vec![Statement::ExpressionStatement(ExpressionStatement {
+                    typ: "ExpressionStatement",
+                    expression: Expression::BinaryExpression(BinaryExpression {
+                        typ: "BinaryExpression",
+                        operator: Cow::from("**"),
+                        left: Box::new(Expression::Literal(Literal::NumericLiteral(
+                            NumericLiteral {
+                                typ: "NumericLiteral",
+                                value: 2.0,
+                                raw: Cow::from("2")
+                            }
+                        ))),
+                        right: Box::new(Expression::BinaryExpression(BinaryExpression {
+                            typ: "BinaryExpression",
+                            operator: Cow::from("**"),
+                            left: Box::new(Expression::Literal(Literal::NumericLiteral(
+                                NumericLiteral {
+                                    typ: "NumericLiteral",
+                                    value: 3.0,
+                                    raw: Cow::from("3")
+                                }
+                            ))),
+                            right: Box::new(Expression::Literal(Literal::NumericLiteral(
+                                NumericLiteral {
+                                    typ: "NumericLiteral",
+                                    value: 2.0,
+                                    raw: Cow::from("2")
+                                }
+                            )))
+                        }))
+                    })
+                })]
+            }
+        )
+    }
+
+    #[test]
+    fn exponentiation_binds_tighter_than_multiplication() {
+        let mut parser = init();
+
+        let result = parser.parse("2 * 3 ** 2;");
+
+        assert_eq!(
+            result,
+            Program {
+                typ: "Program",
+                hashbang: None,
+                body: vec![Statement::ExpressionStatement(ExpressionStatement {
+                    typ: "ExpressionStatement",
+                    expression: Expression::BinaryExpression(BinaryExpression {
+                        typ: "BinaryExpression",
+                        operator: Cow::from("*"),
+                        left: Box::new(Expression::Literal(Literal::NumericLiteral(
+                            NumericLiteral {
+                                typ: "NumericLiteral",
+                                value: 2.0,
+                                raw: Cow::from("2")
+                            }
+                        ))),
+                        right: Box::new(Expression::BinaryExpression(BinaryExpression {
+                            typ: "BinaryExpression",
+                            operator: Cow::from("**"),
+                            left: Box::new(Expression::Literal(Literal::NumericLiteral(
+                                NumericLiteral {
+                                    typ: "NumericLiteral",
+                                    value: 3.0,
+                                    raw: Cow::from("3")
+                                }
+                            ))),
+                            right: Box::new(Expression::Literal(Literal::NumericLiteral(
+                                NumericLiteral {
+                                    typ: "NumericLiteral",
+                                    value: 2.0,
+                                    raw: Cow::from("2")
+                                }
+                            )))
+                        }))
+                    })
+                })]
+            }
+        )
+    }
+
+    #[test]
+    fn parenthesized_unary_exponentiation_base() {
+        let mut parser = init();
+
+        let result = parser.parse("(-2) ** 2;");
+
+        assert_eq!(
+            result,
+            Program {
+                typ: "Program",
+                hashbang: None,
+                body: vec![Statement::ExpressionStatement(ExpressionStatement {
+                    typ: "ExpressionStatement",
+                    expression: Expression::BinaryExpression(BinaryExpression {
+                        typ: "BinaryExpression",
+                        operator: Cow::from("**"),
+                        left: Box::new(Expression::UnaryExpression(UnaryExpression {
+                            typ: "UnaryExpression",
+                            operator: Cow::from("-"),
+                            argument: Box::new(Expression::Literal(Literal::NumericLiteral(
+                                NumericLiteral {
+                                    typ: "NumericLiteral",
+                                    value: 2.0,
+                                    raw: Cow::from("2")
+                                }
+                            )))
+                        })),
+                        right: Box::new(Expression::Literal(Literal::NumericLiteral(
+                            NumericLiteral {
+                                typ: "NumericLiteral",
+                                value: 2.0,
+                                raw: Cow::from("2")
+                            }
+                        )))
+                    })
+                })]
+            }
+        )
+    }
+
+    #[test]
+    #[should_panic(expected = "Unary operator used immediately before exponentiation expression")]
+    fn rejects_unary_exponentiation_base() {
+        let mut parser = init();
+
+        parser.parse("-2 ** 2;");
+    }
 }
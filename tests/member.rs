@@ -0,0 +1,329 @@
+#[cfg(test)]
+mod test {
+    use letter_rdp_rust::*;
+    use std::borrow::Cow;
+    #[test]
+    fn handles_member_expression() {
+        let mut parser = init();
+
+        let result = parser.parse("a.b[0];");
+
+        assert_eq!(
+            result,
+            Program {
+                typ: "Program",
+                hashbang: None,
+                body: vec![Statement::ExpressionStatement(ExpressionStatement {
+                    typ: "ExpressionStatement",
+                    expression: Expression::LeftHandSideExpression(
+                        LeftHandSideExpression::MemberExpression(MemberExpression {
+                            typ: "MemberExpression",
+                            object: Box::new(Expression::LeftHandSideExpression(
+                                LeftHandSideExpression::MemberExpression(MemberExpression {
+                                    typ: "MemberExpression",
+                                    object: Box::new(Expression::LeftHandSideExpression(
+                                        LeftHandSideExpression::Identifier(Identifier {
+                                            typ: "Identifier",
+                                            name: Cow::from("a"),
+                                            type_annotation: None,
+                                            optional: false
+                                        })
+                                    )),
+                                    property: Box::new(Expression::LeftHandSideExpression(
+                                        LeftHandSideExpression::Identifier(Identifier {
+                                            typ: "Identifier",
+                                            name: Cow::from("b"),
+                                            type_annotation: None,
+                                            optional: false
+                                        })
+                                    )),
+                                    computed: false,
+                                    optional: false
+                                })
+                            )),
+                            property: Box::new(Expression::Literal(Literal::NumericLiteral(
+                                NumericLiteral {
+                                    typ: "NumericLiteral",
+                                    value: 0.0,
+                                    raw: Cow::from("0")
+                                }
+                            ))),
+                            computed: true,
+                            optional: false
+                        })
+                    )
+                })]
+            }
+        )
+    }
+
+    #[test]
+    fn handles_call_expression() {
+        let mut parser = init();
+
+        let result = parser.parse("f(x, 1)(y);");
+
+        assert_eq!(
+            result,
+            Program {
+                typ: "Program",
+                hashbang: None,
+                body: vec![Statement::ExpressionStatement(ExpressionStatement {
+                    typ: "ExpressionStatement",
+                    expression: Expression::LeftHandSideExpression(
+                        LeftHandSideExpression::CallExpression(CallExpression {
+                            typ: "CallExpression",
+                            callee: Box::new(Expression::LeftHandSideExpression(
+                                LeftHandSideExpression::CallExpression(CallExpression {
+                                    typ: "CallExpression",
+                                    callee: Box::new(Expression::LeftHandSideExpression(
+                                        LeftHandSideExpression::Identifier(Identifier {
+                                            typ: "Identifier",
+                                            name: Cow::from("f"),
+                                            type_annotation: None,
+                                            optional: false
+                                        })
+                                    )),
+                                    arguments: vec![
+                                        Expression::LeftHandSideExpression(
+                                            LeftHandSideExpression::Identifier(Identifier {
+                                                typ: "Identifier",
+                                                name: Cow::from("x"),
+                                                type_annotation: None,
+                                                optional: false
+                                            })
+                                        ),
+                                        Expression::Literal(Literal::NumericLiteral(
+                                            NumericLiteral {
+                                                typ: "NumericLiteral",
+                                                value: 1.0,
+                                                raw: Cow::from("1")
+                                            }
+                                        ))
+                                    ],
+                                    optional: false
+                                })
+                            )),
+                            arguments: vec![Expression::LeftHandSideExpression(
+                                LeftHandSideExpression::Identifier(Identifier {
+                                    typ: "Identifier",
+                                    name: Cow::from("y"),
+                                    type_annotation: None,
+                                    optional: false
+                                })
+                            )],
+                            optional: false
+                        })
+                    )
+                })]
+            }
+        )
+    }
+
+    #[test]
+    fn handles_optional_member_chain() {
+        let mut parser = init();
+
+        let result = parser.parse("a?.b.c;");
+
+        assert_eq!(
+            result,
+            Program {
+                typ: "Program",
+                hashbang: None,
+                body: vec![Statement::ExpressionStatement(ExpressionStatement {
+                    typ: "ExpressionStatement",
+                    expression: Expression::LeftHandSideExpression(
+                        LeftHandSideExpression::ChainExpression(ChainExpression {
+                            typ: "ChainExpression",
+                            expression: Box::new(Expression::LeftHandSideExpression(
+                                LeftHandSideExpression::MemberExpression(MemberExpression {
+                                    typ: "MemberExpression",
+                                    object: Box::new(Expression::LeftHandSideExpression(
+                                        LeftHandSideExpression::MemberExpression(
+                                            MemberExpression {
+                                                typ: "MemberExpression",
+                                                object: Box::new(
+                                                    Expression::LeftHandSideExpression(
+                                                        LeftHandSideExpression::Identifier(
+                                                            Identifier {
+                                                                typ: "Identifier",
+                                                                name: Cow::from("a"),
+                                                                type_annotation: None,
+                                                                optional: false
+                                                            }
+                                                        )
+                                                    )
+                                                ),
+                                                property: Box::new(
+                                                    Expression::LeftHandSideExpression(
+                                                        LeftHandSideExpression::Identifier(
+                                                            Identifier {
+                                                                typ: "Identifier",
+                                                                name: Cow::from("b"),
+                                                                type_annotation: None,
+                                                                optional: false
+                                                            }
+                                                        )
+                                                    )
+                                                ),
+                                                computed: false,
+                                                optional: true
+                                            }
+                                        )
+                                    )),
+                                    property: Box::new(Expression::LeftHandSideExpression(
+                                        LeftHandSideExpression::Identifier(Identifier {
+                                            typ: "Identifier",
+                                            name: Cow::from("c"),
+                                            type_annotation: None,
+                                            optional: false
+                                        })
+                                    )),
+                                    computed: false,
+                                    optional: false
+                                })
+                            ))
+                        })
+                    )
+                })]
+            }
+        )
+    }
+
+    #[test]
+    fn handles_optional_computed_member_and_call() {
+        let mut parser = init();
+
+        let result = parser.parse("a?.[0]?.(x);");
+
+        assert_eq!(
+            result,
+            Program {
+                typ: "Program",
+                hashbang: None,
+                body: vec![Statement::ExpressionStatement(ExpressionStatement {
+                    typ: "ExpressionStatement",
+                    expression: Expression::LeftHandSideExpression(
+                        LeftHandSideExpression::ChainExpression(ChainExpression {
+                            typ: "ChainExpression",
+                            expression: Box::new(Expression::LeftHandSideExpression(
+                                LeftHandSideExpression::CallExpression(CallExpression {
+                                    typ: "CallExpression",
+                                    callee: Box::new(Expression::LeftHandSideExpression(
+                                        LeftHandSideExpression::MemberExpression(
+                                            MemberExpression {
+                                                typ: "MemberExpression",
+                                                object: Box::new(
+                                                    Expression::LeftHandSideExpression(
+                                                        LeftHandSideExpression::Identifier(
+                                                            Identifier {
+                                                                typ: "Identifier",
+                                                                name: Cow::from("a"),
+                                                                type_annotation: None,
+                                                                optional: false
+                                                            }
+                                                        )
+                                                    )
+                                                ),
+                                                property: Box::new(Expression::Literal(
+                                                    Literal::NumericLiteral(NumericLiteral {
+                                                        typ: "NumericLiteral",
+                                                        value: 0.0,
+                                                        raw: Cow::from("0")
+                                                    })
+                                                )),
+                                                computed: true,
+                                                optional: true
+                                            }
+                                        )
+                                    )),
+                                    arguments: vec![Expression::LeftHandSideExpression(
+                                        LeftHandSideExpression::Identifier(Identifier {
+                                            typ: "Identifier",
+                                            name: Cow::from("x"),
+                                            type_annotation: None,
+                                            optional: false
+                                        })
+                                    )],
+                                    optional: true
+                                })
+                            ))
+                        })
+                    )
+                })]
+            }
+        )
+    }
+
+    #[test]
+    fn parenthesized_chain_ends_short_circuiting() {
+        let mut parser = init();
+
+        let result = parser.parse("(a?.b).c;");
+
+        assert_eq!(
+            result,
+            Program {
+                typ: "Program",
+                hashbang: None,
+                body: vec![Statement::ExpressionStatement(ExpressionStatement {
+                    typ: "ExpressionStatement",
+                    expression: Expression::LeftHandSideExpression(
+                        LeftHandSideExpression::MemberExpression(MemberExpression {
+                            typ: "MemberExpression",
+                            object: Box::new(Expression::LeftHandSideExpression(
+                                LeftHandSideExpression::ChainExpression(ChainExpression {
+                                    typ: "ChainExpression",
+                                    expression: Box::new(Expression::LeftHandSideExpression(
+                                        LeftHandSideExpression::MemberExpression(
+                                            MemberExpression {
+                                                typ: "MemberExpression",
+                                                object: Box::new(
+                                                    Expression::LeftHandSideExpression(
+                                                        LeftHandSideExpression::Identifier(
+                                                            Identifier {
+                                                                typ: "Identifier",
+                                                                name: Cow::from("a"),
+                                                                type_annotation: None,
+                                                                optional: false
+                                                            }
+                                                        )
+                                                    )
+                                                ),
+                                                property: Box::new(
+                                                    Expression::LeftHandSideExpression(
+                                                        LeftHandSideExpression::Identifier(
+                                                            Identifier {
+                                                                typ: "Identifier",
+                                                                name: Cow::from("b"),
+                                                                type_annotation: None,
+                                                                optional: false
+                                                            }
+                                                        )
+                                                    )
+                                                ),
+                                                computed: false,
+                                                optional: true
+                                            }
+                                        )
+                                    ))
+                                })
+                            )),
+                            property: Box::new(Expression::LeftHandSideExpression(
+                                LeftHandSideExpression::Identifier(Identifier {
+                                    typ: "Identifier",
+                                    name: Cow::from("c"),
+                                    type_annotation: None,
+                                    optional: false
+                                })
+                            )),
+                            computed: false,
+                            optional: false
+                        })
+                    )
+                })]
+            }
+        )
+    }
+}
@@ -0,0 +1,607 @@
+#[cfg(test)]
+mod test {
+    use letter_rdp_rust::*;
+    use std::borrow::Cow;
+    #[test]
+    fn handles_default_and_named_imports() {
+        let mut parser = init_with(ParserOptions {
+            source_type: SourceType::Module,
+            ..ParserOptions::default()
+        });
+
+        let result = parser.parse(r#"import x, { a as b, c } from "m";"#);
+
+        assert_eq!(
+            result,
+            Program {
+                typ: "Program",
+                hashbang: None,
+                body: vec![Statement::ModuleDeclaration(
+                    ModuleDeclaration::ImportDeclaration(ImportDeclaration {
+                        typ: "ImportDeclaration",
+                        specifiers: vec![
+                            ImportSpecifierKind::ImportDefaultSpecifier(ImportDefaultSpecifier {
+                                typ: "ImportDefaultSpecifier",
+                                local: Identifier {
+                                    typ: "Identifier",
+                                    name: Cow::from("x"),
+                                    type_annotation: None,
+                                    optional: false
+                                }
+                            }),
+                            ImportSpecifierKind::ImportSpecifier(ImportSpecifier {
+                                typ: "ImportSpecifier",
+                                imported: Identifier {
+                                    typ: "Identifier",
+                                    name: Cow::from("a"),
+                                    type_annotation: None,
+                                    optional: false
+                                },
+                                local: Identifier {
+                                    typ: "Identifier",
+                                    name: Cow::from("b"),
+                                    type_annotation: None,
+                                    optional: false
+                                }
+                            }),
+                            ImportSpecifierKind::ImportSpecifier(ImportSpecifier {
+                                typ: "ImportSpecifier",
+                                imported: Identifier {
+                                    typ: "Identifier",
+                                    name: Cow::from("c"),
+                                    type_annotation: None,
+                                    optional: false
+                                },
+                                local: Identifier {
+                                    typ: "Identifier",
+                                    name: Cow::from("c"),
+                                    type_annotation: None,
+                                    optional: false
+                                }
+                            })
+                        ],
+                        source: StringLiteral {
+                            typ: "StringLiteral",
+                            value: Cow::from("m"),
+                            raw: Cow::from("\"m\"")
+                        }
+                    })
+                )]
+            }
+        )
+    }
+
+    #[test]
+    fn handles_namespace_import() {
+        let mut parser = init_with(ParserOptions {
+            source_type: SourceType::Module,
+            ..ParserOptions::default()
+        });
+
+        let result = parser.parse(r#"import * as ns from "m";"#);
+
+        assert_eq!(
+            result,
+            Program {
+                typ: "Program",
+                hashbang: None,
+                body: vec![Statement::ModuleDeclaration(
+                    ModuleDeclaration::ImportDeclaration(ImportDeclaration {
+                        typ: "ImportDeclaration",
+                        specifiers: vec![ImportSpecifierKind::ImportNamespaceSpecifier(
+                            ImportNamespaceSpecifier {
+                                typ: "ImportNamespaceSpecifier",
+                                local: Identifier {
+                                    typ: "Identifier",
+                                    name: Cow::from("ns"),
+                                    type_annotation: None,
+                                    optional: false
+                                }
+                            }
+                        )],
+                        source: StringLiteral {
+                            typ: "StringLiteral",
+                            value: Cow::from("m"),
+                            raw: Cow::from("\"m\"")
+                        }
+                    })
+                )]
+            }
+        )
+    }
+
+    #[test]
+    fn handles_side_effect_import() {
+        let mut parser = init_with(ParserOptions {
+            source_type: SourceType::Module,
+            ..ParserOptions::default()
+        });
+
+        let result = parser.parse(r#"import "m";"#);
+
+        assert_eq!(
+            result,
+            Program {
+                typ: "Program",
+                hashbang: None,
+                body: vec![Statement::ModuleDeclaration(
+                    ModuleDeclaration::ImportDeclaration(ImportDeclaration {
+                        typ: "ImportDeclaration",
+                        specifiers: vec![],
+                        source: StringLiteral {
+                            typ: "StringLiteral",
+                            value: Cow::from("m"),
+                            raw: Cow::from("\"m\"")
+                        }
+                    })
+                )]
+            }
+        )
+    }
+
+    #[test]
+    fn handles_export_const() {
+        let mut parser = init_with(ParserOptions {
+            source_type: SourceType::Module,
+            ..ParserOptions::default()
+        });
+
+        let result = parser.parse("export const x = 1;");
+
+        assert_eq!(
+            result,
+            Program {
+                typ: "Program",
+                hashbang: None,
+                body: vec![Statement::ModuleDeclaration(
+                    ModuleDeclaration::ExportNamedDeclaration(ExportNamedDeclaration {
+                        typ: "ExportNamedDeclaration",
+                        declaration: Some(Box::new(Statement::VariableStatement(
+                            VariableStatement {
+                                typ: "VariableStatement",
+                                kind: "const",
+                                declarations: vec![VariableDeclaration {
+                                    typ: "VariableDeclaration",
+                                    id: Identifier {
+                                        typ: "Identifier",
+                                        name: Cow::from("x"),
+                                        type_annotation: None,
+                                        optional: false
+                                    },
+                                    init: Some(Expression::Literal(Literal::NumericLiteral(
+                                        NumericLiteral {
+                                            typ: "NumericLiteral",
+                                            value: 1.0,
+                                            raw: Cow::from("1")
+                                        }
+                                    )))
+                                }]
+                            }
+                        ))),
+                        specifiers: vec![],
+                        source: None
+                    })
+                )]
+            }
+        )
+    }
+
+    #[test]
+    fn handles_export_default() {
+        let mut parser = init_with(ParserOptions {
+            source_type: SourceType::Module,
+            ..ParserOptions::default()
+        });
+
+        let result = parser.parse("export default a + 1;");
+
+        assert_eq!(
+            result,
+            Program {
+                typ: "Program",
+                hashbang: None,
+                body: vec![Statement::ModuleDeclaration(
+                    ModuleDeclaration::ExportDefaultDeclaration(ExportDefaultDeclaration {
+                        typ: "ExportDefaultDeclaration",
+                        declaration: ExportDefaultKind::Expression(Expression::BinaryExpression(
+                            BinaryExpression {
+                                typ: "BinaryExpression",
+                                operator: Cow::from("+"),
+                                left: Box::new(Expression::LeftHandSideExpression(
+                                    LeftHandSideExpression::Identifier(Identifier {
+                                        typ: "Identifier",
+                                        name: Cow::from("a"),
+                                        type_annotation: None,
+                                        optional: false
+                                    })
+                                )),
+                                right: Box::new(Expression::Literal(Literal::NumericLiteral(
+                                    NumericLiteral {
+                                        typ: "NumericLiteral",
+                                        value: 1.0,
+                                        raw: Cow::from("1")
+                                    }
+                                )))
+                            }
+                        ))
+                    })
+                )]
+            }
+        )
+    }
+
+    #[test]
+    fn declares_named_export_default_function() {
+        let mut parser = init_with(ParserOptions {
+            source_type: SourceType::Module,
+            ..ParserOptions::default()
+        });
+
+        let result = parser.parse("export default function f() {}\nf();");
+
+        assert_eq!(
+            result.body[0],
+            Statement::ModuleDeclaration(ModuleDeclaration::ExportDefaultDeclaration(
+                ExportDefaultDeclaration {
+                    typ: "ExportDefaultDeclaration",
+                    declaration: ExportDefaultKind::FunctionDeclaration(FunctionDeclaration {
+                        typ: "FunctionDeclaration",
+                        id: Identifier {
+                            typ: "Identifier",
+                            name: Cow::from("f"),
+                            type_annotation: None,
+                            optional: false
+                        },
+                        params: vec![],
+                        body: BlockStatement {
+                            typ: "BlockStatement",
+                            body: vec![]
+                        },
+                        is_async: false,
+                        is_generator: false,
+                        return_type: None
+                    })
+                }
+            ))
+        );
+        assert_eq!(result.body.len(), 2)
+    }
+
+    #[test]
+    fn handles_anonymous_export_default_function_and_class() {
+        let parse = |source| {
+            let mut parser = init_with(ParserOptions {
+                source_type: SourceType::Module,
+                ..ParserOptions::default()
+            });
+            match parser.parse(source).body.remove(0) {
+                Statement::ModuleDeclaration(ModuleDeclaration::ExportDefaultDeclaration(
+                    declaration,
+                )) => declaration.declaration,
+                statement => panic!("Unexpected statement: {:?}", statement),
+            }
+        };
+
+        assert!(matches!(
+            parse("export default function () {}"),
+            ExportDefaultKind::Expression(Expression::FunctionExpression(_))
+        ));
+        assert!(matches!(
+            parse("export default class {}"),
+            ExportDefaultKind::Expression(Expression::ClassExpression(_))
+        ));
+        assert!(matches!(
+            parse("export default class A {}"),
+            ExportDefaultKind::ClassDeclaration(_)
+        ))
+    }
+
+    #[test]
+    #[should_panic(expected = "Identifier 'f' has already been declared")]
+    fn rejects_let_redeclaring_export_default_function() {
+        let mut parser = init_with(ParserOptions {
+            source_type: SourceType::Module,
+            ..ParserOptions::default()
+        });
+
+        parser.parse("export default function f() {}\nlet f;");
+    }
+
+    #[test]
+    fn handles_export_specifiers() {
+        let mut parser = init_with(ParserOptions {
+            source_type: SourceType::Module,
+            ..ParserOptions::default()
+        });
+
+        let result = parser.parse("export { a as b, c as default };");
+
+        assert_eq!(
+            result,
+            Program {
+                typ: "Program",
+                hashbang: None,
+                body: vec![Statement::ModuleDeclaration(
+                    ModuleDeclaration::ExportNamedDeclaration(ExportNamedDeclaration {
+                        typ: "ExportNamedDeclaration",
+                        declaration: None,
+                        specifiers: vec![
+                            ExportSpecifier {
+                                typ: "ExportSpecifier",
+                                local: Identifier {
+                                    typ: "Identifier",
+                                    name: Cow::from("a"),
+                                    type_annotation: None,
+                                    optional: false
+                                },
+                                exported: Identifier {
+                                    typ: "Identifier",
+                                    name: Cow::from("b"),
+                                    type_annotation: None,
+                                    optional: false
+                                }
+                            },
+                            ExportSpecifier {
+                                typ: "ExportSpecifier",
+                                local: Identifier {
+                                    typ: "Identifier",
+                                    name: Cow::from("c"),
+                                    type_annotation: None,
+                                    optional: false
+                                },
+                                exported: Identifier {
+                                    typ: "Identifier",
+                                    name: Cow::from("default"),
+                                    type_annotation: None,
+                                    optional: false
+                                }
+                            }
+                        ],
+                        source: None
+                    })
+                )]
+            }
+        )
+    }
+
+    #[test]
+    fn handles_reexport() {
+        let mut parser = init_with(ParserOptions {
+            source_type: SourceType::Module,
+            ..ParserOptions::default()
+        });
+
+        let result = parser.parse(r#"export { a } from "m";"#);
+
+        assert_eq!(
+            result,
+            Program {
+                typ: "Program",
+                hashbang: None,
+                body: vec![Statement::ModuleDeclaration(
+                    ModuleDeclaration::ExportNamedDeclaration(ExportNamedDeclaration {
+                        typ: "ExportNamedDeclaration",
+                        declaration: None,
+                        specifiers: vec![ExportSpecifier {
+                            typ: "ExportSpecifier",
+                            local: Identifier {
+                                typ: "Identifier",
+                                name: Cow::from("a"),
+                                type_annotation: None,
+                                optional: false
+                            },
+                            exported: Identifier {
+                                typ: "Identifier",
+                                name: Cow::from("a"),
+                                type_annotation: None,
+                                optional: false
+                            }
+                        }],
+                        source: Some(StringLiteral {
+                            typ: "StringLiteral",
+                            value: Cow::from("m"),
+                            raw: Cow::from("\"m\"")
+                        })
+                    })
+                )]
+            }
+        )
+    }
+
+    #[test]
+    fn handles_export_all() {
+        let mut parser = init_with(ParserOptions {
+            source_type: SourceType::Module,
+            ..ParserOptions::default()
+        });
+
+        let result = parser.parse(r#"export * from "m";"#);
+
+        assert_eq!(
+            result,
+            Program {
+                typ: "Program",
+                hashbang: None,
+                body: vec![Statement::ModuleDeclaration(
+                    ModuleDeclaration::ExportAllDeclaration(ExportAllDeclaration {
+                        typ: "ExportAllDeclaration",
+                        exported: None,
+                        source: StringLiteral {
+                            typ: "StringLiteral",
+                            value: Cow::from("m"),
+                            raw: Cow::from("\"m\"")
+                        }
+                    })
+                )]
+            }
+        )
+    }
+
+    #[test]
+    fn handles_export_all_as_namespace() {
+        let mut parser = init_with(ParserOptions {
+            source_type: SourceType::Module,
+            ..ParserOptions::default()
+        });
+
+        let result = parser.parse(r#"export * as ns from "m";"#);
+
+        assert_eq!(
+            result,
+            Program {
+                typ: "Program",
+                hashbang: None,
+                body: vec![Statement::ModuleDeclaration(
+                    ModuleDeclaration::ExportAllDeclaration(ExportAllDeclaration {
+                        typ: "ExportAllDeclaration",
+                        exported: Some(Identifier {
+                            typ: "Identifier",
+                            name: Cow::from("ns"),
+                            type_annotation: None,
+                            optional: false
+                        }),
+                        source: StringLiteral {
+                            typ: "StringLiteral",
+                            value: Cow::from("m"),
+                            raw: Cow::from("\"m\"")
+                        }
+                    })
+                )]
+            }
+        )
+    }
+
+    #[test]
+    fn inserts_semicolon_after_export_all() {
+        let mut parser = init_with(ParserOptions {
+            source_type: SourceType::Module,
+            ..ParserOptions::default()
+        });
+
+        let result = parser.parse("export * from \"m\"\nlet a = 1\nexport * from \"n\"");
+        let expected = parser.parse("export * from \"m\";\nlet a = 1;\nexport * from \"n\";");
+
+        assert_eq!(result, expected)
+    }
+
+    #[test]
+    fn handles_dynamic_import_in_script() {
+        let mut parser = init();
+
+        let result = parser.parse(r#"import("m").then(f);"#);
+
+        assert_eq!(
+            result,
+            Program {
+                typ: "Program",
+                hashbang: None,
+                body: vec![Statement::ExpressionStatement(ExpressionStatement {
+                    typ: "ExpressionStatement",
+                    expression: Expression::LeftHandSideExpression(
+                        LeftHandSideExpression::CallExpression(CallExpression {
+                            typ: "CallExpression",
+                            callee: Box::new(Expression::LeftHandSideExpression(
+                                LeftHandSideExpression::MemberExpression(MemberExpression {
+                                    typ: "MemberExpression",
+                                    object: Box::new(Expression::LeftHandSideExpression(
+                                        LeftHandSideExpression::ImportExpression(
+                                            ImportExpression {
+                                                typ: "ImportExpression",
+                                                source: Box::new(Expression::Literal(
+                                                    Literal::StringLiteral(StringLiteral {
+                                                        typ: "StringLiteral",
+                                                        value: Cow::from("m"),
+                                                        raw: Cow::from("\"m\"")
+                                                    })
+                                                ))
+                                            }
+                                        )
+                                    )),
+                                    property: Box::new(Expression::LeftHandSideExpression(
+                                        LeftHandSideExpression::Identifier(Identifier {
+                                            typ: "Identifier",
+                                            name: Cow::from("then"),
+                                            type_annotation: None,
+                                            optional: false
+                                        })
+                                    )),
+                                    computed: false,
+                                    optional: false
+                                })
+                            )),
+                            arguments: vec![Expression::LeftHandSideExpression(
+                                LeftHandSideExpression::Identifier(Identifier {
+                                    typ: "Identifier",
+                                    name: Cow::from("f"),
+                                    type_annotation: None,
+                                    optional: false
+                                })
+                            )],
+                            optional: false
+                        })
+                    )
+                })]
+            }
+        )
+    }
+
+    #[test]
+    #[should_panic(expected = "'import' may only be used in a module")]
+    fn rejects_import_in_script() {
+        let mut parser = init();
+
+        parser.parse("import x from \"m\";");
+    }
+
+    #[test]
+    #[should_panic(expected = "'export' may only be used in a module")]
+    fn rejects_export_in_script() {
+        let mut parser = init();
+
+        parser.parse("export const x = 1;");
+    }
+
+    #[test]
+    #[should_panic(expected = "'export' may only appear at the top level")]
+    fn rejects_nested_export() {
+        let mut parser = init_with(ParserOptions {
+            source_type: SourceType::Module,
+            ..ParserOptions::default()
+        });
+
+        parser.parse("{ export const x = 1; }");
+    }
+
+    #[test]
+    #[should_panic(expected = "'export' may only appear at the top level")]
+    fn rejects_export_in_if_statement() {
+        let mut parser = init_with(ParserOptions {
+            source_type: SourceType::Module,
+            ..ParserOptions::default()
+        });
+
+        parser.parse("if (x) export const a = 1;");
+    }
+
+    #[test]
+    #[should_panic(expected = "'import' may only appear at the top level")]
+    fn rejects_import_in_else_statement() {
+        let mut parser = init_with(ParserOptions {
+            source_type: SourceType::Module,
+            ..ParserOptions::default()
+        });
+
+        parser.parse(r#"if (x) {} else import a from "m";"#);
+    }
+
+    #[test]
+    #[should_panic(expected = "Identifier 'x' has already been declared")]
+    fn rejects_redeclared_import() {
+        let mut parser = init_with(ParserOptions {
+            source_type: SourceType::Module,
+            ..ParserOptions::default()
+        });
+
+        parser.parse("import x from \"m\"; let x;");
+    }
+}
@@ -1,6 +1,7 @@
 #[cfg(test)]
 mod test {
     use letter_rdp_rust::*;
+    use std::borrow::Cow;
     #[test]
     fn prints_number() {
         let mut parser = init();
@@ -11,14 +12,254 @@ mod test {
             result,
             Program {
                 typ: "Program",
+                hashbang: None,
                 body: vec![Statement::ExpressionStatement(ExpressionStatement {
                     typ: "ExpressionStatement",
                     expression: Expression::Literal(Literal::NumericLiteral(NumericLiteral {
                         typ: "NumericLiteral",
-                        value: 1
+                        value: 1.0,
+                        raw: Cow::from("1")
                     }))
                 })]
             }
         )
     }
+    #[test]
+    fn handles_decimal_number() {
+        let mut parser = init();
+
+        let result = parser.parse("2.75;");
+
+        assert_eq!(
+            result,
+            Program {
+                typ: "Program",
+                hashbang: None,
+                body: vec![Statement::ExpressionStatement(ExpressionStatement {
+                    typ: "ExpressionStatement",
+                    expression: Expression::Literal(Literal::NumericLiteral(NumericLiteral {
+                        typ: "NumericLiteral",
+                        value: 2.75,
+                        raw: Cow::from("2.75")
+                    }))
+                })]
+            }
+        )
+    }
+
+    #[test]
+    fn handles_leading_dot_number() {
+        let mut parser = init();
+
+        let result = parser.parse(".5;");
+
+        assert_eq!(
+            result,
+            Program {
+                typ: "Program",
+                hashbang: None,
+                body: vec![Statement::ExpressionStatement(ExpressionStatement {
+                    typ: "ExpressionStatement",
+                    expression: Expression::Literal(Literal::NumericLiteral(NumericLiteral {
+                        typ: "NumericLiteral",
+                        value: 0.5,
+                        raw: Cow::from(".5")
+                    }))
+                })]
+            }
+        )
+    }
+
+    #[test]
+    fn handles_exponent_number() {
+        let mut parser = init();
+
+        let result = parser.parse("1e3;");
+
+        assert_eq!(
+            result,
+            Program {
+                typ: "Program",
+                hashbang: None,
+                body: vec![Statement::ExpressionStatement(ExpressionStatement {
+                    typ: "ExpressionStatement",
+                    expression: Expression::Literal(Literal::NumericLiteral(NumericLiteral {
+                        typ: "NumericLiteral",
+                        value: 1000.0,
+                        raw: Cow::from("1e3")
+                    }))
+                })]
+            }
+        )
+    }
+
+    #[test]
+    fn handles_signed_exponent_number() {
+        let mut parser = init();
+
+        let result = parser.parse("2.5E-3;");
+
+        assert_eq!(
+            result,
+            Program {
+                typ: "Program",
+                hashbang: None,
+                body: vec![Statement::ExpressionStatement(ExpressionStatement {
+                    typ: "ExpressionStatement",
+                    expression: Expression::Literal(Literal::NumericLiteral(NumericLiteral {
+                        typ: "NumericLiteral",
+                        value: 0.0025,
+                        raw: Cow::from("2.5E-3")
+                    }))
+                })]
+            }
+        )
+    }
+
+    #[test]
+    fn handles_hex_number() {
+        let mut parser = init();
+
+        let result = parser.parse("0xFF;");
+
+        assert_eq!(
+            result,
+            Program {
+                typ: "Program",
+                hashbang: None,
+                body: vec![Statement::ExpressionStatement(ExpressionStatement {
+                    typ: "ExpressionStatement",
+                    expression: Expression::Literal(Literal::NumericLiteral(NumericLiteral {
+                        typ: "NumericLiteral",
+                        value: 255.0,
+                        raw: Cow::from("0xFF")
+                    }))
+                })]
+            }
+        )
+    }
+
+    #[test]
+    fn handles_octal_number() {
+        let mut parser = init();
+
+        let result = parser.parse("0o17;");
+
+        assert_eq!(
+            result,
+            Program {
+                typ: "Program",
+                hashbang: None,
+                body: vec![Statement::ExpressionStatement(ExpressionStatement {
+                    typ: "ExpressionStatement",
+                    expression: Expression::Literal(Literal::NumericLiteral(NumericLiteral {
+                        typ: "NumericLiteral",
+                        value: 15.0,
+                        raw: Cow::from("0o17")
+                    }))
+                })]
+            }
+        )
+    }
+
+    #[test]
+    fn handles_binary_number() {
+        let mut parser = init();
+
+        let result = parser.parse("0b1010;");
+
+        assert_eq!(
+            result,
+            Program {
+                typ: "Program",
+                hashbang: None,
+                body: vec![Statement::ExpressionStatement(ExpressionStatement {
+                    typ: "ExpressionStatement",
+                    expression: Expression::Literal(Literal::NumericLiteral(NumericLiteral {
+                        typ: "NumericLiteral",
+                        value: 10.0,
+                        raw: Cow::from("0b1010")
+                    }))
+                })]
+            }
+        )
+    }
+
+    #[test]
+    fn handles_separators_number() {
+        let mut parser = init();
+
+        let result = parser.parse("1_000_000;");
+
+        assert_eq!(
+            result,
+            Program {
+                typ: "Program",
+                hashbang: None,
+                body: vec![Statement::ExpressionStatement(ExpressionStatement {
+                    typ: "ExpressionStatement",
+                    expression: Expression::Literal(Literal::NumericLiteral(NumericLiteral {
+                        typ: "NumericLiteral",
+                        value: 1000000.0,
+                        raw: Cow::from("1_000_000")
+                    }))
+                })]
+            }
+        )
+    }
+
+    #[test]
+    fn handles_number_beyond_integer_range() {
+        let mut parser = init();
+
+        let result = parser.parse("123456789012345678901234567890;");
+
+        match &result.body[0] {
+            Statement::ExpressionStatement(ExpressionStatement {
+                expression: Expression::Literal(Literal::NumericLiteral(n)),
+                ..
+            }) => assert_eq!(n.value, 1.2345678901234568e29),
+            _ => panic!("Expected a numeric literal"),
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "Invalid numeric literal: 0x")]
+    fn rejects_empty_hex() {
+        let mut parser = init();
+
+        parser.parse("0x;");
+    }
+
+    #[test]
+    #[should_panic(expected = "Invalid numeric literal: 1__0")]
+    fn rejects_double_separator() {
+        let mut parser = init();
+
+        parser.parse("1__0;");
+    }
+
+    #[test]
+    #[should_panic(expected = "Invalid numeric literal: 10_")]
+    fn rejects_trailing_separator() {
+        let mut parser = init();
+
+        parser.parse("10_;");
+    }
+
+    #[test]
+    #[should_panic(expected = "Invalid numeric literal: 0b102")]
+    fn rejects_digit_out_of_radix() {
+        let mut parser = init();
+
+        parser.parse("0b102;");
+    }
+
+    #[test]
+    #[should_panic(expected = "Invalid numeric literal: 1e")]
+    fn rejects_empty_exponent() {
+        let mut parser = init();
+
+        parser.parse("1e;");
+    }
 }
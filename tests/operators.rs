@@ -0,0 +1,282 @@
+#[cfg(test)]
+mod test {
+    use letter_rdp_rust::*;
+    use std::borrow::Cow;
+    use std::sync::Arc;
+    #[test]
+    fn adds_operators_through_the_options() {
+        let mut spec = TokenSpec::default();
+        spec.insert_rule("BITWISE_OR", r"\|>", "PIPELINE").unwrap();
+        let mut options = ParserOptions::default();
+        options
+            .binary_operators
+            .push(BinaryOperator::binary("PIPELINE", 1));
+        let mut parser = init_with(options);
+        parser.tokenizer = Tokenizer::with_spec(Arc::new(spec));
+
+        let result = parser.parse("a + b |> f |> g");
+
+        assert_eq!(
+            result,
+            Program {
+                typ: "Program",
+                hashbang: None,
+                body: vec![Statement::ExpressionStatement(ExpressionStatement {
+                    typ: "ExpressionStatement",
+                    expression: Expression::BinaryExpression(BinaryExpression {
+                        typ: "BinaryExpression",
+                        operator: Cow::from("|>"),
+                        left: Box::new(Expression::BinaryExpression(BinaryExpression {
+                            typ: "BinaryExpression",
+                            operator: Cow::from("|>"),
+                            left: Box::new(Expression::BinaryExpression(BinaryExpression {
+                                typ: "BinaryExpression",
+                                operator: Cow::from("+"),
+                                left: Box::new(Expression::LeftHandSideExpression(
+                                    LeftHandSideExpression::Identifier(Identifier {
+                                        typ: "Identifier",
+                                        name: Cow::from("a"),
+                                        type_annotation: None,
+                                        optional: false
+                                    })
+                                )),
+                                right: Box::new(Expression::LeftHandSideExpression(
+                                    LeftHandSideExpression::Identifier(Identifier {
+                                        typ: "Identifier",
+                                        name: Cow::from("b"),
+                                        type_annotation: None,
+                                        optional: false
+                                    })
+                                ))
+                            })),
+                            right: Box::new(Expression::LeftHandSideExpression(
+                                LeftHandSideExpression::Identifier(Identifier {
+                                    typ: "Identifier",
+                                    name: Cow::from("f"),
+                                    type_annotation: None,
+                                    optional: false
+                                })
+                            ))
+                        })),
+                        right: Box::new(Expression::LeftHandSideExpression(
+                            LeftHandSideExpression::Identifier(Identifier {
+                                typ: "Identifier",
+                                name: Cow::from("g"),
+                                type_annotation: None,
+                                optional: false
+                            })
+                        ))
+                    })
+                })]
+            }
+        )
+    }
+
+    #[test]
+    fn adds_right_associative_operators() {
+        let mut spec = TokenSpec::default();
+        spec.insert_rule("BITWISE_OR", r"\|>", "PIPELINE").unwrap();
+        let mut options = ParserOptions::default();
+        options.binary_operators.push(BinaryOperator {
+            associativity: Associativity::Right,
+            ..BinaryOperator::binary("PIPELINE", 1)
+        });
+        let mut parser = init_with(options);
+        parser.tokenizer = Tokenizer::with_spec(Arc::new(spec));
+
+        let result = parser.parse("a |> f |> g");
+
+        assert_eq!(
+            result,
+            Program {
+                typ: "Program",
+                hashbang: None,
+                body: vec![Statement::ExpressionStatement(ExpressionStatement {
+                    typ: "ExpressionStatement",
+                    expression: Expression::BinaryExpression(BinaryExpression {
+                        typ: "BinaryExpression",
+                        operator: Cow::from("|>"),
+                        left: Box::new(Expression::LeftHandSideExpression(
+                            LeftHandSideExpression::Identifier(Identifier {
+                                typ: "Identifier",
+                                name: Cow::from("a"),
+                                type_annotation: None,
+                                optional: false
+                            })
+                        )),
+                        right: Box::new(Expression::BinaryExpression(BinaryExpression {
+                            typ: "BinaryExpression",
+                            operator: Cow::from("|>"),
+                            left: Box::new(Expression::LeftHandSideExpression(
+                                LeftHandSideExpression::Identifier(Identifier {
+                                    typ: "Identifier",
+                                    name: Cow::from("f"),
+                                    type_annotation: None,
+                                    optional: false
+                                })
+                            )),
+                            right: Box::new(Expression::LeftHandSideExpression(
+                                LeftHandSideExpression::Identifier(Identifier {
+                                    typ: "Identifier",
+                                    name: Cow::from("g"),
+                                    type_annotation: None,
+                                    optional: false
+                                })
+                            ))
+                        }))
+                    })
+                })]
+            }
+        )
+    }
+
+    #[test]
+    fn adds_keyword_operators() {
+        let mut spec = TokenSpec::default();
+        spec.add_keyword("in");
+        let mut options = ParserOptions::default();
+        options
+            .binary_operators
+            .push(BinaryOperator::binary("in", 7));
+        let mut parser = init_with(options);
+        parser.tokenizer = Tokenizer::with_spec(Arc::new(spec));
+
+        let result = parser.parse("a in b && c");
+
+        assert_eq!(
+            result,
+            Program {
+                typ: "Program",
+                hashbang: None,
+                body: vec![Statement::ExpressionStatement(ExpressionStatement {
+                    typ: "ExpressionStatement",
+                    expression: Expression::LogicalExpression(LogicalExpression {
+                        typ: "LogicalExpression",
+                        operator: Cow::from("&&"),
+                        left: Box::new(Expression::BinaryExpression(BinaryExpression {
+                            typ: "BinaryExpression",
+                            operator: Cow::from("in"),
+                            left: Box::new(Expression::LeftHandSideExpression(
+                                LeftHandSideExpression::Identifier(Identifier {
+                                    typ: "Identifier",
+                                    name: Cow::from("a"),
+                                    type_annotation: None,
+                                    optional: false
+                                })
+                            )),
+                            right: Box::new(Expression::LeftHandSideExpression(
+                                LeftHandSideExpression::Identifier(Identifier {
+                                    typ: "Identifier",
+                                    name: Cow::from("b"),
+                                    type_annotation: None,
+                                    optional: false
+                                })
+                            ))
+                        })),
+                        right: Box::new(Expression::LeftHandSideExpression(
+                            LeftHandSideExpression::Identifier(Identifier {
+                                typ: "Identifier",
+                                name: Cow::from("c"),
+                                type_annotation: None,
+                                optional: false
+                            })
+                        ))
+                    })
+                })]
+            }
+        )
+    }
+
+    #[test]
+    fn changes_operator_precedence() {
+        let mut options = ParserOptions::default();
+        for operator in options.binary_operators.iter_mut() {
+            if operator.token == "ADDITIVE_OPERATOR" {
+                operator.precedence = 11;
+            }
+        }
+        let mut parser = init_with(options);
+
+        let result = parser.parse("a * b + c");
+
+        assert_eq!(
+            result,
+            Program {
+                typ: "Program",
+                hashbang: None,
+                body: vec![Statement::ExpressionStatement(ExpressionStatement {
+                    typ: "ExpressionStatement",
+                    expression: Expression::BinaryExpression(BinaryExpression {
+                        typ: "BinaryExpression",
+                        operator: Cow::from("*"),
+                        left: Box::new(Expression::LeftHandSideExpression(
+                            LeftHandSideExpression::Identifier(Identifier {
+                                typ: "Identifier",
+                                name: Cow::from("a"),
+                                type_annotation: None,
+                                optional: false
+                            })
+                        )),
+                        right: Box::new(Expression::BinaryExpression(BinaryExpression {
+                            typ: "BinaryExpression",
+                            operator: Cow::from("+"),
+                            left: Box::new(Expression::LeftHandSideExpression(
+                                LeftHandSideExpression::Identifier(Identifier {
+                                    typ: "Identifier",
+                                    name: Cow::from("b"),
+                                    type_annotation: None,
+                                    optional: false
+                                })
+                            )),
+                            right: Box::new(Expression::LeftHandSideExpression(
+                                LeftHandSideExpression::Identifier(Identifier {
+                                    typ: "Identifier",
+                                    name: Cow::from("c"),
+                                    type_annotation: None,
+                                    optional: false
+                                })
+                            ))
+                        }))
+                    })
+                })]
+            }
+        )
+    }
+
+    #[test]
+    fn groups_left_associative_operators_of_the_highest_precedence() {
+        let mut spec = TokenSpec::default();
+        spec.insert_rule("BITWISE_OR", r"\|>", "PIPELINE").unwrap();
+        let mut options = ParserOptions::default();
+        options
+            .binary_operators
+            .push(BinaryOperator::binary("PIPELINE", u8::MAX));
+        let mut parser = init_with(options);
+        parser.tokenizer = Tokenizer::with_spec(Arc::new(spec));
+
+        let result = parser.parse("a |> b |> c");
+
+        let expression = match &result.body[0] {
+            Statement::ExpressionStatement(statement) => &statement.expression,
+            statement => panic!("Unexpected statement: {:?}", statement),
+        };
+        match expression {
+            Expression::BinaryExpression(expression) => {
+                assert!(matches!(*expression.left, Expression::BinaryExpression(_)))
+            }
+            expression => panic!("Unexpected expression: {:?}", expression),
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "Unexpected token: BITWISE_XOR, expected: ;")]
+    fn removes_operators() {
+        let mut options = ParserOptions::default();
+        options
+            .binary_operators
+            .retain(|operator| operator.token != "BITWISE_XOR");
+        let mut parser = init_with(options);
+
+        parser.parse("a ^ b;");
+    }
+}
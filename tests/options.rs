@@ -0,0 +1,401 @@
+#[cfg(test)]
+mod test {
+    use letter_rdp_rust::*;
+    use std::borrow::Cow;
+    #[test]
+    fn accepts_es5_syntax() {
+        let mut parser = init_with(ParserOptions {
+            ecma_version: EcmaVersion::Es5,
+            ..ParserOptions::default()
+        });
+
+        let result = parser.parse("var a = b || c; function f() { return a; }");
+
+        assert_eq!(
+            result,
+            Program {
+                typ: "Program",
+                hashbang: None,
+                body: vec![
+                    Statement::VariableStatement(VariableStatement {
+                        typ: "VariableStatement",
+                        kind: "var",
+                        declarations: vec![VariableDeclaration {
+                            typ: "VariableDeclaration",
+                            id: Identifier {
+                                typ: "Identifier",
+                                name: Cow::from("a"),
+                                type_annotation: None,
+                                optional: false
+                            },
+                            init: Some(Expression::LogicalExpression(LogicalExpression {
+                                typ: "LogicalExpression",
+                                operator: Cow::from("||"),
+                                left: Box::new(Expression::LeftHandSideExpression(
+                                    LeftHandSideExpression::Identifier(Identifier {
+                                        typ: "Identifier",
+                                        name: Cow::from("b"),
+                                        type_annotation: None,
+                                        optional: false
+                                    })
+                                )),
+                                right: Box::new(Expression::LeftHandSideExpression(
+                                    LeftHandSideExpression::Identifier(Identifier {
+                                        typ: "Identifier",
+                                        name: Cow::from("c"),
+                                        type_annotation: None,
+                                        optional: false
+                                    })
+                                ))
+                            }))
+                        }]
+                    }),
+                    Statement::FunctionDeclaration(FunctionDeclaration {
+                        typ: "FunctionDeclaration",
+                        id: Identifier {
+                            typ: "Identifier",
+                            name: Cow::from("f"),
+                            type_annotation: None,
+                            optional: false
+                        },
+                        params: vec![],
+                        body: BlockStatement {
+                            typ: "BlockStatement",
+                            body: vec![Statement::ReturnStatement(ReturnStatement {
+                                typ: "ReturnStatement",
+                                argument: Some(Expression::LeftHandSideExpression(
+                                    LeftHandSideExpression::Identifier(Identifier {
+                                        typ: "Identifier",
+                                        name: Cow::from("a"),
+                                        type_annotation: None,
+                                        optional: false
+                                    })
+                                ))
+                            })]
+                        },
+                        is_async: false,
+                        is_generator: false,
+                        return_type: None
+                    })
+                ]
+            }
+        )
+    }
+
+    #[test]
+    #[should_panic(expected = "'let' is not available in ES5")]
+    fn rejects_let_in_es5() {
+        let mut parser = init_with(ParserOptions {
+            ecma_version: EcmaVersion::Es5,
+            ..ParserOptions::default()
+        });
+
+        parser.parse("let a = 1;");
+    }
+
+    #[test]
+    #[should_panic(expected = "'class' is not available in ES5")]
+    fn rejects_class_in_es5() {
+        let mut parser = init_with(ParserOptions {
+            ecma_version: EcmaVersion::Es5,
+            ..ParserOptions::default()
+        });
+
+        parser.parse("class A {}");
+    }
+
+    #[test]
+    #[should_panic(expected = "'async' is not available in ES5")]
+    fn rejects_async_function_in_es5() {
+        let mut parser = init_with(ParserOptions {
+            ecma_version: EcmaVersion::Es5,
+            ..ParserOptions::default()
+        });
+
+        parser.parse("async function f() {}");
+    }
+
+    #[test]
+    #[should_panic(expected = "'function*' is not available in ES5")]
+    fn rejects_generator_in_es5() {
+        let mut parser = init_with(ParserOptions {
+            ecma_version: EcmaVersion::Es5,
+            ..ParserOptions::default()
+        });
+
+        parser.parse("function* g() {}");
+    }
+
+    #[test]
+    #[should_panic(expected = "'**' is not available in ES5")]
+    fn rejects_exponentiation_in_es5() {
+        let mut parser = init_with(ParserOptions {
+            ecma_version: EcmaVersion::Es5,
+            ..ParserOptions::default()
+        });
+
+        parser.parse("a ** b;");
+    }
+
+    #[test]
+    #[should_panic(expected = "'?.' is not available in ES5")]
+    fn rejects_optional_chaining_in_es5() {
+        let mut parser = init_with(ParserOptions {
+            ecma_version: EcmaVersion::Es5,
+            ..ParserOptions::default()
+        });
+
+        parser.parse("a?.b;");
+    }
+
+    #[test]
+    #[should_panic(expected = "'??' is not available in ES5")]
+    fn rejects_nullish_coalescing_in_es5() {
+        let mut parser = init_with(ParserOptions {
+            ecma_version: EcmaVersion::Es5,
+            ..ParserOptions::default()
+        });
+
+        parser.parse("a ?? b;");
+    }
+
+    #[test]
+    #[should_panic(expected = "'0b' is not available in ES5")]
+    fn rejects_binary_literal_in_es5() {
+        let mut parser = init_with(ParserOptions {
+            ecma_version: EcmaVersion::Es5,
+            ..ParserOptions::default()
+        });
+
+        parser.parse("0b1;");
+    }
+
+    #[test]
+    #[should_panic(expected = "'0O' is not available in ES5")]
+    fn rejects_octal_literal_in_es5() {
+        let mut parser = init_with(ParserOptions {
+            ecma_version: EcmaVersion::Es5,
+            ..ParserOptions::default()
+        });
+
+        parser.parse("0O7;");
+    }
+
+    #[test]
+    #[should_panic(expected = "'_' is not available in ES5")]
+    fn rejects_numeric_separators_in_es5() {
+        let mut parser = init_with(ParserOptions {
+            ecma_version: EcmaVersion::Es5,
+            ..ParserOptions::default()
+        });
+
+        parser.parse("1_000;");
+    }
+
+    #[test]
+    #[should_panic(expected = "'/u' is not available in ES5")]
+    fn rejects_unicode_regexp_flag_in_es5() {
+        let mut parser = init_with(ParserOptions {
+            ecma_version: EcmaVersion::Es5,
+            ..ParserOptions::default()
+        });
+
+        parser.parse("/a/gu;");
+    }
+
+    #[test]
+    #[should_panic(expected = "'/y' is not available in ES5")]
+    fn rejects_sticky_regexp_flag_in_es5() {
+        let mut parser = init_with(ParserOptions {
+            ecma_version: EcmaVersion::Es5,
+            ..ParserOptions::default()
+        });
+
+        parser.parse("/a/y;");
+    }
+
+    #[test]
+    #[should_panic(expected = "'\\u{' is not available in ES5")]
+    fn rejects_code_point_escape_in_string_in_es5() {
+        let mut parser = init_with(ParserOptions {
+            ecma_version: EcmaVersion::Es5,
+            ..ParserOptions::default()
+        });
+
+        parser.parse("'\\u{61}';");
+    }
+
+    #[test]
+    #[should_panic(expected = "'\\u{' is not available in ES5")]
+    fn rejects_code_point_escape_in_identifier_in_es5() {
+        let mut parser = init_with(ParserOptions {
+            ecma_version: EcmaVersion::Es5,
+            ..ParserOptions::default()
+        });
+
+        parser.parse("\\u{61};");
+    }
+
+    #[test]
+    fn accepts_es5_literals() {
+        let mut parser = init_with(ParserOptions {
+            ecma_version: EcmaVersion::Es5,
+            ..ParserOptions::default()
+        });
+
+        let result = parser.parse("0x1F; 1e3; /a/gim; '\\u0061 \\\\u{61}'; \\u0061;");
+
+        assert_eq!(result.body.len(), 5)
+    }
+
+    #[test]
+    #[should_panic(expected = "'import' is not available in ES5")]
+    fn rejects_modules_in_es5() {
+        let mut parser = init_with(ParserOptions {
+            ecma_version: EcmaVersion::Es5,
+            source_type: SourceType::Module,
+            ..ParserOptions::default()
+        });
+
+        parser.parse("import x from \"m\";");
+    }
+
+    #[test]
+    #[should_panic(expected = "Unexpected strict mode reserved word 'private'")]
+    fn parses_strict_mode_code_when_set() {
+        let mut parser = init_with(ParserOptions {
+            strict: true,
+            ..ParserOptions::default()
+        });
+
+        parser.parse("var private = 1;");
+    }
+
+    #[test]
+    fn inserts_semicolons_by_default() {
+        let mut parser = init();
+
+        let result = parser.parse("a\nb");
+
+        assert_eq!(
+            result,
+            Program {
+                typ: "Program",
+                hashbang: None,
+                body: vec![
+                    Statement::ExpressionStatement(ExpressionStatement {
+                        typ: "ExpressionStatement",
+                        expression: Expression::LeftHandSideExpression(
+                            LeftHandSideExpression::Identifier(Identifier {
+                                typ: "Identifier",
+                                name: Cow::from("a"),
+                                type_annotation: None,
+                                optional: false
+                            })
+                        )
+                    }),
+                    Statement::ExpressionStatement(ExpressionStatement {
+                        typ: "ExpressionStatement",
+                        expression: Expression::LeftHandSideExpression(
+                            LeftHandSideExpression::Identifier(Identifier {
+                                typ: "Identifier",
+                                name: Cow::from("b"),
+                                type_annotation: None,
+                                optional: false
+                            })
+                        )
+                    })
+                ]
+            }
+        )
+    }
+
+    #[test]
+    #[should_panic(expected = "Unexpected token: IDENTIFIER, expected: ;")]
+    fn requires_semicolons_without_automatic_insertion() {
+        let mut parser = init_with(ParserOptions {
+            automatic_semicolon_insertion: false,
+            ..ParserOptions::default()
+        });
+
+        parser.parse("a\nb");
+    }
+
+    #[test]
+    fn parses_explicit_semicolons_without_automatic_insertion() {
+        let mut parser = init_with(ParserOptions {
+            automatic_semicolon_insertion: false,
+            ..ParserOptions::default()
+        });
+
+        let result = parser.parse("a;\nb;");
+
+        assert_eq!(
+            result,
+            Program {
+                typ: "Program",
+                hashbang: None,
+                body: vec![
+                    Statement::ExpressionStatement(ExpressionStatement {
+                        typ: "ExpressionStatement",
+                        expression: Expression::LeftHandSideExpression(
+                            LeftHandSideExpression::Identifier(Identifier {
+                                typ: "Identifier",
+                                name: Cow::from("a"),
+                                type_annotation: None,
+                                optional: false
+                            })
+                        )
+                    }),
+                    Statement::ExpressionStatement(ExpressionStatement {
+                        typ: "ExpressionStatement",
+                        expression: Expression::LeftHandSideExpression(
+                            LeftHandSideExpression::Identifier(Identifier {
+                                typ: "Identifier",
+                                name: Cow::from("b"),
+                                type_annotation: None,
+                                optional: false
+                            })
+                        )
+                    })
+                ]
+            }
+        )
+    }
+
+    #[test]
+    fn allows_return_outside_function_when_set() {
+        let mut parser = init_with(ParserOptions {
+            allow_return_outside_function: true,
+            ..ParserOptions::default()
+        });
+
+        let result = parser.parse("return 1;");
+
+        assert_eq!(
+            result,
+            Program {
+                typ: "Program",
+                hashbang: None,
+                body: vec![Statement::ReturnStatement(ReturnStatement {
+                    typ: "ReturnStatement",
+                    argument: Some(Expression::Literal(Literal::NumericLiteral(
+                        NumericLiteral {
+                            typ: "NumericLiteral",
+                            value: 1.0,
+                            raw: Cow::from("1")
+                        }
+                    )))
+                })]
+            }
+        )
+    }
+
+    #[test]
+    #[should_panic(expected = "Illegal return statement")]
+    fn rejects_return_outside_function_by_default() {
+        let mut parser = init();
+
+        parser.parse("return 1;");
+    }
+}
@@ -0,0 +1,239 @@
+#[cfg(test)]
+mod test {
+    use letter_rdp_rust::*;
+    use std::borrow::Cow;
+    #[test]
+    fn handles_regexp_literal() {
+        let mut parser = init();
+
+        let result = parser.parse("/ab+c/gi;");
+
+        assert_eq!(
+            result,
+            Program {
+                typ: "Program",
+                hashbang: None,
+                body: vec![Statement::ExpressionStatement(ExpressionStatement {
+                    typ: "ExpressionStatement",
+                    expression: Expression::Literal(Literal::RegExpLiteral(RegExpLiteral {
+                        typ: "RegExpLiteral",
+                        pattern: Cow::from("ab+c"),
+                        flags: Cow::from("gi"),
+                        raw: Cow::from("/ab+c/gi")
+                    }))
+                })]
+            }
+        )
+    }
+
+    #[test]
+    fn handles_slashes_in_class_and_escapes() {
+        let mut parser = init();
+
+        let result = parser.parse(r#"x = /[/]\/=/;"#);
+
+        assert_eq!(
+            result,
+            Program {
+                typ: "Program",
+                hashbang: None,
+                body: vec![Statement::ExpressionStatement(ExpressionStatement {
+                    typ: "ExpressionStatement",
+                    expression: Expression::AssignmentExpression(AssignmentExpression {
+                        typ: "AssignmentExpression",
+                        operator: Cow::from("="),
+                        left: Box::new(Expression::LeftHandSideExpression(
+                            LeftHandSideExpression::Identifier(Identifier {
+                                typ: "Identifier",
+                                name: Cow::from("x"),
+                                type_annotation: None,
+                                optional: false
+                            })
+                        )),
+                        right: Box::new(Expression::Literal(Literal::RegExpLiteral(
+                            RegExpLiteral {
+                                typ: "RegExpLiteral",
+                                pattern: Cow::from("[/]\\/="),
+                                flags: Cow::from(""),
+                                raw: Cow::from("/[/]\\/=/")
+                            }
+                        )))
+                    })
+                })]
+            }
+        )
+    }
+
+    #[test]
+    fn handles_regexp_starting_with_equals() {
+        let mut parser = init();
+
+        let result = parser.parse("f(/=a/);");
+
+        assert_eq!(
+            result,
+            Program {
+                typ: "Program",
+                hashbang: None,
+                body: vec![Statement::ExpressionStatement(ExpressionStatement {
+                    typ: "ExpressionStatement",
+                    expression: Expression::LeftHandSideExpression(
+                        LeftHandSideExpression::CallExpression(CallExpression {
+                            typ: "CallExpression",
+                            callee: Box::new(Expression::LeftHandSideExpression(
+                                LeftHandSideExpression::Identifier(Identifier {
+                                    typ: "Identifier",
+                                    name: Cow::from("f"),
+                                    type_annotation: None,
+                                    optional: false
+                                })
+                            )),
+                            arguments: vec![Expression::Literal(Literal::RegExpLiteral(
+                                RegExpLiteral {
+                                    typ: "RegExpLiteral",
+                                    pattern: Cow::from("=a"),
+                                    flags: Cow::from(""),
+                                    raw: Cow::from("/=a/")
+                                }
+                            ))],
+                            optional: false
+                        })
+                    )
+                })]
+            }
+        )
+    }
+
+    #[test]
+    fn handles_division_after_operand() {
+        let mut parser = init();
+
+        let result = parser.parse("a / b / c;");
+
+        assert_eq!(
+            result,
+            Program {
+                typ: "Program",
+                hashbang: None,
+                body: vec![Statement::ExpressionStatement(ExpressionStatement {
+                    typ: "ExpressionStatement",
+                    expression: Expression::BinaryExpression(BinaryExpression {
+                        typ: "BinaryExpression",
+                        operator: Cow::from("/"),
+                        left: Box::new(Expression::BinaryExpression(BinaryExpression {
+                            typ: "BinaryExpression",
+                            operator: Cow::from("/"),
+                            left: Box::new(Expression::LeftHandSideExpression(
+                                LeftHandSideExpression::Identifier(Identifier {
+                                    typ: "Identifier",
+                                    name: Cow::from("a"),
+                                    type_annotation: None,
+                                    optional: false
+                                })
+                            )),
+                            right: Box::new(Expression::LeftHandSideExpression(
+                                LeftHandSideExpression::Identifier(Identifier {
+                                    typ: "Identifier",
+                                    name: Cow::from("b"),
+                                    type_annotation: None,
+                                    optional: false
+                                })
+                            ))
+                        })),
+                        right: Box::new(Expression::LeftHandSideExpression(
+                            LeftHandSideExpression::Identifier(Identifier {
+                                typ: "Identifier",
+                                name: Cow::from("c"),
+                                type_annotation: None,
+                                optional: false
+                            })
+                        ))
+                    })
+                })]
+            }
+        )
+    }
+
+    #[test]
+    fn handles_member_access_on_regexp() {
+        let mut parser = init();
+
+        let result = parser.parse("/a/.test(s);");
+
+        assert_eq!(
+            result,
+            Program {
+                typ: "Program",
+                hashbang: None,
+                body: vec![Statement::ExpressionStatement(ExpressionStatement {
+                    typ: "ExpressionStatement",
+                    expression: Expression::LeftHandSideExpression(
+                        LeftHandSideExpression::CallExpression(CallExpression {
+                            typ: "CallExpression",
+                            callee: Box::new(Expression::LeftHandSideExpression(
+                                LeftHandSideExpression::MemberExpression(MemberExpression {
+                                    typ: "MemberExpression",
+                                    object: Box::new(Expression::Literal(Literal::RegExpLiteral(
+                                        RegExpLiteral {
+                                            typ: "RegExpLiteral",
+                                            pattern: Cow::from("a"),
+                                            flags: Cow::from(""),
+                                            raw: Cow::from("/a/")
+                                        }
+                                    ))),
+                                    property: Box::new(Expression::LeftHandSideExpression(
+                                        LeftHandSideExpression::Identifier(Identifier {
+                                            typ: "Identifier",
+                                            name: Cow::from("test"),
+                                            type_annotation: None,
+                                            optional: false
+                                        })
+                                    )),
+                                    computed: false,
+                                    optional: false
+                                })
+                            )),
+                            arguments: vec![Expression::LeftHandSideExpression(
+                                LeftHandSideExpression::Identifier(Identifier {
+                                    typ: "Identifier",
+                                    name: Cow::from("s"),
+                                    type_annotation: None,
+                                    optional: false
+                                })
+                            )],
+                            optional: false
+                        })
+                    )
+                })]
+            }
+        )
+    }
+
+    #[test]
+    #[should_panic(expected = "Unterminated regular expression literal (2:5)")]
+    fn rejects_unterminated_regexp() {
+        let mut parser = init();
+
+        parser.parse(
+            "x = 1;
+x = /ab
+/;",
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Invalid regular expression flags (1:4)")]
+    fn rejects_duplicate_regexp_flags() {
+        let mut parser = init();
+
+        parser.parse("/a/gig;");
+    }
+
+    #[test]
+    #[should_panic(expected = "Invalid regular expression flags (1:4)")]
+    fn rejects_unknown_regexp_flags() {
+        let mut parser = init();
+
+        parser.parse("/a/x;");
+    }
+}
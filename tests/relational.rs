@@ -1,6 +1,7 @@
 #[cfg(test)]
 mod test {
     use letter_rdp_rust::*;
+    use std::borrow::Cow;
     #[test]
     fn handles_relational_gt_expression() {
         let mut parser = init();
@@ -11,21 +12,25 @@ mod test {
             result,
             Program {
                 typ: "Program",
+                hashbang: None,
                 body: vec![Statement::ExpressionStatement(ExpressionStatement {
                     typ: "ExpressionStatement",
                     expression: Expression::BinaryExpression(BinaryExpression {
                         typ: "BinaryExpression",
-                        operator: String::from(">"),
+                        operator: Cow::from(">"),
                         left: Box::new(Expression::LeftHandSideExpression(
                             LeftHandSideExpression::Identifier(Identifier {
                                 typ: "Identifier",
-                                name: String::from("x"),
+                                name: Cow::from("x"),
+                                type_annotation: None,
+                                optional: false,
                             })
                         )),
                         right: Box::new(Expression::Literal(Literal::NumericLiteral(
                             NumericLiteral {
                                 typ: "NumericLiteral",
-                                value: 0
+                                value: 0.0,
+                                raw: Cow::from("0")
                             }
                         )))
                     })
@@ -44,21 +49,25 @@ mod test {
             result,
             Program {
                 typ: "Program",
+                hashbang: None,
                 body: vec![Statement::ExpressionStatement(ExpressionStatement {
                     typ: "ExpressionStatement",
                     expression: Expression::BinaryExpression(BinaryExpression {
                         typ: "BinaryExpression",
-                        operator: String::from(">="),
+                        operator: Cow::from(">="),
                         left: Box::new(Expression::LeftHandSideExpression(
                             LeftHandSideExpression::Identifier(Identifier {
                                 typ: "Identifier",
-                                name: String::from("x"),
+                                name: Cow::from("x"),
+                                type_annotation: None,
+                                optional: false,
                             })
                         )),
                         right: Box::new(Expression::Literal(Literal::NumericLiteral(
                             NumericLiteral {
                                 typ: "NumericLiteral",
-                                value: 0
+                                value: 0.0,
+                                raw: Cow::from("0")
                             }
                         )))
                     })
@@ -0,0 +1,179 @@
+#[cfg(test)]
+mod test {
+    use letter_rdp_rust::*;
+    use std::borrow::Cow;
+    #[test]
+    fn handles_sequence_of_assignments() {
+        let mut parser = init();
+
+        let result = parser.parse("a = 1, b = 2;");
+
+        assert_eq!(
+            result,
+            Program {
+                typ: "Program",
+                hashbang: None,
+                body: vec![Statement::ExpressionStatement(ExpressionStatement {
+                    typ: "ExpressionStatement",
+                    expression: Expression::SequenceExpression(SequenceExpression {
+                        typ: "SequenceExpression",
+                        expressions: vec![
+                            Expression::AssignmentExpression(AssignmentExpression {
+                                typ: "AssignmentExpression",
+                                operator: Cow::from("="),
+                                left: Box::new(Expression::LeftHandSideExpression(
+                                    LeftHandSideExpression::Identifier(Identifier {
+                                        typ: "Identifier",
+                                        name: Cow::from("a"),
+                                        type_annotation: None,
+                                        optional: false
+                                    })
+                                )),
+                                right: Box::new(Expression::Literal(Literal::NumericLiteral(
+                                    NumericLiteral {
+                                        typ: "NumericLiteral",
+                                        value: 1.0,
+                                        raw: Cow::from("1")
+                                    }
+                                )))
+                            }),
+                            Expression::AssignmentExpression(AssignmentExpression {
+                                typ: "AssignmentExpression",
+                                operator: Cow::from("="),
+                                left: Box::new(Expression::LeftHandSideExpression(
+                                    LeftHandSideExpression::Identifier(Identifier {
+                                        typ: "Identifier",
+                                        name: Cow::from("b"),
+                                        type_annotation: None,
+                                        optional: false
+                                    })
+                                )),
+                                right: Box::new(Expression::Literal(Literal::NumericLiteral(
+                                    NumericLiteral {
+                                        typ: "NumericLiteral",
+                                        value: 2.0,
+                                        raw: Cow::from("2")
+                                    }
+                                )))
+                            })
+                        ]
+                    })
+                })]
+            }
+        )
+    }
+
+    #[test]
+    fn handles_parenthesized_sequence() {
+        let mut parser = init();
+
+        let result = parser.parse("x = (a, b);");
+
+        assert_eq!(
+            result,
+            Program {
+                typ: "Program",
+                hashbang: None,
+                body: vec![Statement::ExpressionStatement(ExpressionStatement {
+                    typ: "ExpressionStatement",
+                    expression: Expression::AssignmentExpression(AssignmentExpression {
+                        typ: "AssignmentExpression",
+                        operator: Cow::from("="),
+                        left: Box::new(Expression::LeftHandSideExpression(
+                            LeftHandSideExpression::Identifier(Identifier {
+                                typ: "Identifier",
+                                name: Cow::from("x"),
+                                type_annotation: None,
+                                optional: false
+                            })
+                        )),
+                        right: Box::new(Expression::SequenceExpression(SequenceExpression {
+                            typ: "SequenceExpression",
+                            expressions: vec![
+                                Expression::LeftHandSideExpression(
+                                    LeftHandSideExpression::Identifier(Identifier {
+                                        typ: "Identifier",
+                                        name: Cow::from("a"),
+                                        type_annotation: None,
+                                        optional: false
+                                    })
+                                ),
+                                Expression::LeftHandSideExpression(
+                                    LeftHandSideExpression::Identifier(Identifier {
+                                        typ: "Identifier",
+                                        name: Cow::from("b"),
+                                        type_annotation: None,
+                                        optional: false
+                                    })
+                                )
+                            ]
+                        }))
+                    })
+                })]
+            }
+        )
+    }
+
+    #[test]
+    fn keeps_arguments_separate() {
+        let mut parser = init();
+
+        let result = parser.parse("f(a, (b, c));");
+
+        assert_eq!(
+            result,
+            Program {
+                typ: "Program",
+                hashbang: None,
+                body: vec![Statement::ExpressionStatement(ExpressionStatement {
+                    typ: "ExpressionStatement",
+                    expression: Expression::LeftHandSideExpression(
+                        LeftHandSideExpression::CallExpression(CallExpression {
+                            typ: "CallExpression",
+                            callee: Box::new(Expression::LeftHandSideExpression(
+                                LeftHandSideExpression::Identifier(Identifier {
+                                    typ: "Identifier",
+                                    name: Cow::from("f"),
+                                    type_annotation: None,
+                                    optional: false
+                                })
+                            )),
+                            arguments: vec![
+                                Expression::LeftHandSideExpression(
+                                    LeftHandSideExpression::Identifier(Identifier {
+                                        typ: "Identifier",
+                                        name: Cow::from("a"),
+                                        type_annotation: None,
+                                        optional: false
+                                    })
+                                ),
+                                Expression::SequenceExpression(SequenceExpression {
+                                    typ: "SequenceExpression",
+                                    expressions: vec![
+                                        Expression::LeftHandSideExpression(
+                                            LeftHandSideExpression::Identifier(Identifier {
+                                                typ: "Identifier",
+                                                name: Cow::from("b"),
+                                                type_annotation: None,
+                                                optional: false
+                                            })
+                                        ),
+                                        Expression::LeftHandSideExpression(
+                                            LeftHandSideExpression::Identifier(Identifier {
+                                                typ: "Identifier",
+                                                name: Cow::from("c"),
+                                                type_annotation: None,
+                                                optional: false
+                                            })
+                                        )
+                                    ]
+                                })
+                            ],
+                            optional: false
+                        })
+                    )
+                })]
+            }
+        )
+    }
+}
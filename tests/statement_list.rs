@@ -1,6 +1,7 @@
 #[cfg(test)]
 mod test {
     use letter_rdp_rust::*;
+    use std::borrow::Cow;
     #[test]
     fn handles_multiple_statements() {
         let mut parser = init();
@@ -16,19 +17,22 @@ mod test {
             result,
             Program {
                 typ: "Program",
+                hashbang: None,
                 body: vec![
                     Statement::ExpressionStatement(ExpressionStatement {
                         typ: "ExpressionStatement",
                         expression: Expression::Literal(Literal::NumericLiteral(NumericLiteral {
                             typ: "NumericLiteral",
-                            value: 1
+                            value: 1.0,
+                            raw: Cow::from("1")
                         }))
                     }),
                     Statement::ExpressionStatement(ExpressionStatement {
                         typ: "ExpressionStatement",
                         expression: Expression::Literal(Literal::StringLiteral(StringLiteral {
                             typ: "StringLiteral",
-                            value: String::from("hello")
+                            value: Cow::from("hello"),
+                            raw: Cow::from("\"hello\"")
                         }))
                     })
                 ]
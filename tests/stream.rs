@@ -0,0 +1,313 @@
+#[cfg(test)]
+mod test {
+    use letter_rdp_rust::*;
+    use std::io::{self, Read};
+
+    // Hands out its bytes a few at a time, as a socket might.
+    struct Trickle {
+        bytes: Vec<u8>,
+        position: usize,
+        step: usize,
+    }
+
+    impl Trickle {
+        fn new(string: &str, step: usize) -> Self {
+            Self::from_bytes(string.as_bytes(), step)
+        }
+
+        fn from_bytes(bytes: &[u8], step: usize) -> Self {
+            Trickle {
+                bytes: bytes.to_vec(),
+                position: 0,
+                step,
+            }
+        }
+    }
+
+    impl Read for Trickle {
+        fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+            let end = self
+                .bytes
+                .len()
+                .min(self.position + self.step.min(buf.len()));
+            let len = end - self.position;
+            buf[..len].copy_from_slice(&self.bytes[self.position..end]);
+            self.position = end;
+            Ok(len)
+        }
+    }
+
+    struct Failing;
+
+    impl Read for Failing {
+        fn read(&mut self, _: &mut [u8]) -> io::Result<usize> {
+            Err(io::Error::other("disconnected"))
+        }
+    }
+
+    fn tokens(stream: &mut StreamTokenizer<Trickle>) -> Vec<(String, String, usize, usize, bool)> {
+        let mut tokens = Vec::new();
+        while let Some(token) = stream.next_token().unwrap() {
+            tokens.push((
+                token.typ.to_string(),
+                token.value.to_string(),
+                token.start,
+                token.end,
+                token.newline_before,
+            ));
+        }
+        tokens
+    }
+
+    fn token_error(stream: &mut StreamTokenizer<Trickle>) -> StreamError {
+        loop {
+            match stream.next_token() {
+                Ok(Some(_)) => {}
+                Ok(None) => panic!("Expected an error"),
+                Err(error) => return error,
+            }
+        }
+    }
+
+    fn statements(stream: &mut StatementStream<Trickle>) -> Vec<String> {
+        let mut statements = Vec::new();
+        stream
+            .for_each_statement(|statement| statements.push(format!("{:?}", statement)))
+            .unwrap();
+        statements
+    }
+
+    fn program(string: &str) -> Vec<String> {
+        let mut parser = init();
+        let program = parser.parse(string);
+        program
+            .body
+            .iter()
+            .map(|statement| format!("{:?}", statement))
+            .collect()
+    }
+
+    const SOURCE: &str = "var total = 0; // running sum
+function add(a, b) {
+    /* adds
+       them */
+    return a + b;
+}
+total = add(total, 'é')
+if (total) { total = total * 2; }
+";
+
+    #[test]
+    fn reads_the_tokens_of_the_tokenizer() {
+        let source = SOURCE.repeat(20);
+        let mut stream = StreamTokenizer::new(Trickle::new(&source, 7));
+        stream.window = 32;
+
+        let mut tokenizer = Tokenizer::new();
+        tokenizer.init(&source);
+        let expected: Vec<_> = tokenizer
+            .map(|token| {
+                (
+                    token.typ.to_string(),
+                    token.value.to_string(),
+                    token.start,
+                    token.end,
+                    token.newline_before,
+                )
+            })
+            .collect();
+
+        assert_eq!(tokens(&mut stream), expected)
+    }
+
+    #[test]
+    fn reads_characters_split_between_reads() {
+        let mut stream = StreamTokenizer::new(Trickle::new("a = 'ünï' + ü;", 1));
+
+        let values: Vec<String> = tokens(&mut stream)
+            .into_iter()
+            .map(|(_, value, ..)| value)
+            .collect();
+
+        assert_eq!(values, vec!["a", "=", "'ünï'", "+", "ü", ";"])
+    }
+
+    #[test]
+    fn reads_comments_longer_than_the_window() {
+        let source = format!(
+            "a /* {} */ b // {}\nc",
+            "* / ".repeat(50_000),
+            "x".repeat(200)
+        );
+        let mut stream = StreamTokenizer::new(Trickle::new(&source, 1000));
+        stream.window = 16;
+
+        let values: Vec<String> = tokens(&mut stream)
+            .into_iter()
+            .map(|(_, value, ..)| value)
+            .collect();
+
+        assert_eq!(values, vec!["a", "b", "c"])
+    }
+
+    #[test]
+    fn reads_strings_longer_than_the_window() {
+        let string = format!("'{}\\\n{}'", "\\' ".repeat(100), "x".repeat(100));
+        let source = format!("a = {};", string);
+        let mut stream = StreamTokenizer::new(Trickle::new(&source, 3));
+        stream.window = 16;
+
+        let values: Vec<String> = tokens(&mut stream)
+            .into_iter()
+            .map(|(_, value, ..)| value)
+            .collect();
+
+        assert_eq!(values, vec!["a", "=", string.as_str(), ";"])
+    }
+
+    #[test]
+    fn reports_unterminated_strings_longer_than_the_window() {
+        let source = format!("a = '{}\nb;", "x".repeat(100));
+        let mut stream = StreamTokenizer::new(Trickle::new(&source, 3));
+        stream.window = 16;
+
+        let error = token_error(&mut stream);
+
+        assert_eq!(error.to_string(), "Unterminated string literal (1:5)")
+    }
+
+    #[test]
+    fn reports_token_errors_in_the_whole_input() {
+        let mut stream = StreamTokenizer::new(Trickle::new("a;\nb;\nc + @;", 2));
+        stream.window = 4;
+
+        let error = token_error(&mut stream);
+
+        assert!(matches!(error, StreamError::Parse(_)));
+        assert_eq!(error.to_string(), "Unexpected token: @ (3:5)")
+    }
+
+    #[test]
+    fn parses_the_statements_of_the_program() {
+        let source = SOURCE.repeat(20);
+        let mut stream = StatementStream::new(Trickle::new(&source, 5));
+        stream.window = 16;
+
+        assert_eq!(statements(&mut stream), program(&source))
+    }
+
+    #[test]
+    fn reads_statements_longer_than_the_window() {
+        let source = format!("f(function () {{ {} }});\ng();", "a = a + 1;\n".repeat(100));
+        let mut stream = StatementStream::new(Trickle::new(&source, 64));
+        stream.window = 8;
+
+        assert_eq!(statements(&mut stream), program(&source))
+    }
+
+    #[test]
+    fn reads_comments_and_strings_longer_than_the_window() {
+        let source = format!(
+            "a /* {} */;\nb = '{}';",
+            "* / ".repeat(100),
+            "\\' ".repeat(100)
+        );
+        let mut stream = StatementStream::new(Trickle::new(&source, 3));
+        stream.window = 16;
+
+        assert_eq!(statements(&mut stream), program(&source))
+    }
+
+    #[test]
+    fn hands_statements_over_one_at_a_time() {
+        let mut stream = StatementStream::new(Trickle::new("a; if (b) c;\nlet d", 1));
+
+        let mut kinds = Vec::new();
+        while let Some(kind) = stream
+            .next_statement(|statement| match statement {
+                Statement::ExpressionStatement(_) => "expression",
+                Statement::IfStatement(_) => "if",
+                Statement::VariableStatement(_) => "variable",
+                _ => "other",
+            })
+            .unwrap()
+        {
+            kinds.push(kind);
+        }
+
+        assert_eq!(kinds, vec!["expression", "if", "variable"])
+    }
+
+    #[test]
+    fn keeps_the_directive_prologue() {
+        let source = "'use strict';\n'a'\nb;\n'c';";
+        let mut stream = StatementStream::new(Trickle::new(source, 3));
+
+        assert_eq!(statements(&mut stream), program(source));
+        assert!(stream.options.strict)
+    }
+
+    #[test]
+    fn applies_strict_mode_to_later_statements() {
+        let mut stream = StatementStream::new(Trickle::new("'use strict';\nlet static;", 3));
+
+        let error = stream.for_each_statement(|_| {}).unwrap_err();
+
+        assert_eq!(
+            error.to_string(),
+            "Unexpected strict mode reserved word 'static'"
+        )
+    }
+
+    #[test]
+    fn exposes_hashbang() {
+        let source = "#!/usr/bin/env letter\n1;";
+        let mut stream = StatementStream::new(Trickle::new(source, 4));
+
+        assert_eq!(statements(&mut stream), program(source));
+        assert_eq!(stream.hashbang(), Some("/usr/bin/env letter"))
+    }
+
+    #[test]
+    fn handles_empty_input() {
+        let mut stream = StatementStream::new(Trickle::new(" // nothing\n", 4));
+
+        assert_eq!(statements(&mut stream), Vec::<String>::new());
+        assert_eq!(stream.hashbang(), None)
+    }
+
+    #[test]
+    fn reports_syntax_errors_in_the_whole_input() {
+        let mut stream = StatementStream::new(Trickle::new("a;\nb;\nc;\nd = 'e;", 2));
+        stream.window = 4;
+
+        let mut count = 0;
+        let error = stream.for_each_statement(|_| count += 1).unwrap_err();
+
+        assert_eq!(count, 3);
+        assert!(matches!(error, StreamError::Parse(_)));
+        assert_eq!(error.to_string(), "Unterminated string literal (4:5)")
+    }
+
+    #[test]
+    fn returns_read_errors() {
+        let mut stream = StatementStream::new(Failing);
+
+        let error = stream.next_statement(|_| ()).unwrap_err();
+
+        assert_eq!(error.to_string(), "disconnected")
+    }
+
+    #[test]
+    fn rejects_invalid_utf8() {
+        let mut stream = StreamTokenizer::new(Trickle::from_bytes(b"a + \xff;", 2));
+
+        let error = token_error(&mut stream);
+
+        assert!(matches!(
+            &error,
+            StreamError::Io(error) if error.kind() == io::ErrorKind::InvalidData
+        ));
+        assert_eq!(error.to_string(), "Invalid UTF-8 at offset 4")
+    }
+}
@@ -1,6 +1,7 @@
 #[cfg(test)]
 mod test {
     use letter_rdp_rust::*;
+    use std::borrow::Cow;
 
     #[test]
     fn prints_double_quotes_string() {
@@ -12,12 +13,15 @@ mod test {
             result,
             Program {
                 typ: "Program",
-                body: vec![Statement::ExpressionStatement(ExpressionStatement {
-                    typ: "ExpressionStatement",
-                    expression: Expression::Literal(Literal::StringLiteral(StringLiteral {
+                hashbang: None,
+                body: vec![Statement::Directive(Directive {
+                    typ: "Directive",
+                    expression: StringLiteral {
                         typ: "StringLiteral",
-                        value: String::from("a")
-                    }))
+                        value: Cow::from("a"),
+                        raw: Cow::from("\"a\"")
+                    },
+                    directive: Cow::from("a")
                 })]
             }
         )
@@ -33,12 +37,15 @@ mod test {
             result,
             Program {
                 typ: "Program",
-                body: vec![Statement::ExpressionStatement(ExpressionStatement {
-                    typ: "ExpressionStatement",
-                    expression: Expression::Literal(Literal::StringLiteral(StringLiteral {
+                hashbang: None,
+                body: vec![Statement::Directive(Directive {
+                    typ: "Directive",
+                    expression: StringLiteral {
                         typ: "StringLiteral",
-                        value: String::from("a")
-                    }))
+                        value: Cow::from("a"),
+                        raw: Cow::from("'a'")
+                    },
+                    directive: Cow::from("a")
                 })]
             }
         )
@@ -59,12 +66,15 @@ mod test {
             result,
             Program {
                 typ: "Program",
-                body: vec![Statement::ExpressionStatement(ExpressionStatement {
-                    typ: "ExpressionStatement",
-                    expression: Expression::Literal(Literal::StringLiteral(StringLiteral {
+                hashbang: None,
+                body: vec![Statement::Directive(Directive {
+                    typ: "Directive",
+                    expression: StringLiteral {
                         typ: "StringLiteral",
-                        value: String::from("a")
-                    }))
+                        value: Cow::from("a"),
+                        raw: Cow::from("'a'")
+                    },
+                    directive: Cow::from("a")
                 })]
             }
         )
@@ -87,14 +97,186 @@ mod test {
             result,
             Program {
                 typ: "Program",
-                body: vec![Statement::ExpressionStatement(ExpressionStatement {
-                    typ: "ExpressionStatement",
-                    expression: Expression::Literal(Literal::StringLiteral(StringLiteral {
+                hashbang: None,
+                body: vec![Statement::Directive(Directive {
+                    typ: "Directive",
+                    expression: StringLiteral {
                         typ: "StringLiteral",
-                        value: String::from("a")
-                    }))
+                        value: Cow::from("a"),
+                        raw: Cow::from("'a'")
+                    },
+                    directive: Cow::from("a")
                 })]
             }
         )
     }
+    #[test]
+    fn decodes_escape_sequences() {
+        let mut parser = init();
+
+        let result = parser.parse(r#""say \"hi\"\n\t\\ \'";"#);
+
+        assert_eq!(
+            result,
+            Program {
+                typ: "Program",
+                hashbang: None,
+                body: vec![Statement::Directive(Directive {
+                    typ: "Directive",
+                    expression: StringLiteral {
+                        typ: "StringLiteral",
+                        value: Cow::from("say \"hi\"\n\t\\ '"),
+                        raw: Cow::from(r#""say \"hi\"\n\t\\ \'""#)
+                    },
+                    directive: Cow::from(r#"say \"hi\"\n\t\\ \'"#)
+                })]
+            }
+        )
+    }
+
+    #[test]
+    fn decodes_hex_and_unicode_escapes() {
+        let mut parser = init();
+
+        let result = parser.parse(r#"'\x41B\u{1F600}😀';"#);
+
+        assert_eq!(
+            result,
+            Program {
+                typ: "Program",
+                hashbang: None,
+                body: vec![Statement::Directive(Directive {
+                    typ: "Directive",
+                    expression: StringLiteral {
+                        typ: "StringLiteral",
+                        value: Cow::from("AB\u{1F600}\u{1F600}"),
+                        raw: Cow::from(r#"'\x41B\u{1F600}😀'"#)
+                    },
+                    directive: Cow::from(r#"\x41B\u{1F600}😀"#)
+                })]
+            }
+        )
+    }
+
+    #[test]
+    fn drops_line_continuations() {
+        let mut parser = init();
+
+        let result = parser.parse("'a\\\nb';");
+
+        assert_eq!(
+            result,
+            Program {
+                typ: "Program",
+                hashbang: None,
+                body: vec![Statement::Directive(Directive {
+                    typ: "Directive",
+                    expression: StringLiteral {
+                        typ: "StringLiteral",
+                        value: Cow::from("ab"),
+                        raw: Cow::from("'a\\\nb'")
+                    },
+                    directive: Cow::from("a\\\nb")
+                })]
+            }
+        )
+    }
+
+    #[test]
+    #[should_panic(expected = "Unterminated string literal (2:9)")]
+    fn rejects_unterminated_string() {
+        let mut parser = init();
+
+        parser.parse("1;\n        \"abc;");
+    }
+
+    #[test]
+    #[should_panic(expected = "Unterminated string literal (1:1)")]
+    fn rejects_newline_in_string() {
+        let mut parser = init();
+
+        parser.parse("'a\nb';");
+    }
+
+    #[test]
+    #[should_panic(expected = "Invalid hexadecimal escape sequence (1:4)")]
+    fn rejects_invalid_hex_escape() {
+        let mut parser = init();
+
+        parser.parse(r#""ab\x4g";"#);
+    }
+
+    #[test]
+    #[should_panic(expected = "Invalid Unicode escape sequence (1:2)")]
+    fn rejects_out_of_range_unicode_escape() {
+        let mut parser = init();
+
+        parser.parse(r#"'\u{110000}';"#);
+    }
+
+    #[test]
+    fn decodes_lone_surrogates_to_the_replacement_character() {
+        let mut parser = init();
+
+        let result = parser.parse_expression(r"'a\uD800b\u{DC00}'");
+
+        match result {
+            Expression::Literal(Literal::StringLiteral(literal)) => {
+                assert_eq!(literal.value, "a\u{FFFD}b\u{FFFD}")
+            }
+            _ => panic!("Expected a string literal"),
+        }
+    }
+
+    #[test]
+    fn decodes_legacy_octal_escapes_outside_strict_mode() {
+        let mut parser = init();
+
+        let result = parser.parse_expression(r"'\1\101\400\08\9'");
+
+        match result {
+            Expression::Literal(Literal::StringLiteral(literal)) => {
+                assert_eq!(literal.value, "\u{1}A\u{20}0\u{0}89")
+            }
+            _ => panic!("Expected a string literal"),
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "Octal escape sequences are not allowed in strict mode (2:6)")]
+    fn rejects_octal_escapes_in_strict_mode() {
+        let mut parser = init();
+
+        parser.parse("'use strict';\na = '\\1';");
+    }
+
+    #[test]
+    fn borrows_values_without_escapes() {
+        let mut parser = init();
+
+        let result = parser.parse_expression("'plain'");
+
+        match result {
+            Expression::Literal(Literal::StringLiteral(literal)) => {
+                assert!(matches!(literal.value, Cow::Borrowed("plain")));
+                assert!(matches!(literal.raw, Cow::Borrowed("'plain'")));
+            }
+            _ => panic!("Expected a string literal"),
+        }
+    }
+
+    #[test]
+    fn allocates_decoded_values() {
+        let mut parser = init();
+
+        let result = parser.parse_expression(r"'a\tb'");
+
+        match result {
+            Expression::Literal(Literal::StringLiteral(literal)) => {
+                assert!(matches!(literal.value, Cow::Owned(ref value) if value == "a\tb"));
+                assert!(matches!(literal.raw, Cow::Borrowed(r"'a\tb'")));
+            }
+            _ => panic!("Expected a string literal"),
+        }
+    }
 }
@@ -0,0 +1,254 @@
+#[cfg(test)]
+mod test {
+    use letter_rdp_rust::*;
+    use std::borrow::Cow;
+    use std::sync::Arc;
+
+    fn types<'a>(tokenizer: &mut Tokenizer<'a>, string: &'a str) -> Vec<&'a str> {
+        tokenizer.init(string);
+        tokenizer.map(|token| token.typ).collect()
+    }
+
+    #[test]
+    fn tokenizes_with_the_default_spec() {
+        let mut tokenizer = Tokenizer::new();
+
+        assert_eq!(
+            types(&mut tokenizer, "let rule = a; // done"),
+            vec!["let", "IDENTIFIER", "SIMPLE_ASSIGN", "IDENTIFIER", ";"]
+        )
+    }
+
+    #[test]
+    fn registers_custom_keywords() {
+        let mut spec = TokenSpec::default();
+        spec.add_keyword("rule");
+        spec.add_keyword("when");
+        spec.add_keyword("then");
+        let mut tokenizer = Tokenizer::with_spec(Arc::new(spec));
+
+        assert_eq!(
+            types(&mut tokenizer, "rule a when b then c"),
+            vec![
+                "rule",
+                "IDENTIFIER",
+                "when",
+                "IDENTIFIER",
+                "then",
+                "IDENTIFIER"
+            ]
+        )
+    }
+
+    #[test]
+    fn removes_keywords() {
+        let mut spec = TokenSpec::default();
+        spec.remove_keyword("let");
+        let mut tokenizer = Tokenizer::with_spec(Arc::new(spec));
+
+        assert_eq!(types(&mut tokenizer, "let"), vec!["IDENTIFIER"])
+    }
+
+    #[test]
+    fn inserts_rules_before_existing_ones() {
+        let mut spec = TokenSpec::default();
+        spec.insert_rule("BITWISE_OR", r"\|>", "PIPELINE").unwrap();
+        let mut tokenizer = Tokenizer::with_spec(Arc::new(spec));
+
+        assert_eq!(
+            types(&mut tokenizer, "a |> f"),
+            vec!["IDENTIFIER", "PIPELINE", "IDENTIFIER"]
+        )
+    }
+
+    #[test]
+    fn removes_rules() {
+        let mut spec = TokenSpec::default();
+        spec.remove_rule("COMMENT");
+        let mut tokenizer = Tokenizer::with_spec(Arc::new(spec));
+
+        assert_eq!(
+            types(&mut tokenizer, "a // b"),
+            vec![
+                "IDENTIFIER",
+                "MULTIPLICATIVE_OPERATOR",
+                "MULTIPLICATIVE_OPERATOR",
+                "IDENTIFIER"
+            ]
+        )
+    }
+
+    #[test]
+    fn reorders_rules() {
+        let mut spec = TokenSpec::default();
+        spec.add_rule(r"[a-z]+(?:-[a-z]+)+", "KEBAB_NAME").unwrap();
+        spec.move_rule("KEBAB_NAME", "IDENTIFIER").unwrap();
+        let mut tokenizer = Tokenizer::with_spec(Arc::new(spec));
+
+        assert_eq!(
+            types(&mut tokenizer, "font-size - a"),
+            vec!["KEBAB_NAME", "ADDITIVE_OPERATOR", "IDENTIFIER"]
+        )
+    }
+
+    #[test]
+    fn skips_custom_trivia() {
+        let mut spec = TokenSpec::default();
+        spec.insert_rule("WHITESPACE", r"--[^\n]*", "LINE_COMMENT")
+            .unwrap();
+        spec.skip("LINE_COMMENT").unwrap();
+        let mut tokenizer = Tokenizer::with_spec(Arc::new(spec));
+        tokenizer.init("a -- note\nb");
+
+        let tokens: Vec<(&str, bool)> = tokenizer
+            .map(|token| (token.typ, token.newline_before))
+            .collect();
+
+        assert_eq!(tokens, vec![("IDENTIFIER", false), ("IDENTIFIER", true)])
+    }
+
+    #[test]
+    fn builds_a_spec_from_scratch() {
+        let mut spec = TokenSpec::new();
+        spec.add_rule(r"\s+", "WHITESPACE").unwrap();
+        spec.skip("WHITESPACE").unwrap();
+        spec.add_rule(r"[0-9]+", "NUMBER").unwrap();
+        spec.add_rule(r"[+*]", "OPERATOR").unwrap();
+        let mut tokenizer = Tokenizer::with_spec(Arc::new(spec));
+
+        assert_eq!(
+            types(&mut tokenizer, "1 + 2 * 3"),
+            vec!["NUMBER", "OPERATOR", "NUMBER", "OPERATOR", "NUMBER"]
+        )
+    }
+
+    #[test]
+    fn shares_a_spec_between_tokenizers() {
+        let mut spec = TokenSpec::default();
+        spec.add_keyword("rule");
+        let spec = Arc::new(spec);
+        let mut first = Tokenizer::with_spec(Arc::clone(&spec));
+        let mut second = Tokenizer::with_spec(Arc::clone(&spec));
+
+        assert_eq!(types(&mut first, "rule"), vec!["rule"]);
+        assert_eq!(types(&mut second, "rule"), vec!["rule"]);
+        assert_eq!(first.spec().keywords, spec.keywords)
+    }
+
+    #[test]
+    fn moves_tokenizers_and_parsers_between_threads() {
+        fn assert_send<T: Send>(_: &T) {}
+        let mut spec = TokenSpec::default();
+        spec.add_keyword("rule");
+        let tokenizer = Tokenizer::with_spec(Arc::new(spec));
+        let mut parser = init();
+        parser.tokenizer = tokenizer.clone();
+        assert_send(&tokenizer);
+        assert_send(&parser);
+
+        let handle = std::thread::spawn(move || {
+            let mut tokenizer = tokenizer;
+            let types = types(&mut tokenizer, "rule");
+            (types, parser.parse("a;").body.len())
+        });
+
+        assert_eq!(handle.join().unwrap(), (vec!["rule"], 1))
+    }
+
+    #[test]
+    fn parses_with_a_custom_spec() {
+        let mut spec = TokenSpec::default();
+        spec.insert_rule("WHITESPACE", r"--[^\n]*", "LINE_COMMENT")
+            .unwrap();
+        spec.skip("LINE_COMMENT").unwrap();
+        let mut parser = init();
+        parser.tokenizer = Tokenizer::with_spec(Arc::new(spec));
+
+        let result = parser.parse("a -- note\nb");
+
+        assert_eq!(
+            result,
+            Program {
+                typ: "Program",
+                hashbang: None,
+                body: vec![
+                    Statement::ExpressionStatement(ExpressionStatement {
+                        typ: "ExpressionStatement",
+                        expression: Expression::LeftHandSideExpression(
+                            LeftHandSideExpression::Identifier(Identifier {
+                                typ: "Identifier",
+                                name: Cow::from("a"),
+                                type_annotation: None,
+                                optional: false
+                            })
+                        )
+                    }),
+                    Statement::ExpressionStatement(ExpressionStatement {
+                        typ: "ExpressionStatement",
+                        expression: Expression::LeftHandSideExpression(
+                            LeftHandSideExpression::Identifier(Identifier {
+                                typ: "Identifier",
+                                name: Cow::from("b"),
+                                type_annotation: None,
+                                optional: false
+                            })
+                        )
+                    })
+                ]
+            }
+        )
+    }
+
+    #[test]
+    fn rejects_invalid_patterns() {
+        let mut spec = TokenSpec::new();
+
+        assert!(TokenRule::new(r"(", "BROKEN").is_err());
+        assert!(spec.add_rule(r"(", "BROKEN").is_err());
+        assert!(matches!(
+            spec.insert_rule("BROKEN", r"[", "BROKEN"),
+            Err(TokenSpecError::UnknownRule(_))
+        ));
+        assert!(spec.rules.is_empty())
+    }
+
+    #[test]
+    fn rejects_unknown_rule_types() {
+        let mut spec = TokenSpec::default();
+        let rules = spec.rules.len();
+
+        assert_eq!(
+            spec.insert_rule("MISSING", r"@", "AT")
+                .unwrap_err()
+                .to_string(),
+            "No token rule of type MISSING"
+        );
+        assert!(spec.move_rule("MISSING", "IDENTIFIER").is_err());
+        assert!(spec.move_rule("IDENTIFIER", "MISSING").is_err());
+        assert!(spec.skip("MISSING").is_err());
+        assert_eq!(spec.position("MISSING"), None);
+        assert_eq!(spec.rules.len(), rules)
+    }
+
+    #[test]
+    fn reports_invalid_patterns_of_inserted_rules() {
+        let mut spec = TokenSpec::default();
+
+        assert!(matches!(
+            spec.insert_rule("IDENTIFIER", r"(", "BROKEN"),
+            Err(TokenSpecError::InvalidPattern(_))
+        ));
+        assert_eq!(spec.position("BROKEN"), None)
+    }
+
+    #[test]
+    fn borrows_token_values_from_the_source() {
+        let source = String::from("let name = 'value';");
+        let mut tokenizer = Tokenizer::new();
+        tokenizer.init(&source);
+
+        for token in tokenizer {
+            assert!(std::ptr::eq(token.value, &source[token.start..token.end]));
+        }
+    }
+}
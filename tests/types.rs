@@ -0,0 +1,409 @@
+#[cfg(all(test, feature = "types"))]
+mod test {
+    use letter_rdp_rust::*;
+    use std::borrow::Cow;
+    #[test]
+    fn handles_variable_annotation() {
+        let mut parser = init();
+
+        let result = parser.parse("let x: number = 1;");
+
+        assert_eq!(
+            result,
+            Program {
+                typ: "Program",
+                hashbang: None,
+                body: vec![Statement::VariableStatement(VariableStatement {
+                    typ: "VariableStatement",
+                    kind: "let",
+                    declarations: vec![VariableDeclaration {
+                        typ: "VariableDeclaration",
+                        id: Identifier {
+                            typ: "Identifier",
+                            name: Cow::from("x"),
+                            type_annotation: Some(Box::new(TypeAnnotation {
+                                typ: "TypeAnnotation",
+                                type_annotation: Type::KeywordType(KeywordType {
+                                    typ: "KeywordType",
+                                    name: Cow::from("number")
+                                })
+                            })),
+                            optional: false
+                        },
+                        init: Some(Expression::Literal(Literal::NumericLiteral(
+                            NumericLiteral {
+                                typ: "NumericLiteral",
+                                value: 1.0,
+                                raw: Cow::from("1")
+                            }
+                        )))
+                    }]
+                })]
+            }
+        )
+    }
+
+    #[test]
+    fn handles_parameter_and_return_annotations() {
+        let mut parser = init();
+
+        let result = parser.parse("function f(a: string, b?: Foo[]): boolean {}");
+
+        assert_eq!(
+            result,
+            Program {
+                typ: "Program",
+                hashbang: None,
+                body: vec![Statement::FunctionDeclaration(FunctionDeclaration {
+                    typ: "FunctionDeclaration",
+                    id: Identifier {
+                        typ: "Identifier",
+                        name: Cow::from("f"),
+                        type_annotation: None,
+                        optional: false
+                    },
+                    params: vec![
+                        Identifier {
+                            typ: "Identifier",
+                            name: Cow::from("a"),
+                            type_annotation: Some(Box::new(TypeAnnotation {
+                                typ: "TypeAnnotation",
+                                type_annotation: Type::KeywordType(KeywordType {
+                                    typ: "KeywordType",
+                                    name: Cow::from("string")
+                                })
+                            })),
+                            optional: false
+                        },
+                        Identifier {
+                            typ: "Identifier",
+                            name: Cow::from("b"),
+                            type_annotation: Some(Box::new(TypeAnnotation {
+                                typ: "TypeAnnotation",
+                                type_annotation: Type::ArrayType(ArrayType {
+                                    typ: "ArrayType",
+                                    element_type: Box::new(Type::TypeReference(TypeReference {
+                                        typ: "TypeReference",
+                                        name: Cow::from("Foo")
+                                    }))
+                                })
+                            })),
+                            optional: true
+                        }
+                    ],
+                    body: BlockStatement {
+                        typ: "BlockStatement",
+                        body: vec![]
+                    },
+                    is_async: false,
+                    is_generator: false,
+                    return_type: Some(Box::new(TypeAnnotation {
+                        typ: "TypeAnnotation",
+                        type_annotation: Type::KeywordType(KeywordType {
+                            typ: "KeywordType",
+                            name: Cow::from("boolean")
+                        })
+                    }))
+                })]
+            }
+        )
+    }
+
+    #[test]
+    fn handles_type_alias() {
+        let mut parser = init();
+
+        let result = parser.parse("type Id = string | number;");
+
+        assert_eq!(
+            result,
+            Program {
+                typ: "Program",
+                hashbang: None,
+                body: vec![Statement::TypeAliasDeclaration(TypeAliasDeclaration {
+                    typ: "TypeAliasDeclaration",
+                    id: Identifier {
+                        typ: "Identifier",
+                        name: Cow::from("Id"),
+                        type_annotation: None,
+                        optional: false
+                    },
+                    type_annotation: Type::UnionType(UnionType {
+                        typ: "UnionType",
+                        types: vec![
+                            Type::KeywordType(KeywordType {
+                                typ: "KeywordType",
+                                name: Cow::from("string")
+                            }),
+                            Type::KeywordType(KeywordType {
+                                typ: "KeywordType",
+                                name: Cow::from("number")
+                            })
+                        ]
+                    })
+                })]
+            }
+        )
+    }
+
+    #[test]
+    fn handles_literal_and_parenthesized_types() {
+        let mut parser = init();
+
+        let result = parser.parse(
+            "type Mode = | 'on' | 'off';
+type List = (Id | null)[];",
+        );
+
+        assert_eq!(
+            result,
+            Program {
+                typ: "Program",
+                hashbang: None,
+                body: vec![
+                    Statement::TypeAliasDeclaration(TypeAliasDeclaration {
+                        typ: "TypeAliasDeclaration",
+                        id: Identifier {
+                            typ: "Identifier",
+                            name: Cow::from("Mode"),
+                            type_annotation: None,
+                            optional: false
+                        },
+                        type_annotation: Type::UnionType(UnionType {
+                            typ: "UnionType",
+                            types: vec![
+                                Type::LiteralType(LiteralType {
+                                    typ: "LiteralType",
+                                    literal: Literal::StringLiteral(StringLiteral {
+                                        typ: "StringLiteral",
+                                        value: Cow::from("on"),
+                                        raw: Cow::from("'on'")
+                                    })
+                                }),
+                                Type::LiteralType(LiteralType {
+                                    typ: "LiteralType",
+                                    literal: Literal::StringLiteral(StringLiteral {
+                                        typ: "StringLiteral",
+                                        value: Cow::from("off"),
+                                        raw: Cow::from("'off'")
+                                    })
+                                })
+                            ]
+                        })
+                    }),
+                    Statement::TypeAliasDeclaration(TypeAliasDeclaration {
+                        typ: "TypeAliasDeclaration",
+                        id: Identifier {
+                            typ: "Identifier",
+                            name: Cow::from("List"),
+                            type_annotation: None,
+                            optional: false
+                        },
+                        type_annotation: Type::ArrayType(ArrayType {
+                            typ: "ArrayType",
+                            element_type: Box::new(Type::UnionType(UnionType {
+                                typ: "UnionType",
+                                types: vec![
+                                    Type::TypeReference(TypeReference {
+                                        typ: "TypeReference",
+                                        name: Cow::from("Id")
+                                    }),
+                                    Type::KeywordType(KeywordType {
+                                        typ: "KeywordType",
+                                        name: Cow::from("null")
+                                    })
+                                ]
+                            }))
+                        })
+                    })
+                ]
+            }
+        )
+    }
+
+    #[test]
+    fn handles_interface() {
+        let mut parser = init();
+
+        let result = parser.parse(
+            "interface Shape extends Named, Sized {
+  area: number
+  label?: string;
+}",
+        );
+
+        assert_eq!(
+            result,
+            Program {
+                typ: "Program",
+                hashbang: None,
+                body: vec![Statement::InterfaceDeclaration(InterfaceDeclaration {
+                    typ: "InterfaceDeclaration",
+                    id: Identifier {
+                        typ: "Identifier",
+                        name: Cow::from("Shape"),
+                        type_annotation: None,
+                        optional: false
+                    },
+                    extends: vec![
+                        TypeReference {
+                            typ: "TypeReference",
+                            name: Cow::from("Named")
+                        },
+                        TypeReference {
+                            typ: "TypeReference",
+                            name: Cow::from("Sized")
+                        }
+                    ],
+                    body: InterfaceBody {
+                        typ: "InterfaceBody",
+                        body: vec![
+                            PropertySignature {
+                                typ: "PropertySignature",
+                                key: Identifier {
+                                    typ: "Identifier",
+                                    name: Cow::from("area"),
+                                    type_annotation: None,
+                                    optional: false
+                                },
+                                optional: false,
+                                type_annotation: TypeAnnotation {
+                                    typ: "TypeAnnotation",
+                                    type_annotation: Type::KeywordType(KeywordType {
+                                        typ: "KeywordType",
+                                        name: Cow::from("number")
+                                    })
+                                }
+                            },
+                            PropertySignature {
+                                typ: "PropertySignature",
+                                key: Identifier {
+                                    typ: "Identifier",
+                                    name: Cow::from("label"),
+                                    type_annotation: None,
+                                    optional: false
+                                },
+                                optional: true,
+                                type_annotation: TypeAnnotation {
+                                    typ: "TypeAnnotation",
+                                    type_annotation: Type::KeywordType(KeywordType {
+                                        typ: "KeywordType",
+                                        name: Cow::from("string")
+                                    })
+                                }
+                            }
+                        ]
+                    }
+                })]
+            }
+        )
+    }
+
+    #[test]
+    fn converts_annotations_through_an_arena() {
+        let string = "let x: number = 1; function f(a?: 'a' | Foo[]): boolean {} \
+                      type Id = string; interface A extends B { c?: number; }";
+        let mut parser = init();
+
+        let ast = arena::Ast::from(parser.parse(string));
+
+        assert_eq!(ast.to_program(), parser.parse(string))
+    }
+
+    #[test]
+    fn keeps_type_as_identifier() {
+        let mut parser = init();
+
+        let result = parser.parse("type = 1;");
+
+        assert_eq!(
+            result,
+            Program {
+                typ: "Program",
+                hashbang: None,
+                body: vec![Statement::ExpressionStatement(ExpressionStatement {
+                    typ: "ExpressionStatement",
+                    expression: Expression::AssignmentExpression(AssignmentExpression {
+                        typ: "AssignmentExpression",
+                        operator: Cow::from("="),
+                        left: Box::new(Expression::LeftHandSideExpression(
+                            LeftHandSideExpression::Identifier(Identifier {
+                                typ: "Identifier",
+                                name: Cow::from("type"),
+                                type_annotation: None,
+                                optional: false
+                            })
+                        )),
+                        right: Box::new(Expression::Literal(Literal::NumericLiteral(
+                            NumericLiteral {
+                                typ: "NumericLiteral",
+                                value: 1.0,
+                                raw: Cow::from("1")
+                            }
+                        )))
+                    })
+                })]
+            }
+        )
+    }
+
+    #[test]
+    #[should_panic(expected = "Unexpected token: SIMPLE_ASSIGN, expected: IDENTIFIER")]
+    fn rejects_missing_type() {
+        let mut parser = init();
+
+        parser.parse("let x: = 1;");
+    }
+}
+
+#[cfg(all(test, not(feature = "types")))]
+mod without_feature {
+    use letter_rdp_rust::*;
+
+    #[test]
+    #[should_panic(expected = "Unexpected token: : (1:6)")]
+    fn rejects_annotations() {
+        let mut parser = init();
+
+        parser.parse("let x: number = 1;");
+    }
+}
+
+#[cfg(test)]
+mod with_or_without_feature {
+    use letter_rdp_rust::*;
+    use std::borrow::Cow;
+
+    #[test]
+    fn leaves_unannotated_code_unchanged() {
+        let mut parser = init();
+
+        let result = parser.parse("function f(a) {}");
+
+        assert_eq!(
+            result.body,
+            vec![Statement::FunctionDeclaration(FunctionDeclaration {
+                typ: "FunctionDeclaration",
+                id: Identifier {
+                    typ: "Identifier",
+                    name: Cow::from("f"),
+                    type_annotation: None,
+                    optional: false
+                },
+                params: vec![Identifier {
+                    typ: "Identifier",
+                    name: Cow::from("a"),
+                    type_annotation: None,
+                    optional: false
+                }],
+                body: BlockStatement {
+                    typ: "BlockStatement",
+                    body: vec![]
+                },
+                is_async: false,
+                is_generator: false,
+                return_type: None
+            })]
+        )
+    }
+}
@@ -0,0 +1,109 @@
+#[cfg(test)]
+mod test {
+    use letter_rdp_rust::*;
+    use std::borrow::Cow;
+    #[test]
+    fn handles_unary_minus() {
+        let mut parser = init();
+
+        let result = parser.parse("-x;");
+
+        assert_eq!(
+            result,
+            Program {
+                typ: "Program",
+                hashbang: None,
+                body: vec![Statement::ExpressionStatement(ExpressionStatement {
+                    typ: "ExpressionStatement",
+                    expression: Expression::UnaryExpression(UnaryExpression {
+                        typ: "UnaryExpression",
+                        operator: Cow::from("-"),
+                        argument: Box::new(Expression::LeftHandSideExpression(
+                            LeftHandSideExpression::Identifier(Identifier {
+                                typ: "Identifier",
+                                name: Cow::from("x"),
+                                type_annotation: None,
+                                optional: false
+                            })
+                        ))
+                    })
+                })]
+            }
+        )
+    }
+
+    #[test]
+    fn handles_chained_unary_operators() {
+        let mut parser = init();
+
+        let result = parser.parse("!~x;");
+
+        assert_eq!(
+            result,
+            Program {
+                typ: "Program",
+                hashbang: None,
+                body: vec![Statement::ExpressionStatement(ExpressionStatement {
+                    typ: "ExpressionStatement",
+                    expression: Expression::UnaryExpression(UnaryExpression {
+                        typ: "UnaryExpression",
+                        operator: Cow::from("!"),
+                        argument: Box::new(Expression::UnaryExpression(UnaryExpression {
+                            typ: "UnaryExpression",
+                            operator: Cow::from("~"),
+                            argument: Box::new(Expression::LeftHandSideExpression(
+                                LeftHandSideExpression::Identifier(Identifier {
+                                    typ: "Identifier",
+                                    name: Cow::from("x"),
+                                    type_annotation: None,
+                                    optional: false
+                                })
+                            ))
+                        }))
+                    })
+                })]
+            }
+        )
+    }
+
+    #[test]
+    fn unary_binds_tighter_than_multiplication() {
+        let mut parser = init();
+
+        let result = parser.parse("-x * 2;");
+
+        assert_eq!(
+            result,
+            Program {
+                typ: "Program",
+                hashbang: None,
+                body: vec![Statement::ExpressionStatement(ExpressionStatement {
+                    typ: "ExpressionStatement",
+                    expression: Expression::BinaryExpression(BinaryExpression {
+                        typ: "BinaryExpression",
+                        operator: Cow::from("*"),
+                        left: Box::new(Expression::UnaryExpression(UnaryExpression {
+                            typ: "UnaryExpression",
+                            operator: Cow::from("-"),
+                            argument: Box::new(Expression::LeftHandSideExpression(
+                                LeftHandSideExpression::Identifier(Identifier {
+                                    typ: "Identifier",
+                                    name: Cow::from("x"),
+                                    type_annotation: None,
+                                    optional: false
+                                })
+                            ))
+                        })),
+                        right: Box::new(Expression::Literal(Literal::NumericLiteral(
+                            NumericLiteral {
+                                typ: "NumericLiteral",
+                                value: 2.0,
+                                raw: Cow::from("2")
+                            }
+                        )))
+                    })
+                })]
+            }
+        )
+    }
+}
@@ -1,6 +1,7 @@
 #[cfg(test)]
 mod test {
     use letter_rdp_rust::*;
+    use std::borrow::Cow;
     #[test]
     fn handles_single_variable_declaration() {
         let mut parser = init();
@@ -11,18 +12,23 @@ mod test {
             result,
             Program {
                 typ: "Program",
+                hashbang: None,
                 body: vec![Statement::VariableStatement(VariableStatement {
                     typ: "VariableStatement",
+                    kind: "let",
                     declarations: vec![VariableDeclaration {
                         typ: "VariableDeclaration",
                         id: Identifier {
                             typ: "Identifier",
-                            name: String::from("x")
+                            name: Cow::from("x"),
+                            type_annotation: None,
+                            optional: false
                         },
-                        init: Some(VariableInitializer::Literal(Literal::NumericLiteral(
+                        init: Some(Expression::Literal(Literal::NumericLiteral(
                             NumericLiteral {
                                 typ: "NumericLiteral",
-                                value: 42
+                                value: 42.0,
+                                raw: Cow::from("42")
                             }
                         )))
                     }]
@@ -41,14 +47,18 @@ mod test {
             result,
             Program {
                 typ: "Program",
+                hashbang: None,
                 body: vec![Statement::VariableStatement(VariableStatement {
                     typ: "VariableStatement",
+                    kind: "let",
                     declarations: vec![
                         VariableDeclaration {
                             typ: "VariableDeclaration",
                             id: Identifier {
                                 typ: "Identifier",
-                                name: String::from("x")
+                                name: Cow::from("x"),
+                                type_annotation: None,
+                                optional: false
                             },
                             init: None
                         },
@@ -56,7 +66,9 @@ mod test {
                             typ: "VariableDeclaration",
                             id: Identifier {
                                 typ: "Identifier",
-                                name: String::from("y")
+                                name: Cow::from("y"),
+                                type_annotation: None,
+                                optional: false
                             },
                             init: None
                         }
@@ -76,14 +88,18 @@ mod test {
             result,
             Program {
                 typ: "Program",
+                hashbang: None,
                 body: vec![Statement::VariableStatement(VariableStatement {
                     typ: "VariableStatement",
+                    kind: "let",
                     declarations: vec![
                         VariableDeclaration {
                             typ: "VariableDeclaration",
                             id: Identifier {
                                 typ: "Identifier",
-                                name: String::from("x")
+                                name: Cow::from("x"),
+                                type_annotation: None,
+                                optional: false
                             },
                             init: None
                         },
@@ -91,12 +107,15 @@ mod test {
                             typ: "VariableDeclaration",
                             id: Identifier {
                                 typ: "Identifier",
-                                name: String::from("y")
+                                name: Cow::from("y"),
+                                type_annotation: None,
+                                optional: false
                             },
-                            init: Some(VariableInitializer::Literal(Literal::NumericLiteral(
+                            init: Some(Expression::Literal(Literal::NumericLiteral(
                                 NumericLiteral {
                                     typ: "NumericLiteral",
-                                    value: 42
+                                    value: 42.0,
+                                    raw: Cow::from("42")
                                 }
                             )))
                         }
@@ -116,31 +135,408 @@ mod test {
             result,
             Program {
                 typ: "Program",
+                hashbang: None,
                 body: vec![Statement::VariableStatement(VariableStatement {
                     typ: "VariableStatement",
+                    kind: "let",
                     declarations: vec![VariableDeclaration {
                         typ: "VariableDeclaration",
                         id: Identifier {
                             typ: "Identifier",
-                            name: String::from("x")
+                            name: Cow::from("x"),
+                            type_annotation: None,
+                            optional: false
                         },
-                        init: Some(VariableInitializer::AssignmentExpression(
-                            AssignmentExpression {
-                                typ: "AssignmentExpression",
-                                operator: String::from("="),
-                                left: Identifier {
+                        init: Some(Expression::AssignmentExpression(AssignmentExpression {
+                            typ: "AssignmentExpression",
+                            operator: Cow::from("="),
+                            left: Box::new(Expression::LeftHandSideExpression(
+                                LeftHandSideExpression::Identifier(Identifier {
                                     typ: "Identifier",
-                                    name: String::from("y")
-                                },
+                                    name: Cow::from("y"),
+                                    type_annotation: None,
+                                    optional: false
+                                })
+                            )),
+                            right: Box::new(Expression::Literal(Literal::NumericLiteral(
+                                NumericLiteral {
+                                    typ: "NumericLiteral",
+                                    value: 42.0,
+                                    raw: Cow::from("42")
+                                }
+                            )))
+                        }))
+                    },]
+                })]
+            }
+        )
+    }
+    #[test]
+    fn handles_const_declaration() {
+        let mut parser = init();
+
+        let result = parser.parse("const x = 42;");
+
+        assert_eq!(
+            result,
+            Program {
+                typ: "Program",
+                hashbang: None,
+                body: vec![Statement::VariableStatement(VariableStatement {
+                    typ: "VariableStatement",
+                    kind: "const",
+                    declarations: vec![VariableDeclaration {
+                        typ: "VariableDeclaration",
+                        id: Identifier {
+                            typ: "Identifier",
+                            name: Cow::from("x"),
+                            type_annotation: None,
+                            optional: false
+                        },
+                        init: Some(Expression::Literal(Literal::NumericLiteral(
+                            NumericLiteral {
+                                typ: "NumericLiteral",
+                                value: 42.0,
+                                raw: Cow::from("42")
+                            }
+                        )))
+                    }]
+                })]
+            }
+        )
+    }
+
+    #[test]
+    fn handles_var_redeclaration() {
+        let mut parser = init();
+
+        let result = parser.parse("var x; var x = 42;");
+
+        assert_eq!(
+            result,
+            Program {
+                typ: "Program",
+                hashbang: None,
+                body: vec![
+                    Statement::VariableStatement(VariableStatement {
+                        typ: "VariableStatement",
+                        kind: "var",
+                        declarations: vec![VariableDeclaration {
+                            typ: "VariableDeclaration",
+                            id: Identifier {
+                                typ: "Identifier",
+                                name: Cow::from("x"),
+                                type_annotation: None,
+                                optional: false
+                            },
+                            init: None
+                        }]
+                    }),
+                    Statement::VariableStatement(VariableStatement {
+                        typ: "VariableStatement",
+                        kind: "var",
+                        declarations: vec![VariableDeclaration {
+                            typ: "VariableDeclaration",
+                            id: Identifier {
+                                typ: "Identifier",
+                                name: Cow::from("x"),
+                                type_annotation: None,
+                                optional: false
+                            },
+                            init: Some(Expression::Literal(Literal::NumericLiteral(
+                                NumericLiteral {
+                                    typ: "NumericLiteral",
+                                    value: 42.0,
+                                    raw: Cow::from("42")
+                                }
+                            )))
+                        }]
+                    })
+                ]
+            }
+        )
+    }
+
+    #[test]
+    fn handles_let_shadowing_in_nested_block() {
+        let mut parser = init();
+
+        let result = parser.parse("let x; { let x; }");
+
+        assert_eq!(result.body.len(), 2);
+    }
+
+    #[test]
+    #[should_panic(expected = "Missing initializer in const declaration: x")]
+    fn rejects_const_without_initializer() {
+        let mut parser = init();
+
+        parser.parse("const x;");
+    }
+
+    #[test]
+    #[should_panic(expected = "Identifier 'x' has already been declared")]
+    fn rejects_let_redeclaration() {
+        let mut parser = init();
+
+        parser.parse("let x; let x;");
+    }
+
+    #[test]
+    #[should_panic(expected = "Identifier 'x' has already been declared")]
+    fn rejects_var_redeclaring_const() {
+        let mut parser = init();
+
+        parser.parse("const x = 1; var x;");
+    }
+
+    #[test]
+    #[should_panic(expected = "Identifier 'x' has already been declared")]
+    fn rejects_var_in_block_redeclaring_let() {
+        let mut parser = init();
+
+        parser.parse("let x; { var x; }");
+    }
+
+    #[test]
+    #[should_panic(expected = "Identifier 'x' has already been declared")]
+    fn rejects_let_redeclaring_var_in_block() {
+        let mut parser = init();
+
+        parser.parse("{ var x; } let x;");
+    }
+
+    #[test]
+    #[should_panic(expected = "Identifier 'x' has already been declared")]
+    fn rejects_let_redeclaring_var_in_function_block() {
+        let mut parser = init();
+
+        parser.parse("function f() { { var x; } let x; }");
+    }
+
+    #[test]
+    #[should_panic(expected = "Lexical declaration cannot appear in a single-statement context")]
+    fn rejects_let_as_if_body() {
+        let mut parser = init();
+
+        parser.parse("if (x) let a = 1;");
+    }
+
+    #[test]
+    #[should_panic(expected = "Lexical declaration cannot appear in a single-statement context")]
+    fn rejects_const_as_else_body() {
+        let mut parser = init();
+
+        parser.parse("if (x) {} else const b = 2;");
+    }
+
+    #[test]
+    fn allows_var_as_if_body() {
+        let mut parser = init();
+
+        let result = parser.parse("if (x) var a = 1;");
+
+        assert_eq!(result.body.len(), 1)
+    }
+
+    #[test]
+    fn hoists_var_no_further_than_the_function() {
+        let mut parser = init();
+
+        let result = parser.parse("let x; function f() { { var x; } } { var y; } { let y; }");
+
+        assert_eq!(result.body.len(), 4);
+    }
+
+    #[test]
+    fn handles_identifier_initializer() {
+        let mut parser = init();
+
+        let result = parser.parse("let y = x + 1;");
+
+        assert_eq!(
+            result,
+            Program {
+                typ: "Program",
+                hashbang: None,
+                body: vec![Statement::VariableStatement(VariableStatement {
+                    typ: "VariableStatement",
+                    kind: "let",
+                    declarations: vec![VariableDeclaration {
+                        typ: "VariableDeclaration",
+                        id: Identifier {
+                            typ: "Identifier",
+                            name: Cow::from("y"),
+                            type_annotation: None,
+                            optional: false
+                        },
+                        init: Some(Expression::BinaryExpression(BinaryExpression {
+                            typ: "BinaryExpression",
+                            operator: Cow::from("+"),
+                            left: Box::new(Expression::LeftHandSideExpression(
+                                LeftHandSideExpression::Identifier(Identifier {
+                                    typ: "Identifier",
+                                    name: Cow::from("x"),
+                                    type_annotation: None,
+                                    optional: false
+                                })
+                            )),
+                            right: Box::new(Expression::Literal(Literal::NumericLiteral(
+                                NumericLiteral {
+                                    typ: "NumericLiteral",
+                                    value: 1.0,
+                                    raw: Cow::from("1")
+                                }
+                            )))
+                        }))
+                    }]
+                })]
+            }
+        )
+    }
+
+    #[test]
+    fn handles_logical_initializer() {
+        let mut parser = init();
+
+        let result = parser.parse("let z = a && b || c;");
+
+        assert_eq!(
+            result,
+            Program {
+                typ: "Program",
+                hashbang: None,
+                body: vec![Statement::VariableStatement(VariableStatement {
+                    typ: "VariableStatement",
+                    kind: "let",
+                    declarations: vec![VariableDeclaration {
+                        typ: "VariableDeclaration",
+                        id: Identifier {
+                            typ: "Identifier",
+                            name: Cow::from("z"),
+                            type_annotation: None,
+                            optional: false
+                        },
+                        init: Some(Expression::LogicalExpression(LogicalExpression {
+                            typ: "LogicalExpression",
+                            operator: Cow::from("||"),
+                            left: Box::new(Expression::LogicalExpression(LogicalExpression {
+                                typ: "LogicalExpression",
+                                operator: Cow::from("&&"),
+                                left: Box::new(Expression::LeftHandSideExpression(
+                                    LeftHandSideExpression::Identifier(Identifier {
+                                        typ: "Identifier",
+                                        name: Cow::from("a"),
+                                        type_annotation: None,
+                                        optional: false
+                                    })
+                                )),
+                                right: Box::new(Expression::LeftHandSideExpression(
+                                    LeftHandSideExpression::Identifier(Identifier {
+                                        typ: "Identifier",
+                                        name: Cow::from("b"),
+                                        type_annotation: None,
+                                        optional: false
+                                    })
+                                ))
+                            })),
+                            right: Box::new(Expression::LeftHandSideExpression(
+                                LeftHandSideExpression::Identifier(Identifier {
+                                    typ: "Identifier",
+                                    name: Cow::from("c"),
+                                    type_annotation: None,
+                                    optional: false
+                                })
+                            ))
+                        }))
+                    }]
+                })]
+            }
+        )
+    }
+
+    #[test]
+    fn handles_nested_initializer() {
+        let mut parser = init();
+
+        let result = parser.parse("const w = f(x)[0] * (y - 1);");
+
+        assert_eq!(
+            result,
+            Program {
+                typ: "Program",
+                hashbang: None,
+                body: vec![Statement::VariableStatement(VariableStatement {
+                    typ: "VariableStatement",
+                    kind: "const",
+                    declarations: vec![VariableDeclaration {
+                        typ: "VariableDeclaration",
+                        id: Identifier {
+                            typ: "Identifier",
+                            name: Cow::from("w"),
+                            type_annotation: None,
+                            optional: false
+                        },
+                        init: Some(Expression::BinaryExpression(BinaryExpression {
+                            typ: "BinaryExpression",
+                            operator: Cow::from("*"),
+                            left: Box::new(Expression::LeftHandSideExpression(
+                                LeftHandSideExpression::MemberExpression(MemberExpression {
+                                    typ: "MemberExpression",
+                                    object: Box::new(Expression::LeftHandSideExpression(
+                                        LeftHandSideExpression::CallExpression(CallExpression {
+                                            typ: "CallExpression",
+                                            callee: Box::new(Expression::LeftHandSideExpression(
+                                                LeftHandSideExpression::Identifier(Identifier {
+                                                    typ: "Identifier",
+                                                    name: Cow::from("f"),
+                                                    type_annotation: None,
+                                                    optional: false
+                                                })
+                                            )),
+                                            arguments: vec![Expression::LeftHandSideExpression(
+                                                LeftHandSideExpression::Identifier(Identifier {
+                                                    typ: "Identifier",
+                                                    name: Cow::from("x"),
+                                                    type_annotation: None,
+                                                    optional: false
+                                                })
+                                            )],
+                                            optional: false
+                                        })
+                                    )),
+                                    property: Box::new(Expression::Literal(
+                                        Literal::NumericLiteral(NumericLiteral {
+                                            typ: "NumericLiteral",
+                                            value: 0.0,
+                                            raw: Cow::from("0")
+                                        })
+                                    )),
+                                    computed: true,
+                                    optional: false
+                                })
+                            )),
+                            right: Box::new(Expression::BinaryExpression(BinaryExpression {
+                                typ: "BinaryExpression",
+                                operator: Cow::from("-"),
+                                left: Box::new(Expression::LeftHandSideExpression(
+                                    LeftHandSideExpression::Identifier(Identifier {
+                                        typ: "Identifier",
+                                        name: Cow::from("y"),
+                                        type_annotation: None,
+                                        optional: false
+                                    })
+                                )),
                                 right: Box::new(Expression::Literal(Literal::NumericLiteral(
                                     NumericLiteral {
                                         typ: "NumericLiteral",
-                                        value: 42
+                                        value: 1.0,
+                                        raw: Cow::from("1")
                                     }
                                 )))
-                            }
-                        ))
-                    },]
+                            }))
+                        }))
+                    }]
                 })]
             }
         )